@@ -0,0 +1,86 @@
+use multiversx_sc_scenario::{scenario::run_coverage::ScenarioCoverage, scenario_model::*, *};
+
+fn world(coverage: &ScenarioCoverage) -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract("file:output/adder.wasm", adder::ContractBuilder);
+    blockchain
+        .start_coverage(coverage)
+        .register_coverage_contract::<adder::AbiProvider>("file:output/adder.wasm");
+    blockchain
+}
+
+#[test]
+fn adder_coverage() {
+    let coverage = ScenarioCoverage::new();
+    world(&coverage).run("scenarios/adder.scen.json");
+    world(&coverage).run("scenarios/interactor_trace.scen.json");
+
+    let report = coverage.report();
+    assert_eq!(
+        report.never_exercised(),
+        vec![("Adder".to_string(), "upgrade".to_string())]
+    );
+
+    let add = report.contracts[0]
+        .endpoints
+        .iter()
+        .find(|endpoint| endpoint.name == "add")
+        .unwrap();
+    assert!(add.hit_count >= 2);
+    assert!(add
+        .scenarios
+        .iter()
+        .any(|scenario| scenario.starts_with("adder.scen.json")));
+    assert!(add
+        .scenarios
+        .iter()
+        .any(|scenario| scenario.starts_with("interactor_trace.scen.json")));
+
+    let lcov_path = std::env::temp_dir().join("adder-coverage.lcov");
+    report.write_lcov(&lcov_path);
+    let lcov = std::fs::read_to_string(&lcov_path).unwrap();
+    assert!(lcov.contains("adder.rs"));
+    assert!(lcov.contains("FN:24,add"));
+    assert!(lcov.contains("FNDA:0,upgrade"));
+    assert!(lcov.contains("FNF:4"));
+    assert!(lcov.contains("FNH:3"));
+}
+
+#[test]
+fn adder_coverage_fork() {
+    let coverage = ScenarioCoverage::new();
+    let mut world = world(&coverage);
+    let adder_code = world.code_expression("file:output/adder.wasm");
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:owner", Account::new().nonce(1))
+                .new_address("address:owner", 1, "sc:adder"),
+        )
+        .sc_deploy(
+            ScDeployStep::new()
+                .from("address:owner")
+                .code(adder_code)
+                .argument("5"),
+        );
+
+    // only the fork calls `add`, the hit must still be recorded in the shared coverage
+    let mut fork = world.fork();
+    fork.sc_call(
+        ScCallStep::new()
+            .from("address:owner")
+            .to("sc:adder")
+            .function("add")
+            .argument("3"),
+    );
+
+    let report = coverage.report();
+    let add = report.contracts[0]
+        .endpoints
+        .iter()
+        .find(|endpoint| endpoint.name == "add")
+        .unwrap();
+    assert_eq!(add.hit_count, 1);
+}
@@ -0,0 +1,80 @@
+use multiversx_sc::contract_base::ContractAbiProvider;
+use multiversx_sc_scenario::{fuzz::ContractFuzzer, num_bigint::BigUint, scenario_model::*, *};
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+    blockchain
+}
+
+fn setup(world: &mut ScenarioWorld) {
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:owner", Account::new().nonce(1))
+                .put_account("address:user", Account::new())
+                .new_address("address:owner", 1, "sc:adder"),
+        )
+        .sc_deploy(
+            ScDeployStep::new()
+                .from("address:owner")
+                .code(adder_code)
+                .argument("5"),
+        );
+}
+
+fn get_sum(world: &mut ScenarioWorld) -> BigUint {
+    let mut sum = BigUint::default();
+    world.sc_query_use_raw_response(
+        ScQueryStep::new().to("sc:adder").function("getSum"),
+        |response| sum = BigUint::from_bytes_be(&response.out[0]),
+    );
+    sum
+}
+
+fn adder_fuzzer() -> ContractFuzzer {
+    ContractFuzzer::new(world, adder::AbiProvider::abi(), "sc:adder")
+        .setup(setup)
+        .caller("address:owner")
+        .caller("address:user")
+        .exclude_endpoint("upgrade")
+        .seed(1)
+        .runs(5)
+        .calls_per_run(5)
+}
+
+#[test]
+fn adder_fuzz_invariant_holds() {
+    adder_fuzzer()
+        .invariant("sum never decreases below initial value", |world| {
+            get_sum(world) >= BigUint::from(5u32)
+        })
+        .run();
+}
+
+#[test]
+fn adder_fuzz_invariant_violated() {
+    let failure = adder_fuzzer()
+        .invariant("sum stays small", |world| {
+            get_sum(world) <= BigUint::from(1000u32)
+        })
+        .repro_path(format!("adder-fuzz-repro-{}.scen.json", std::process::id()))
+        .try_run()
+        .unwrap_err();
+
+    assert_eq!(failure.invariant, "sum stays small");
+    assert_eq!(failure.calls.len(), 1);
+    assert_eq!(failure.calls[0].endpoint, "add");
+
+    // the reproduction replays the setup and the failing call, then fails its final check
+    let repro_path = failure.repro_path.unwrap();
+    assert!(repro_path.starts_with(std::env::temp_dir()));
+    let replay = std::panic::catch_unwind(|| world().run(&repro_path));
+    std::fs::remove_file(repro_path).unwrap();
+    assert!(replay.is_err());
+}
@@ -0,0 +1,113 @@
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+    blockchain
+}
+
+fn deployed_world() -> ScenarioWorld {
+    let mut world = world();
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:owner", Account::new().nonce(1))
+                .new_address("address:owner", 1, "sc:adder"),
+        )
+        .sc_deploy(
+            ScDeployStep::new()
+                .from("address:owner")
+                .code(adder_code)
+                .argument("5"),
+        );
+    world
+}
+
+fn add(world: &mut ScenarioWorld, value: &str) {
+    world.sc_call(
+        ScCallStep::new()
+            .from("address:owner")
+            .to("sc:adder")
+            .function("add")
+            .argument(value),
+    );
+}
+
+fn check_sum(world: &mut ScenarioWorld, expected: &str) {
+    world.check_state_step(CheckStateStep::new().put_account(
+        "sc:adder",
+        CheckAccount::new().check_storage("str:sum", expected),
+    ));
+}
+
+#[test]
+fn adder_snapshot_revert() {
+    let mut world = deployed_world();
+    let after_deploy = world.snapshot();
+
+    add(&mut world, "3");
+    check_sum(&mut world, "8");
+    let after_add = world.snapshot();
+
+    world.revert_to(after_deploy);
+    check_sum(&mut world, "5");
+
+    // snapshots can be reverted to several times, in any order
+    add(&mut world, "10");
+    check_sum(&mut world, "15");
+    world.revert_to(after_add);
+    check_sum(&mut world, "8");
+    world.revert_to(after_deploy);
+    check_sum(&mut world, "5");
+}
+
+#[test]
+fn adder_snapshot_revert_trace() {
+    let mut world = world();
+    world.start_trace();
+    let adder_code = world.code_expression(ADDER_PATH_EXPR);
+    world
+        .set_state_step(
+            SetStateStep::new()
+                .put_account("address:owner", Account::new().nonce(1))
+                .new_address("address:owner", 1, "sc:adder"),
+        )
+        .sc_deploy(
+            ScDeployStep::new()
+                .from("address:owner")
+                .code(adder_code)
+                .argument("5"),
+        );
+    let snapshot = world.snapshot();
+
+    add(&mut world, "3");
+    world.revert_to(snapshot);
+    add(&mut world, "7");
+    check_sum(&mut world, "12");
+
+    // the reverted call is not part of the trace, so the trace replays consistently
+    let trace_path = world.current_dir().join("trace-snapshot.scen.json");
+    world.write_scenario_trace(&trace_path);
+    self::world().run(&trace_path);
+    std::fs::remove_file(trace_path).unwrap();
+}
+
+#[test]
+fn adder_fork() {
+    let mut world = deployed_world();
+    let mut fork = world.fork();
+
+    add(&mut fork, "3");
+    check_sum(&mut fork, "8");
+    check_sum(&mut world, "5");
+
+    add(&mut world, "1");
+    check_sum(&mut world, "6");
+    check_sum(&mut fork, "8");
+}
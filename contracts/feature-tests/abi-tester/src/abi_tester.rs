@@ -117,6 +117,10 @@ pub trait AbiTester {
     #[storage_mapper("sample_storage_mapper")]
     fn sample_storage_mapper(&self) -> SingleValueMapper<OnlyShowsUpAsNestedInSingleValueMapper>;
 
+    /// Storage keys appear in the ABI even when they are not exposed as endpoints.
+    #[storage_mapper("address_to_id")]
+    fn address_to_id(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
     #[view]
     fn item_for_vec(&self) -> Vec<OnlyShowsUpAsNestedInVec> {
         Vec::new()
@@ -0,0 +1,67 @@
+use multiversx_sc::{contract_base::ContractAbiProvider, formatter::hex_util};
+use multiversx_sc_scenario::{
+    fuzz::{AbiArgGenerator, FuzzRng},
+    scenario_model::*,
+    *,
+};
+
+const ABI_TESTER_PATH_EXPR: &str = "file:output/abi-tester.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/abi-tester");
+
+    blockchain.register_contract(ABI_TESTER_PATH_EXPR, abi_tester::ContractBuilder);
+    blockchain
+}
+
+fn hex_expr(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        String::new()
+    } else {
+        format!("0x{}", hex_util::encode_bytes_as_hex(bytes))
+    }
+}
+
+/// The echo endpoints return their argument unchanged, so any well-typed, canonically encoded
+/// argument must come back exactly the same.
+#[test]
+fn abi_tester_generated_args_round_trip() {
+    let abi = abi_tester::AbiProvider::abi();
+    let generator = AbiArgGenerator::new(&abi.type_descriptions, &[], &[]);
+    let mut rng = FuzzRng::new(7);
+
+    let mut world = world();
+    let code = world.code_expression(ABI_TESTER_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:owner", Account::new().nonce(1))
+            .new_address("address:owner", 1, "sc:abi-tester"),
+    );
+    let mut deploy = ScDeployStep::new().from("address:owner").code(code);
+    for argument in generator.generate_inputs(&abi.constructors[0].inputs, &mut rng) {
+        deploy = deploy.argument(&hex_expr(&argument));
+    }
+    world.sc_deploy(deploy);
+
+    for endpoint_name in ["echo_abi_test_type", "echo_enum"] {
+        let endpoint = abi
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == endpoint_name)
+            .unwrap();
+        for _ in 0..20 {
+            let arguments = generator.generate_inputs(&endpoint.inputs, &mut rng);
+            let mut step = ScCallStep::new()
+                .from("address:owner")
+                .to("sc:abi-tester")
+                .function(endpoint_name);
+            for argument in &arguments {
+                step = step.argument(argument.as_slice());
+            }
+            world.sc_call_use_raw_response(step, |response| {
+                assert_eq!(response.out, arguments);
+            });
+        }
+    }
+}
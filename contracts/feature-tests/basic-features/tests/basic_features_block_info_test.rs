@@ -0,0 +1,230 @@
+use std::time::Duration;
+
+use basic_features::block_info_features::BlockInfoFeatures;
+use multiversx_sc_scenario::{scenario_model::*, *};
+
+const BASIC_FEATURES_PATH_EXPR: &str = "file:output/basic-features.wasm";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/basic-features");
+
+    blockchain.register_contract(BASIC_FEATURES_PATH_EXPR, basic_features::ContractBuilder);
+    blockchain
+}
+
+fn setup() -> (
+    ScenarioWorld,
+    WhiteboxContract<basic_features::ContractObj<DebugApi>>,
+) {
+    let mut world = world();
+    let basic_features_code = world.code_expression(BASIC_FEATURES_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(
+                "sc:basic-features",
+                Account::new().code(basic_features_code),
+            )
+            .block_timestamp(1_000)
+            .block_nonce(10)
+            .block_round(20)
+            .block_epoch(0),
+    );
+    let whitebox = WhiteboxContract::new("sc:basic-features", basic_features::contract_obj);
+    (world, whitebox)
+}
+
+#[test]
+fn block_info_mine_blocks() {
+    let (mut world, whitebox) = setup();
+
+    world.mine_blocks(1);
+    world.whitebox_query(&whitebox, |sc| {
+        assert_eq!(sc.get_block_nonce(), 11);
+        assert_eq!(sc.get_block_round(), 21);
+        assert_eq!(sc.get_block_timestamp(), 1_006);
+        assert_eq!(sc.get_prev_block_nonce(), 10);
+        assert_eq!(sc.get_prev_block_round(), 20);
+        assert_eq!(sc.get_prev_block_timestamp(), 1_000);
+    });
+
+    world.mine_blocks(5);
+    world.whitebox_query(&whitebox, |sc| {
+        assert_eq!(sc.get_block_nonce(), 16);
+        assert_eq!(sc.get_block_round(), 26);
+        assert_eq!(sc.get_block_timestamp(), 1_036);
+        assert_eq!(sc.get_prev_block_nonce(), 15);
+        assert_eq!(sc.get_prev_block_round(), 25);
+        assert_eq!(sc.get_prev_block_timestamp(), 1_030);
+        assert_eq!(sc.get_block_epoch(), 0);
+        assert_ne!(sc.get_block_random_seed(), sc.get_prev_block_random_seed());
+    });
+}
+
+#[test]
+fn block_info_advance_time() {
+    let (mut world, whitebox) = setup();
+
+    world
+        .set_round_duration(4)
+        .set_rounds_per_epoch(100)
+        .advance_time(Duration::from_secs(600));
+    world.whitebox_query(&whitebox, |sc| {
+        assert_eq!(sc.get_block_timestamp(), 1_600);
+        assert_eq!(sc.get_block_round(), 170);
+        assert_eq!(sc.get_block_nonce(), 160);
+        assert_eq!(sc.get_block_epoch(), 1);
+        assert_eq!(sc.get_prev_block_timestamp(), 1_596);
+        assert_eq!(sc.get_prev_block_round(), 169);
+        assert_eq!(sc.get_prev_block_epoch(), 1);
+    });
+}
+
+#[test]
+fn block_info_jump_to_epoch() {
+    let (mut world, whitebox) = setup();
+
+    world.set_rounds_per_epoch(100).jump_to_epoch(3);
+    world.whitebox_query(&whitebox, |sc| {
+        assert_eq!(sc.get_block_epoch(), 3);
+        assert_eq!(sc.get_block_round(), 300);
+        assert_eq!(sc.get_block_nonce(), 290);
+        assert_eq!(sc.get_block_timestamp(), 1_000 + 280 * 6);
+        assert_eq!(sc.get_prev_block_epoch(), 2);
+        assert_eq!(sc.get_prev_block_round(), 299);
+    });
+}
+
+#[test]
+#[should_panic = "cannot jump to epoch 0, current epoch is 0"]
+fn block_info_jump_to_past_epoch() {
+    let (mut world, _) = setup();
+    world.jump_to_epoch(0);
+}
+
+#[test]
+fn block_info_seeds_deterministic() {
+    let (mut world_1, whitebox) = setup();
+    let (mut world_2, _) = setup();
+    world_1.mine_blocks(3);
+    world_2.mine_blocks(3);
+
+    let mut seed_1 = Vec::new();
+    world_1.whitebox_query(&whitebox, |sc| {
+        seed_1 = sc.get_block_random_seed().to_byte_array().to_vec();
+    });
+    world_2.whitebox_query(&whitebox, |sc| {
+        assert_eq!(sc.get_block_random_seed().to_byte_array().to_vec(), seed_1);
+    });
+}
+
+#[test]
+fn block_info_seed_policy_list() {
+    let (mut world, whitebox) = setup();
+
+    world.set_block_seed_policy(BlockSeedPolicy::list(vec![[1u8; 48], [2u8; 48]]));
+    world.whitebox_query(&whitebox, |sc| {
+        assert_eq!(sc.get_block_random_seed().to_byte_array(), [1u8; 48]);
+    });
+
+    world.mine_blocks(1);
+    world.whitebox_query(&whitebox, |sc| {
+        assert_eq!(sc.get_prev_block_random_seed().to_byte_array(), [1u8; 48]);
+        assert_eq!(sc.get_block_random_seed().to_byte_array(), [2u8; 48]);
+    });
+
+    world.mine_blocks(2);
+    world.whitebox_query(&whitebox, |sc| {
+        assert_eq!(sc.get_prev_block_random_seed().to_byte_array(), [1u8; 48]);
+        assert_eq!(sc.get_block_random_seed().to_byte_array(), [2u8; 48]);
+    });
+}
+
+#[test]
+fn block_info_seed_policy_master_seed() {
+    let (mut world_1, whitebox) = setup();
+    let (mut world_2, _) = setup();
+
+    // the seed of a block only depends on the master seed and the block nonce
+    world_1
+        .set_block_seed_policy(BlockSeedPolicy::master_seed(7))
+        .mine_blocks(4);
+    world_2
+        .set_block_seed_policy(BlockSeedPolicy::master_seed(7))
+        .mine_blocks(1)
+        .mine_blocks(3);
+
+    let mut seed_1 = Vec::new();
+    world_1.whitebox_query(&whitebox, |sc| {
+        seed_1 = sc.get_block_random_seed().to_byte_array().to_vec();
+    });
+    world_2.whitebox_query(&whitebox, |sc| {
+        assert_eq!(sc.get_block_random_seed().to_byte_array().to_vec(), seed_1);
+    });
+
+    world_2.set_block_seed_policy(BlockSeedPolicy::master_seed(8));
+    world_2.whitebox_query(&whitebox, |sc| {
+        assert_ne!(sc.get_block_random_seed().to_byte_array().to_vec(), seed_1);
+    });
+}
+
+#[test]
+fn block_info_seed_policy_traced() {
+    let (mut world, _) = setup();
+    world.start_trace();
+    world
+        .set_block_seed_policy(BlockSeedPolicy::fixed([3u8; 48]))
+        .mine_blocks(1);
+
+    let trace_path = world.current_dir().join("trace-block-seed.scen.json");
+    world.write_scenario_trace(&trace_path);
+    let trace = std::fs::read_to_string(&trace_path).unwrap();
+    std::fs::remove_file(trace_path).unwrap();
+
+    assert!(trace.contains("block random seed: fixed"));
+    assert!(trace.contains(&format!("0x{}", "03".repeat(48))));
+}
+
+fn call_set_random(world: &mut ScenarioWorld) -> Vec<u8> {
+    let mut random = Vec::new();
+    world.sc_call_use_raw_response(
+        ScCallStep::new()
+            .from("address:owner")
+            .to("sc:basic-features")
+            .function("mbuffer_set_random")
+            .argument("16"),
+        |response| random = response.out[0].clone(),
+    );
+    random
+}
+
+#[test]
+fn block_info_seed_policy_per_tx() {
+    let mut world = world();
+    world.start_trace();
+    let basic_features_code = world.code_expression(BASIC_FEATURES_PATH_EXPR);
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:owner", Account::new())
+            .put_account(
+                "sc:basic-features",
+                Account::new().code(basic_features_code),
+            ),
+    );
+
+    // no policy set: transactions in the same block, without ids, see the same random sequence
+    assert_eq!(call_set_random(&mut world), call_set_random(&mut world));
+
+    world.set_block_seed_policy(BlockSeedPolicy::master_seed(5));
+    let random_1 = call_set_random(&mut world);
+    let random_2 = call_set_random(&mut world);
+    assert_ne!(random_1, random_2);
+
+    // the new seeds are traced, so the trace replays with the same results
+    let trace_path = world
+        .current_dir()
+        .join("trace-block-seed-per-tx.scen.json");
+    world.write_scenario_trace(&trace_path);
+    self::world().run(&trace_path);
+    std::fs::remove_file(trace_path).unwrap();
+}
@@ -0,0 +1,67 @@
+use multiversx_sc_scenario::{scenario::run_coverage::ScenarioCoverage, *};
+
+fn world(coverage: &ScenarioCoverage) -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
+    blockchain.register_contract(
+        "file:forwarder/output/forwarder.wasm",
+        forwarder::ContractBuilder,
+    );
+    blockchain.register_contract(
+        "file:promises-features/output/promises-features.wasm",
+        promises_features::ContractBuilder,
+    );
+
+    let vault_sc_config =
+        meta::multi_contract_config::<vault::AbiProvider>(&blockchain.current_dir().join("vault"));
+    blockchain.register_contract_variant(
+        "file:vault/output/vault.wasm",
+        vault::ContractBuilder,
+        vault_sc_config.find_contract("vault"),
+    );
+
+    blockchain
+        .start_coverage(coverage)
+        .register_coverage_contract::<vault::AbiProvider>("file:vault/output/vault.wasm");
+    blockchain
+}
+
+/// The vault is only ever called by other contracts in these scenarios.
+#[test]
+fn composability_coverage_nested_calls() {
+    let coverage = ScenarioCoverage::new();
+    world(&coverage).run("scenarios/forwarder_call_sync_accept_egld.scen.json");
+    world(&coverage).run("scenarios/forwarder_call_async_accept_egld.scen.json");
+    world(&coverage).run("scenarios/promises_call_async_accept_egld.scen.json");
+
+    let report = coverage.report();
+    let vault_endpoint = |name: &str| {
+        report.contracts[0]
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == name)
+            .unwrap()
+    };
+
+    // sync call
+    let accept_funds_echo_payment = vault_endpoint("accept_funds_echo_payment");
+    assert_eq!(accept_funds_echo_payment.hit_count, 1);
+    assert!(accept_funds_echo_payment
+        .scenarios
+        .contains("forwarder_call_sync_accept_egld.scen.json (1)"));
+
+    // async call and promise
+    let accept_funds = vault_endpoint("accept_funds");
+    assert_eq!(accept_funds.hit_count, 2);
+    assert!(accept_funds
+        .scenarios
+        .contains("forwarder_call_async_accept_egld.scen.json (1)"));
+    assert!(accept_funds
+        .scenarios
+        .contains("promises_call_async_accept_egld.scen.json (1)"));
+
+    assert!(report
+        .never_exercised()
+        .contains(&("Vault".to_string(), "echo_arguments".to_string())));
+}
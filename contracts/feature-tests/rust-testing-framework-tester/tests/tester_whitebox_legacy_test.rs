@@ -239,6 +239,59 @@ fn test_sc_payment_ok() {
     wrapper.check_egld_balance(sc_wrapper.address_ref(), &rust_biguint!(3_000));
 }
 
+#[test]
+fn test_snapshot_revert() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let caller_addr = wrapper.create_user_account(&rust_biguint!(1_000));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(2_000),
+        Some(&caller_addr),
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let snapshot = wrapper.snapshot();
+
+    wrapper
+        .execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(1_000), |sc| {
+            sc.receive_egld();
+        })
+        .assert_ok();
+    let new_user_addr = wrapper.create_user_account(&rust_biguint!(500));
+
+    wrapper.revert_to(snapshot);
+    wrapper.check_egld_balance(&caller_addr, &rust_biguint!(1_000));
+    wrapper.check_egld_balance(sc_wrapper.address_ref(), &rust_biguint!(2_000));
+    wrapper.check_egld_balance(&new_user_addr, &rust_biguint!(0));
+
+    // address generation is also reverted
+    assert_eq!(
+        wrapper.create_user_account(&rust_biguint!(0)),
+        new_user_addr
+    );
+}
+
+#[test]
+fn test_fork() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let caller_addr = wrapper.create_user_account(&rust_biguint!(1_000));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(2_000),
+        Some(&caller_addr),
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let mut fork = wrapper.fork();
+    fork.execute_tx(&caller_addr, &sc_wrapper, &rust_biguint!(1_000), |sc| {
+        sc.receive_egld();
+    })
+    .assert_ok();
+
+    fork.check_egld_balance(sc_wrapper.address_ref(), &rust_biguint!(3_000));
+    wrapper.check_egld_balance(sc_wrapper.address_ref(), &rust_biguint!(2_000));
+    wrapper.check_egld_balance(&caller_addr, &rust_biguint!(1_000));
+}
+
 #[test]
 fn test_sc_payment_reverted() {
     let mut wrapper = BlockchainStateWrapper::new();
@@ -0,0 +1,17 @@
+multiversx_sc::imports!();
+
+use multiversx_sc_modules::access_control;
+
+/// Example of a module using the access control module.
+#[multiversx_sc::module]
+pub trait AccessControlModExample: access_control::AccessControlModule {
+    #[only_role("minter")]
+    #[endpoint(mintExample)]
+    fn mint_example(&self, amount: u64) {
+        self.minted_example().update(|minted| *minted += amount);
+    }
+
+    #[view(getMintedExample)]
+    #[storage_mapper("minted_example")]
+    fn minted_example(&self) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,48 @@
+multiversx_sc::imports!();
+
+use multiversx_sc_modules::reentrancy_guard;
+
+/// Example of a module using the reentrancy guard.
+///
+/// The endpoints call themselves back via sync calls, as a malicious callee would.
+#[multiversx_sc::module]
+pub trait ReentrancyGuardModExample: reentrancy_guard::ReentrancyGuardModule {
+    #[non_reentrant]
+    #[endpoint(nonReentrantCallSelf)]
+    fn non_reentrant_call_self(&self, depth: u32) {
+        self.call_self("nonReentrantCallSelf", depth);
+    }
+
+    /// Ends the execution with an async call, so the end of the endpoint is never reached.
+    #[non_reentrant]
+    #[endpoint(nonReentrantAsyncCallSelf)]
+    fn non_reentrant_async_call_self(&self) {
+        self.reentrancy_example_calls().update(|calls| *calls += 1);
+        let sc_address = self.blockchain().get_sc_address();
+        self.send()
+            .contract_call::<()>(sc_address, "nonReentrantCallSelf")
+            .argument(&0u32)
+            .async_call()
+            .call_and_exit_ignore_callback();
+    }
+
+    #[endpoint(reentrantCallSelf)]
+    fn reentrant_call_self(&self, depth: u32) {
+        self.call_self("reentrantCallSelf", depth);
+    }
+
+    fn call_self(&self, endpoint_name: &str, depth: u32) {
+        self.reentrancy_example_calls().update(|calls| *calls += 1);
+        if depth > 0 {
+            let sc_address = self.blockchain().get_sc_address();
+            self.send()
+                .contract_call::<()>(sc_address, endpoint_name)
+                .argument(&(depth - 1))
+                .execute_on_dest_context::<()>();
+        }
+    }
+
+    #[view(getReentrancyExampleCalls)]
+    #[storage_mapper("reentrancy_example_calls")]
+    fn reentrancy_example_calls(&self) -> SingleValueMapper<u32>;
+}
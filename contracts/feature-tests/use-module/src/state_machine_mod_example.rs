@@ -0,0 +1,65 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use multiversx_sc_modules::state_machine;
+
+/// Lifecycle of a fundraiser, as an example of a state machine.
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    StateMachine,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub enum FundraiserStatus {
+    #[transition(to = Successful, guard = "target reached")]
+    #[transition(to = Failed)]
+    Funding,
+    #[transition(to = Funding)]
+    Failed,
+    Successful,
+}
+
+/// Example of a module using the state machine module.
+#[multiversx_sc::module]
+pub trait StateMachineModExample: state_machine::StateMachineModule {
+    #[endpoint(fundraiserDeposit)]
+    fn fundraiser_deposit(&self, amount: u64) {
+        self.require_state(&self.fundraiser_status(), &FundraiserStatus::Funding);
+        self.fundraiser_raised().update(|raised| *raised += amount);
+    }
+
+    #[endpoint(fundraiserClose)]
+    fn fundraiser_close(&self, target: u64) {
+        let next = if self.fundraiser_raised().get() >= target {
+            FundraiserStatus::Successful
+        } else {
+            FundraiserStatus::Failed
+        };
+        self.transition_state_guarded(&self.fundraiser_status(), next, |_| {
+            self.fundraiser_raised().get() >= target
+        });
+    }
+
+    #[endpoint(fundraiserRestart)]
+    fn fundraiser_restart(&self) {
+        self.transition_state(&self.fundraiser_status(), FundraiserStatus::Funding);
+    }
+
+    #[view(getFundraiserStatus)]
+    fn get_fundraiser_status(&self) -> FundraiserStatus {
+        self.current_state(&self.fundraiser_status())
+    }
+
+    #[storage_mapper("fundraiser_status")]
+    fn fundraiser_status(&self) -> SingleValueMapper<FundraiserStatus>;
+
+    #[storage_mapper("fundraiser_raised")]
+    fn fundraiser_raised(&self) -> SingleValueMapper<u64>;
+}
@@ -1,5 +1,6 @@
 #![no_std]
 
+pub mod access_control_mod_example;
 mod contract_base_full_path_mod;
 mod contract_base_mod;
 mod internal_mod_a;
@@ -12,6 +13,8 @@ mod only_admin_derived_mod;
 mod only_admin_mod;
 mod only_owner_derived_mod;
 mod only_owner_mod;
+pub mod reentrancy_guard_mod_example;
+pub mod state_machine_mod_example;
 pub mod token_merge_mod_impl;
 
 multiversx_sc::imports!();
@@ -38,8 +41,12 @@ pub trait UseModule:
     + only_owner_derived_mod::OnlyOwnerDerivedTestModule
     + only_admin_mod::OnlyAdminTestModule
     + only_admin_derived_mod::OnlyAdminDerivedTestModule
+    + access_control_mod_example::AccessControlModExample
     + ongoing_operation_mod_example::OngoingOperationModExample
+    + reentrancy_guard_mod_example::ReentrancyGuardModExample
+    + state_machine_mod_example::StateMachineModExample
     + token_merge_mod_impl::TokenMergeModImpl
+    + multiversx_sc_modules::access_control::AccessControlModule
     + multiversx_sc_modules::claim_developer_rewards::ClaimDeveloperRewardsModule
     + multiversx_sc_modules::dns::DnsModule
     + multiversx_sc_modules::esdt::EsdtModule
@@ -48,12 +55,15 @@ pub trait UseModule:
     + multiversx_sc_modules::governance::governance_configurable::GovernanceConfigurablePropertiesModule
     + multiversx_sc_modules::governance::governance_events::GovernanceEventsModule
     + multiversx_sc_modules::pause::PauseModule
+    + multiversx_sc_modules::reentrancy_guard::ReentrancyGuardModule
     + multiversx_sc_modules::staking::StakingModule
     + multiversx_sc_modules::token_merge::TokenMergeModule
     + multiversx_sc_modules::token_merge::merged_token_setup::MergedTokenSetupModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::only_admin::OnlyAdminModule
     + multiversx_sc_modules::ongoing_operation::OngoingOperationModule
+    + multiversx_sc_modules::state_machine::StateMachineModule
+    + multiversx_sc_modules::timelock::TimelockModule
 {
     /// Validates that the "featureName" feature is on.
     /// Uses the `feature_guard!` macro.
@@ -0,0 +1,146 @@
+use multiversx_sc_modules::access_control::{AccessControlModule, DEFAULT_ADMIN_ROLE};
+use multiversx_sc_scenario::{
+    managed_address, managed_buffer,
+    scenario_model::{Account, AddressValue, ScCallStep, ScDeployStep, SetStateStep, TxExpect},
+    ScenarioWorld, WhiteboxContract,
+};
+use use_module::access_control_mod_example::AccessControlModExample;
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const MINTER_ADDRESS_EXPR: &str = "address:minter";
+const MINTER_ADMIN_ADDRESS_EXPR: &str = "address:minter-admin";
+const USE_MODULE_ADDRESS_EXPR: &str = "sc:use-module";
+const USE_MODULE_PATH_EXPR: &str = "file:output/use-module.wasm";
+
+const MINTER_ROLE: &[u8] = b"minter";
+const MINTER_ADMIN_ROLE: &[u8] = b"minter-admin";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+
+    blockchain.register_contract(USE_MODULE_PATH_EXPR, use_module::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn access_control_module_test() {
+    let mut world = world();
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(MINTER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(MINTER_ADMIN_ADDRESS_EXPR, Account::new().nonce(1))
+            .new_address(OWNER_ADDRESS_EXPR, 1, USE_MODULE_ADDRESS_EXPR),
+    );
+
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+    let use_module_code = world.code_expression(USE_MODULE_PATH_EXPR);
+
+    world.whitebox_deploy(
+        &use_module_whitebox,
+        ScDeployStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .code(use_module_code),
+        |sc| {
+            sc.init_access_control(managed_address!(
+                &AddressValue::from(OWNER_ADDRESS_EXPR).to_address()
+            ));
+        },
+    );
+
+    // role checked by the endpoint, via #[only_role("minter")]
+    world.sc_call(
+        ScCallStep::new()
+            .from(MINTER_ADDRESS_EXPR)
+            .to(USE_MODULE_ADDRESS_EXPR)
+            .function("mintExample")
+            .argument("5")
+            .expect(TxExpect::user_error(
+                "str:Endpoint can only be called by role minter",
+            )),
+    );
+
+    // the default admin delegates the minter role to a dedicated admin role
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| {
+            sc.set_role_admin(
+                managed_buffer!(MINTER_ROLE),
+                managed_buffer!(MINTER_ADMIN_ROLE),
+            );
+            sc.grant_role(
+                managed_buffer!(MINTER_ADMIN_ROLE),
+                managed_address!(&AddressValue::from(MINTER_ADMIN_ADDRESS_EXPR).to_address()),
+            );
+        },
+    );
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.grant_role(
+                managed_buffer!(MINTER_ROLE),
+                managed_address!(&AddressValue::from(MINTER_ADDRESS_EXPR).to_address()),
+            )
+        },
+        |r| {
+            r.assert_user_error("Endpoint can only be called by role minter-admin");
+        },
+    );
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(MINTER_ADMIN_ADDRESS_EXPR),
+        |sc| {
+            sc.grant_role(
+                managed_buffer!(MINTER_ROLE),
+                managed_address!(&AddressValue::from(MINTER_ADDRESS_EXPR).to_address()),
+            )
+        },
+    );
+
+    world.sc_call(
+        ScCallStep::new()
+            .from(MINTER_ADDRESS_EXPR)
+            .to(USE_MODULE_ADDRESS_EXPR)
+            .function("mintExample")
+            .argument("5"),
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(sc.minted_example().get(), 5);
+        assert_eq!(
+            sc.get_role_admin(&managed_buffer!(MINTER_ROLE)),
+            managed_buffer!(MINTER_ADMIN_ROLE)
+        );
+        assert_eq!(sc.roles().len(), 3);
+        assert!(sc.roles().contains(&managed_buffer!(DEFAULT_ADMIN_ROLE)));
+        assert!(sc.has_role(
+            &managed_buffer!(MINTER_ROLE),
+            &managed_address!(&AddressValue::from(MINTER_ADDRESS_EXPR).to_address())
+        ));
+    });
+
+    // renounced roles are no longer listed
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(MINTER_ADDRESS_EXPR),
+        |sc| sc.renounce_role(managed_buffer!(MINTER_ROLE)),
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert!(sc.role_members(&managed_buffer!(MINTER_ROLE)).is_empty());
+        assert!(!sc.roles().contains(&managed_buffer!(MINTER_ROLE)));
+    });
+    world.sc_call(
+        ScCallStep::new()
+            .from(MINTER_ADDRESS_EXPR)
+            .to(USE_MODULE_ADDRESS_EXPR)
+            .function("mintExample")
+            .argument("5")
+            .expect(TxExpect::user_error(
+                "str:Endpoint can only be called by role minter",
+            )),
+    );
+}
@@ -0,0 +1,160 @@
+use multiversx_sc_modules::reentrancy_guard::ReentrancyGuardModule;
+use multiversx_sc_scenario::{
+    multiversx_chain_vm::types::VMAddress,
+    scenario_model::{
+        Account, AddressValue, ScCallStep, ScDeployStep, ScQueryStep, SetStateStep, TxExpect,
+    },
+    ScenarioWorld, WhiteboxContract,
+};
+use use_module::reentrancy_guard_mod_example::ReentrancyGuardModExample;
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const USE_MODULE_ADDRESS_EXPR: &str = "sc:use-module";
+const USE_MODULE_PATH_EXPR: &str = "file:output/use-module.wasm";
+
+const REENTRANT_CALL_ERR_MSG: &str = "Endpoint cannot be called reentrantly";
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+
+    blockchain.register_contract(USE_MODULE_PATH_EXPR, use_module::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn reentrancy_guard_module_test() {
+    let mut world = world();
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .new_address(OWNER_ADDRESS_EXPR, 1, USE_MODULE_ADDRESS_EXPR),
+    );
+
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+    let use_module_code = world.code_expression(USE_MODULE_PATH_EXPR);
+
+    world.whitebox_deploy(
+        &use_module_whitebox,
+        ScDeployStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .code(use_module_code),
+        |_| {},
+    );
+
+    // the lock is released after each call
+    for _ in 0..2 {
+        world.sc_call(
+            ScCallStep::new()
+                .from(OWNER_ADDRESS_EXPR)
+                .to(USE_MODULE_ADDRESS_EXPR)
+                .function("nonReentrantCallSelf")
+                .argument("0"),
+        );
+    }
+    world.sc_query(
+        ScQueryStep::new()
+            .to(USE_MODULE_ADDRESS_EXPR)
+            .function("isReentrancyLocked")
+            .expect(TxExpect::ok().result("false")),
+    );
+
+    // ending with an async call, the lock is released before the call
+    for _ in 0..2 {
+        world.sc_call(
+            ScCallStep::new()
+                .from(OWNER_ADDRESS_EXPR)
+                .to(USE_MODULE_ADDRESS_EXPR)
+                .function("nonReentrantAsyncCallSelf"),
+        );
+    }
+    world.sc_query(
+        ScQueryStep::new()
+            .to(USE_MODULE_ADDRESS_EXPR)
+            .function("isReentrancyLocked")
+            .expect(TxExpect::ok().result("false")),
+    );
+
+    // calling back into #[non_reentrant] endpoint
+    world.sc_call(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .to(USE_MODULE_ADDRESS_EXPR)
+            .function("nonReentrantCallSelf")
+            .argument("1")
+            .expect(TxExpect::user_error(format!(
+                "str:{REENTRANT_CALL_ERR_MSG}"
+            ))),
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(sc.reentrancy_example_calls().get(), 6);
+        assert!(!sc.is_reentrancy_locked());
+    });
+
+    // the explicit lock guards the annotated endpoints too
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.with_non_reentrant(|| sc.non_reentrant_call_self(1));
+        },
+        |r| {
+            r.assert_user_error(REENTRANT_CALL_ERR_MSG);
+        },
+    );
+
+    // unguarded reentrancy is reported by the debugger
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.reentrant_call_self(2),
+        |r| {
+            let use_module_address = VMAddress::from(
+                AddressValue::from(USE_MODULE_ADDRESS_EXPR)
+                    .to_address()
+                    .as_array(),
+            );
+            assert_eq!(r.reentrant_calls.len(), 2);
+            for reentrant_call in &r.reentrant_calls {
+                assert_eq!(reentrant_call.caller, use_module_address);
+                assert_eq!(reentrant_call.contract, use_module_address);
+                assert_eq!(reentrant_call.endpoint.as_str(), "reentrantCallSelf");
+            }
+        },
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(sc.reentrancy_example_calls().get(), 9);
+    });
+
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.non_reentrant_call_self(0),
+        |r| r.assert_no_reentrant_calls(),
+    );
+    // also reported in the responses of regular calls
+    world.sc_call_use_raw_response(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .to(USE_MODULE_ADDRESS_EXPR)
+            .function("reentrantCallSelf")
+            .argument("1"),
+        |response| {
+            assert_eq!(response.reentrant_calls.len(), 1);
+            assert_eq!(
+                response.reentrant_calls[0].endpoint.as_str(),
+                "reentrantCallSelf"
+            );
+        },
+    );
+    world.sc_call_use_raw_response(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .to(USE_MODULE_ADDRESS_EXPR)
+            .function("nonReentrantCallSelf")
+            .argument("0"),
+        |response| assert!(response.reentrant_calls.is_empty()),
+    );
+}
@@ -0,0 +1,134 @@
+use multiversx_sc::state_machine::{StateMachine, StateTransitionCheck};
+use multiversx_sc_modules::state_machine::StateMachineModule;
+use multiversx_sc_scenario::{
+    scenario_model::{Account, ScCallStep, ScDeployStep, ScQueryStep, SetStateStep, TxExpect},
+    ScenarioWorld, WhiteboxContract,
+};
+use use_module::state_machine_mod_example::{FundraiserStatus, StateMachineModExample};
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const USE_MODULE_ADDRESS_EXPR: &str = "sc:use-module";
+const USE_MODULE_PATH_EXPR: &str = "file:output/use-module.wasm";
+
+const TARGET: u64 = 100;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+
+    blockchain.register_contract(USE_MODULE_PATH_EXPR, use_module::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn state_machine_derive_test() {
+    assert_eq!(FundraiserStatus::initial_state(), FundraiserStatus::Funding);
+    assert_eq!(FundraiserStatus::Successful.state_name(), "Successful");
+    assert_eq!(
+        FundraiserStatus::Funding.check_transition(&FundraiserStatus::Successful),
+        StateTransitionCheck::Guarded("target reached")
+    );
+    assert_eq!(
+        FundraiserStatus::Funding.check_transition(&FundraiserStatus::Failed),
+        StateTransitionCheck::Allowed
+    );
+    assert!(FundraiserStatus::Failed.can_transition_to(&FundraiserStatus::Funding));
+    assert!(!FundraiserStatus::Successful.can_transition_to(&FundraiserStatus::Funding));
+    assert!(!FundraiserStatus::Funding.can_transition_to(&FundraiserStatus::Funding));
+}
+
+#[test]
+fn state_machine_module_test() {
+    let mut world = world();
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .new_address(OWNER_ADDRESS_EXPR, 1, USE_MODULE_ADDRESS_EXPR),
+    );
+
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+    let use_module_code = world.code_expression(USE_MODULE_PATH_EXPR);
+
+    world.whitebox_deploy(
+        &use_module_whitebox,
+        ScDeployStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .code(use_module_code),
+        |_| {},
+    );
+
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(sc.get_fundraiser_status(), FundraiserStatus::Funding);
+    });
+
+    // not enough raised, only the failed state is reachable
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.fundraiser_deposit(TARGET / 2),
+    );
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.fundraiser_close(TARGET),
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(sc.get_fundraiser_status(), FundraiserStatus::Failed);
+    });
+
+    // deposits are only accepted while funding
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).no_expect(),
+        |sc| sc.fundraiser_deposit(TARGET),
+        |r| {
+            r.assert_user_error("Invalid state: expected Funding, found Failed");
+        },
+    );
+
+    // failed -> successful is not declared
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).no_expect(),
+        |sc| sc.transition_state(&sc.fundraiser_status(), FundraiserStatus::Successful),
+        |r| {
+            r.assert_user_error("Invalid state transition from Failed to Successful");
+        },
+    );
+
+    // guarded transition, checked without the guard
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.fundraiser_restart(),
+    );
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).no_expect(),
+        |sc| sc.transition_state(&sc.fundraiser_status(), FundraiserStatus::Successful),
+        |r| {
+            r.assert_user_error(
+                "State transition from Funding to Successful requires: target reached",
+            );
+        },
+    );
+
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.fundraiser_deposit(TARGET / 2),
+    );
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR),
+        |sc| sc.fundraiser_close(TARGET),
+    );
+    world.sc_query(
+        ScQueryStep::new()
+            .to(USE_MODULE_ADDRESS_EXPR)
+            .function("getFundraiserStatus")
+            .expect(TxExpect::ok().result("2")),
+    );
+}
@@ -0,0 +1,295 @@
+use multiversx_sc::types::{BigUint, FunctionCall};
+use multiversx_sc_modules::{
+    only_admin::OnlyAdminModule,
+    timelock::{TimelockDeadline, TimelockDelay, TimelockModule},
+};
+use multiversx_sc_scenario::{
+    managed_address,
+    multiversx_chain_vm::tx_mock::TxResult,
+    scenario_model::{Account, AddressValue, ScCallStep, ScDeployStep, SetStateStep},
+    ScenarioWorld, WhiteboxContract,
+};
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const PROPOSER_ADDRESS_EXPR: &str = "address:proposer";
+const EXECUTOR_ADDRESS_EXPR: &str = "address:executor";
+const USE_MODULE_ADDRESS_EXPR: &str = "sc:use-module";
+const USE_MODULE_PATH_EXPR: &str = "file:output/use-module.wasm";
+
+const MIN_DELAY_ROUNDS: u64 = 10;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/use-module");
+
+    blockchain.register_contract(USE_MODULE_PATH_EXPR, use_module::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn timelock_module_test() {
+    let mut world = world();
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(PROPOSER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(EXECUTOR_ADDRESS_EXPR, Account::new().nonce(1))
+            .new_address(OWNER_ADDRESS_EXPR, 1, USE_MODULE_ADDRESS_EXPR)
+            .block_round(1),
+    );
+
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+    let use_module_code = world.code_expression(USE_MODULE_PATH_EXPR);
+
+    world.whitebox_deploy(
+        &use_module_whitebox,
+        ScDeployStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .code(use_module_code),
+        |sc| {
+            sc.init_timelock(TimelockDelay::Rounds(MIN_DELAY_ROUNDS));
+            sc.admins().insert(managed_address!(
+                &AddressValue::from(OWNER_ADDRESS_EXPR).to_address()
+            ));
+        },
+    );
+
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.add_proposer(managed_address!(
+                &AddressValue::from(PROPOSER_ADDRESS_EXPR).to_address()
+            ));
+            sc.add_executor(managed_address!(
+                &AddressValue::from(EXECUTOR_ADDRESS_EXPR).to_address()
+            ));
+            // already added, no event
+            sc.add_executor(managed_address!(
+                &AddressValue::from(EXECUTOR_ADDRESS_EXPR).to_address()
+            ));
+        },
+        |r| {
+            r.assert_ok();
+            assert_eq!(
+                event_topics(&r),
+                vec![
+                    event_topics_of("proposerAdded", PROPOSER_ADDRESS_EXPR),
+                    event_topics_of("executorAdded", EXECUTOR_ADDRESS_EXPR),
+                ]
+            );
+        },
+    );
+
+    // the delay can only be changed through the timelock
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).no_expect(),
+        |sc| sc.update_min_delay(TimelockDelay::Seconds(60)),
+        |r| {
+            r.assert_user_error("Endpoint can only be called through the timelock");
+        },
+    );
+
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(EXECUTOR_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.schedule_operation(
+                managed_address!(&AddressValue::from(USE_MODULE_ADDRESS_EXPR).to_address()),
+                BigUint::zero(),
+                FunctionCall::new("updateMinDelay").argument(&TimelockDelay::Seconds(60)),
+            );
+        },
+        |r| {
+            r.assert_user_error("Only proposers can schedule operations");
+        },
+    );
+
+    let mut operation_id = 0;
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(PROPOSER_ADDRESS_EXPR),
+        |sc| {
+            operation_id = sc.schedule_operation(
+                managed_address!(&AddressValue::from(USE_MODULE_ADDRESS_EXPR).to_address()),
+                BigUint::zero(),
+                FunctionCall::new("updateMinDelay").argument(&TimelockDelay::Seconds(60)),
+            );
+            assert_eq!(
+                sc.get_operation(operation_id)
+                    .into_option()
+                    .unwrap()
+                    .ready_at,
+                TimelockDeadline::Round(1 + MIN_DELAY_ROUNDS)
+            );
+        },
+    );
+
+    // too early
+    world.set_state_step(SetStateStep::new().block_round(MIN_DELAY_ROUNDS));
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(EXECUTOR_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.execute_operation(operation_id);
+        },
+        |r| {
+            r.assert_user_error("Operation is not ready");
+        },
+    );
+
+    world.set_state_step(SetStateStep::new().block_round(1 + MIN_DELAY_ROUNDS));
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert!(sc.is_operation_ready(operation_id));
+    });
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(PROPOSER_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.execute_operation(operation_id);
+        },
+        |r| {
+            r.assert_user_error("Only executors can execute operations");
+        },
+    );
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(EXECUTOR_ADDRESS_EXPR),
+        |sc| {
+            sc.execute_operation(operation_id);
+        },
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert_eq!(sc.timelock_min_delay().get(), TimelockDelay::Seconds(60));
+        assert!(sc.get_operation(operation_id).into_option().is_none());
+        assert!(sc.timelock_pending_operations().is_empty());
+    });
+
+    // executed operations are removed
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(EXECUTOR_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.execute_operation(operation_id);
+        },
+        |r| {
+            r.assert_user_error("Operation not found");
+        },
+    );
+
+    // cancel
+    world.whitebox_call(
+        &use_module_whitebox,
+        ScCallStep::new().from(PROPOSER_ADDRESS_EXPR),
+        |sc| {
+            operation_id = sc.schedule_operation(
+                managed_address!(&AddressValue::from(USE_MODULE_ADDRESS_EXPR).to_address()),
+                BigUint::zero(),
+                FunctionCall::new("updateMinDelay").argument(&TimelockDelay::Rounds(1)),
+            );
+            sc.cancel_operation(operation_id);
+        },
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert!(!sc.is_operation_ready(operation_id));
+        assert!(sc.timelock_pending_operations().is_empty());
+    });
+    // roles
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(OWNER_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.remove_executor(managed_address!(
+                &AddressValue::from(EXECUTOR_ADDRESS_EXPR).to_address()
+            ));
+            sc.remove_proposer(managed_address!(
+                &AddressValue::from(PROPOSER_ADDRESS_EXPR).to_address()
+            ));
+            // already removed, no event
+            sc.remove_proposer(managed_address!(
+                &AddressValue::from(PROPOSER_ADDRESS_EXPR).to_address()
+            ));
+        },
+        |r| {
+            r.assert_ok();
+            assert_eq!(
+                event_topics(&r),
+                vec![
+                    event_topics_of("executorRemoved", EXECUTOR_ADDRESS_EXPR),
+                    event_topics_of("proposerRemoved", PROPOSER_ADDRESS_EXPR),
+                ]
+            );
+        },
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert!(sc.timelock_proposers().is_empty());
+        assert!(sc.timelock_executors().is_empty());
+    });
+}
+
+#[test]
+fn timelock_module_delay_overflow_test() {
+    let mut world = world();
+
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+            .put_account(PROPOSER_ADDRESS_EXPR, Account::new().nonce(1))
+            .new_address(OWNER_ADDRESS_EXPR, 1, USE_MODULE_ADDRESS_EXPR)
+            .block_round(1),
+    );
+
+    let use_module_whitebox =
+        WhiteboxContract::new(USE_MODULE_ADDRESS_EXPR, use_module::contract_obj);
+    let use_module_code = world.code_expression(USE_MODULE_PATH_EXPR);
+
+    world.whitebox_deploy(
+        &use_module_whitebox,
+        ScDeployStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .code(use_module_code),
+        |sc| {
+            sc.init_timelock(TimelockDelay::Rounds(u64::MAX));
+            sc.timelock_proposers()
+                .insert(managed_address!(
+                    &AddressValue::from(PROPOSER_ADDRESS_EXPR).to_address()
+                ));
+        },
+    );
+
+    world.whitebox_call_check(
+        &use_module_whitebox,
+        ScCallStep::new().from(PROPOSER_ADDRESS_EXPR).no_expect(),
+        |sc| {
+            sc.schedule_operation(
+                managed_address!(&AddressValue::from(USE_MODULE_ADDRESS_EXPR).to_address()),
+                BigUint::zero(),
+                FunctionCall::new("updateMinDelay").argument(&TimelockDelay::Rounds(1)),
+            );
+        },
+        |r| {
+            r.assert_user_error("Timelock delay too long");
+        },
+    );
+    world.whitebox_query(&use_module_whitebox, |sc| {
+        assert!(sc.timelock_pending_operations().is_empty());
+    });
+}
+
+fn event_topics(tx_result: &TxResult) -> Vec<Vec<Vec<u8>>> {
+    tx_result
+        .result_logs
+        .iter()
+        .map(|log| log.topics.clone())
+        .collect()
+}
+
+fn event_topics_of(identifier: &str, address_expr: &str) -> Vec<Vec<u8>> {
+    vec![
+        identifier.as_bytes().to_vec(),
+        AddressValue::from(address_expr).to_address().to_vec(),
+    ]
+}
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           63
+// Endpoints:                           96
 // Async Callback:                       1
-// Total number of exported functions:  65
+// Total number of exported functions:  98
 
 #![no_std]
 #![allow(internal_features)]
@@ -31,11 +31,29 @@ multiversx_sc_wasm_adapter::endpoints! {
         call_derived_not_owner_only => call_derived_not_owner_only
         only_admin_mod_endpoint => only_admin_mod_endpoint
         call_derived_not_admin_only => call_derived_not_admin_only
+        mintExample => mint_example
+        getMintedExample => minted_example
         countTo100 => count_to_100
+        nonReentrantCallSelf => non_reentrant_call_self
+        nonReentrantAsyncCallSelf => non_reentrant_async_call_self
+        reentrantCallSelf => reentrant_call_self
+        getReentrancyExampleCalls => reentrancy_example_calls
+        fundraiserDeposit => fundraiser_deposit
+        fundraiserClose => fundraiser_close
+        fundraiserRestart => fundraiser_restart
+        getFundraiserStatus => get_fundraiser_status
         mergeTokens => merge_tokens_endpoint
         mergeTokensCustomAttributes => merge_tokens_custom_attributes_endpoint
         splitTokens => split_tokens_endpoint
         splitTokenPartial => split_token_partial_endpoint
+        grantRole => grant_role
+        revokeRole => revoke_role
+        renounceRole => renounce_role
+        setRoleAdmin => set_role_admin
+        hasRole => has_role
+        getRoleAdmin => get_role_admin
+        getRoles => roles
+        getRoleMembers => role_members
         claimDeveloperRewards => claim_developer_rewards
         dnsRegister => dns_register
         issueToken => issue_token
@@ -69,6 +87,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
+        isReentrancyLocked => is_reentrancy_locked
         stake => stake
         unstake => unstake
         voteSlashMember => vote_slash_member
@@ -83,6 +102,20 @@ multiversx_sc_wasm_adapter::endpoints! {
         addAdmin => add_admin
         removeAdmin => remove_admin
         getAdmins => admins
+        scheduleOperation => schedule_operation
+        executeOperation => execute_operation
+        cancelOperation => cancel_operation
+        updateMinDelay => update_min_delay
+        addProposer => add_proposer
+        removeProposer => remove_proposer
+        addExecutor => add_executor
+        removeExecutor => remove_executor
+        isOperationReady => is_operation_ready
+        getOperation => get_operation
+        getMinDelay => timelock_min_delay
+        getPendingOperations => timelock_pending_operations
+        getProposers => timelock_proposers
+        getExecutors => timelock_executors
     )
 }
 
@@ -0,0 +1,146 @@
+multiversx_sc::imports!();
+
+/// Role that administers all roles that have no other admin role configured.
+pub const DEFAULT_ADMIN_ROLE: &[u8] = b"DEFAULT_ADMIN";
+
+/// Standard smart contract module for role-based access control,
+/// for contracts that need more than the single owner and the flat admin set of `only_admin`,
+/// e.g. separate minters, pausers, oracles and treasurers.
+///
+/// Roles are arbitrary names. Each role is administered by another role,
+/// by default `DEFAULT_ADMIN`, whose members can grant and revoke it.
+/// Members can also renounce a role themselves.
+///
+/// Endpoints can be restricted to a role with the `#[only_role("minter")]` attribute,
+/// which also appears in the ABI, or by calling `require_caller_has_role` explicitly.
+#[multiversx_sc::module]
+pub trait AccessControlModule {
+    /// Call from init, grants the default admin role to the given address.
+    fn init_access_control(&self, admin: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        self.grant_role_unchecked(&ManagedBuffer::from(DEFAULT_ADMIN_ROLE), &admin, &caller);
+    }
+
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_has_role(&self.get_role_admin(&role));
+
+        let caller = self.blockchain().get_caller();
+        self.grant_role_unchecked(&role, &address, &caller);
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_has_role(&self.get_role_admin(&role));
+
+        let caller = self.blockchain().get_caller();
+        self.revoke_role_unchecked(&role, &address, &caller);
+    }
+
+    /// Removes the role from the caller.
+    #[endpoint(renounceRole)]
+    fn renounce_role(&self, role: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.has_role(&role, &caller),
+            "Caller does not have the role"
+        );
+
+        self.revoke_role_unchecked(&role, &caller, &caller);
+    }
+
+    /// Only members of the current admin role can change it.
+    #[endpoint(setRoleAdmin)]
+    fn set_role_admin(&self, role: ManagedBuffer, admin_role: ManagedBuffer) {
+        let previous_admin_role = self.get_role_admin(&role);
+        self.require_caller_has_role(&previous_admin_role);
+
+        self.role_admin(&role).set(&admin_role);
+        self.role_admin_changed_event(&role, &previous_admin_role, &admin_role);
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, role: &ManagedBuffer, address: &ManagedAddress) -> bool {
+        self.role_members(role).contains(address)
+    }
+
+    #[view(getRoleAdmin)]
+    fn get_role_admin(&self, role: &ManagedBuffer) -> ManagedBuffer {
+        let admin_role_mapper = self.role_admin(role);
+        if admin_role_mapper.is_empty() {
+            ManagedBuffer::from(DEFAULT_ADMIN_ROLE)
+        } else {
+            admin_role_mapper.get()
+        }
+    }
+
+    fn require_caller_has_role(&self, role: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        if !self.has_role(role, &caller) {
+            sc_panic!("Endpoint can only be called by role {}", role);
+        }
+    }
+
+    fn grant_role_unchecked(
+        &self,
+        role: &ManagedBuffer,
+        address: &ManagedAddress,
+        sender: &ManagedAddress,
+    ) {
+        if self.role_members(role).insert(address.clone()) {
+            self.roles().insert(role.clone());
+            self.role_granted_event(role, address, sender);
+        }
+    }
+
+    fn revoke_role_unchecked(
+        &self,
+        role: &ManagedBuffer,
+        address: &ManagedAddress,
+        sender: &ManagedAddress,
+    ) {
+        let mut members_mapper = self.role_members(role);
+        if members_mapper.swap_remove(address) {
+            if members_mapper.is_empty() {
+                self.roles().swap_remove(role);
+            }
+            self.role_revoked_event(role, address, sender);
+        }
+    }
+
+    #[event("roleGranted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleRevoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleAdminChanged")]
+    fn role_admin_changed_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] previous_admin_role: &ManagedBuffer,
+        #[indexed] new_admin_role: &ManagedBuffer,
+    );
+
+    /// Roles that currently have at least one member.
+    #[view(getRoles)]
+    #[storage_mapper("access_control:roles")]
+    fn roles(&self) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[view(getRoleMembers)]
+    #[storage_mapper("access_control:role_members")]
+    fn role_members(&self, role: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("access_control:role_admin")]
+    fn role_admin(&self, role: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+}
@@ -1,6 +1,7 @@
 #![no_std]
 #![feature(trait_alias)]
 
+pub mod access_control;
 pub mod bonding_curve;
 pub mod claim_developer_rewards;
 pub mod default_issue_callbacks;
@@ -11,8 +12,11 @@ pub mod governance;
 pub mod ongoing_operation;
 pub mod only_admin;
 pub mod pause;
+pub mod reentrancy_guard;
 pub mod staking;
+pub mod state_machine;
 pub mod subscription;
+pub mod timelock;
 pub mod token_merge;
 pub mod transfer_role_proxy;
 pub mod users;
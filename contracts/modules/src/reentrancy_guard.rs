@@ -0,0 +1,40 @@
+multiversx_sc::imports!();
+
+use multiversx_sc::reentrancy_guard;
+
+/// Standard smart contract module that protects code making sync calls
+/// from being called again by the callee before it finishes, e.g. before it updates its own storage.
+///
+/// Whole endpoints can simply be annotated with `#[non_reentrant]`, which does not require this module.
+/// The module offers the same lock with an explicit API, to guard only part of an endpoint.
+/// Both use the same storage key, so they also guard each other.
+///
+/// The lock is released when the guarded code finishes, or right before it ends the execution with an async call.
+/// If it fails instead, the lock is reverted together with all other storage changes.
+#[multiversx_sc::module]
+pub trait ReentrancyGuardModule {
+    #[view(isReentrancyLocked)]
+    fn is_reentrancy_locked(&self) -> bool {
+        reentrancy_guard::is_locked::<Self::Api>()
+    }
+
+    /// Sets the lock. Fails if it is already set.
+    fn enter_non_reentrant(&self) {
+        reentrancy_guard::enter::<Self::Api>();
+    }
+
+    fn exit_non_reentrant(&self) {
+        reentrancy_guard::exit::<Self::Api>();
+    }
+
+    /// Runs the given function with the lock set.
+    fn with_non_reentrant<R, F>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.enter_non_reentrant();
+        let result = f();
+        self.exit_non_reentrant();
+        result
+    }
+}
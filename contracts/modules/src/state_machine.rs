@@ -0,0 +1,127 @@
+multiversx_sc::imports!();
+
+use multiversx_sc::state_machine::{StateMachine, StateTransitionCheck};
+
+/// Standard smart contract module for contracts whose lifecycle is a set of states,
+/// e.g. a crowdfunding that is first funding, then successful or failed.
+///
+/// The states are an enum deriving `StateMachine`, which declares the allowed transitions:
+///
+/// ```ignore
+/// #[derive(TopEncode, TopDecode, TypeAbi, StateMachine, Clone, Copy, PartialEq, Eq, Debug)]
+/// pub enum Status {
+///     #[transition(to = Successful, guard = "target reached")]
+///     #[transition(to = Failed)]
+///     Funding,
+///     Successful,
+///     Failed,
+/// }
+/// ```
+///
+/// The current state is kept in a `SingleValueMapper` of the contract, passed to all methods,
+/// so that a contract can hold several state machines, e.g. one per auction.
+///
+/// It offers:
+/// * state checks, similar to `require!`, that fail with the expected and actual state names
+/// * transitions, which fail if not declared in the enum, and emit the `stateTransition` event
+///
+/// The declared states and transitions are also part of the enum type description in the ABI.
+#[multiversx_sc::module]
+pub trait StateMachineModule {
+    /// Sets the initial state, if no state was set yet.
+    fn init_state<S>(&self, mapper: &SingleValueMapper<S>)
+    where
+        S: StateMachine + TopEncode + TopDecode,
+    {
+        if mapper.is_empty() {
+            mapper.set(S::initial_state());
+        }
+    }
+
+    /// The current state, or the initial one if none was set yet.
+    fn current_state<S>(&self, mapper: &SingleValueMapper<S>) -> S
+    where
+        S: StateMachine + TopEncode + TopDecode,
+    {
+        if mapper.is_empty() {
+            S::initial_state()
+        } else {
+            mapper.get()
+        }
+    }
+
+    fn require_state<S>(&self, mapper: &SingleValueMapper<S>, expected: &S)
+    where
+        S: StateMachine + TopEncode + TopDecode,
+    {
+        let current = self.current_state(mapper);
+        if &current != expected {
+            sc_panic!(
+                "Invalid state: expected {}, found {}",
+                expected.state_name().as_bytes(),
+                current.state_name().as_bytes()
+            );
+        }
+    }
+
+    fn require_state_in<S>(&self, mapper: &SingleValueMapper<S>, expected: &[S])
+    where
+        S: StateMachine + TopEncode + TopDecode,
+    {
+        let current = self.current_state(mapper);
+        if !expected.contains(&current) {
+            sc_panic!("Invalid state: {}", current.state_name().as_bytes());
+        }
+    }
+
+    /// Moves to the next state. The transition must be declared without a guard.
+    fn transition_state<S>(&self, mapper: &SingleValueMapper<S>, next: S)
+    where
+        S: StateMachine + TopEncode + TopDecode,
+    {
+        self.transition_state_guarded(mapper, next, |_| false);
+    }
+
+    /// Moves to the next state. If the transition is declared with a guard,
+    /// the given function is called with the guard name and must confirm that the condition holds.
+    fn transition_state_guarded<S, G>(&self, mapper: &SingleValueMapper<S>, next: S, check_guard: G)
+    where
+        S: StateMachine + TopEncode + TopDecode,
+        G: FnOnce(&'static str) -> bool,
+    {
+        let current = self.current_state(mapper);
+        match current.check_transition(&next) {
+            StateTransitionCheck::Allowed => {},
+            StateTransitionCheck::Guarded(guard) => {
+                if !check_guard(guard) {
+                    sc_panic!(
+                        "State transition from {} to {} requires: {}",
+                        current.state_name().as_bytes(),
+                        next.state_name().as_bytes(),
+                        guard.as_bytes()
+                    );
+                }
+            },
+            StateTransitionCheck::NotAllowed => {
+                sc_panic!(
+                    "Invalid state transition from {} to {}",
+                    current.state_name().as_bytes(),
+                    next.state_name().as_bytes()
+                );
+            },
+        }
+
+        self.state_transition_event(
+            &ManagedBuffer::from(current.state_name()),
+            &ManagedBuffer::from(next.state_name()),
+        );
+        mapper.set(next);
+    }
+
+    #[event("stateTransition")]
+    fn state_transition_event(
+        &self,
+        #[indexed] from: &ManagedBuffer,
+        #[indexed] to: &ManagedBuffer,
+    );
+}
@@ -0,0 +1,327 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::only_admin;
+
+/// Gas kept for the timelock itself after the scheduled call returns.
+const EXECUTE_OPERATION_FINISH_GAS: u64 = 300_000;
+
+pub type TimelockOperationId = usize;
+
+/// The minimum time between scheduling an operation and executing it.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug,
+)]
+pub enum TimelockDelay {
+    Rounds(u64),
+    Seconds(u64),
+}
+
+/// The round or timestamp from which a scheduled operation can be executed.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug,
+)]
+pub enum TimelockDeadline {
+    Round(u64),
+    Timestamp(u64),
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct TimelockOperation<M: ManagedTypeApi> {
+    pub proposer: ManagedAddress<M>,
+    pub to: ManagedAddress<M>,
+    pub egld_amount: BigUint<M>,
+    pub endpoint_name: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+    pub ready_at: TimelockDeadline,
+}
+
+/// Standard smart contract module that delays calls until a minimum time has passed since they were scheduled,
+/// giving users time to react to parameter changes.
+///
+/// Works the same way as the transfer-execute action of the multisig:
+/// * proposers schedule a call, to any contract (including this one), with an optional EGLD amount;
+/// * after the minimum delay, measured in rounds or seconds, executors perform it;
+/// * until then, proposers can cancel it.
+///
+/// Proposers and executors are managed by the admins of the `only_admin` module.
+///
+/// To put endpoints of the contract itself behind the timelock,
+/// guard them with `require_caller_is_timelock` and schedule calls to the contract's own address.
+/// The minimum delay can only be changed this way.
+#[multiversx_sc::module]
+pub trait TimelockModule: only_admin::OnlyAdminModule {
+    /// Call from init.
+    fn init_timelock(&self, min_delay: TimelockDelay) {
+        self.timelock_min_delay().set(min_delay);
+    }
+
+    fn require_caller_is_timelock(&self) {
+        require!(
+            self.blockchain().get_caller() == self.blockchain().get_sc_address(),
+            "Endpoint can only be called through the timelock"
+        );
+    }
+
+    /// Schedules a call to be executed after the minimum delay.
+    /// An empty function call only transfers the EGLD amount.
+    /// Returns the operation id.
+    #[endpoint(scheduleOperation)]
+    fn schedule_operation(
+        &self,
+        to: ManagedAddress,
+        egld_amount: BigUint,
+        function_call: FunctionCall,
+    ) -> TimelockOperationId {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.timelock_proposers().contains(&caller),
+            "Only proposers can schedule operations"
+        );
+        require!(
+            !self.timelock_min_delay().is_empty(),
+            "Timelock not initialized"
+        );
+
+        let ready_at = self.timelock_deadline(self.timelock_min_delay().get());
+        let operation_id = self.timelock_last_operation_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let operation = TimelockOperation {
+            proposer: caller,
+            to,
+            egld_amount,
+            endpoint_name: function_call.function_name,
+            arguments: function_call.arg_buffer.into_vec_of_buffers(),
+            ready_at,
+        };
+
+        self.operation_scheduled_event(
+            operation_id,
+            &operation.proposer,
+            &operation.to,
+            &operation.endpoint_name,
+            ready_at,
+        );
+        self.timelock_operation(operation_id).set(operation);
+        self.timelock_pending_operations().insert(operation_id);
+
+        operation_id
+    }
+
+    /// Performs a scheduled operation whose delay has passed, then removes it.
+    /// Returns the results of the call.
+    #[endpoint(executeOperation)]
+    fn execute_operation(
+        &self,
+        operation_id: TimelockOperationId,
+    ) -> MultiValueEncoded<ManagedBuffer> {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.timelock_executors().contains(&caller),
+            "Only executors can execute operations"
+        );
+        let operation = self.take_pending_operation(operation_id);
+        require!(
+            self.is_timelock_deadline_passed(operation.ready_at),
+            "Operation is not ready"
+        );
+
+        self.operation_executed_event(operation_id, &caller);
+
+        if operation.endpoint_name.is_empty() {
+            self.send()
+                .direct_egld(&operation.to, &operation.egld_amount);
+            return MultiValueEncoded::new();
+        }
+
+        let gas_left = self.blockchain().get_gas_left();
+        require!(
+            gas_left > EXECUTE_OPERATION_FINISH_GAS,
+            "insufficient gas for call"
+        );
+        self.send_raw()
+            .execute_on_dest_context_raw(
+                gas_left - EXECUTE_OPERATION_FINISH_GAS,
+                &operation.to,
+                &operation.egld_amount,
+                &operation.endpoint_name,
+                &operation.arguments.into(),
+            )
+            .into()
+    }
+
+    #[endpoint(cancelOperation)]
+    fn cancel_operation(&self, operation_id: TimelockOperationId) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.timelock_proposers().contains(&caller),
+            "Only proposers can cancel operations"
+        );
+        let _ = self.take_pending_operation(operation_id);
+
+        self.operation_cancelled_event(operation_id, &caller);
+    }
+
+    /// Can only be called through the timelock, so that the delay itself cannot be bypassed.
+    #[endpoint(updateMinDelay)]
+    fn update_min_delay(&self, min_delay: TimelockDelay) {
+        self.require_caller_is_timelock();
+        self.timelock_min_delay().set(min_delay);
+
+        self.min_delay_updated_event(min_delay);
+    }
+
+    #[only_admin]
+    #[endpoint(addProposer)]
+    fn add_proposer(&self, address: ManagedAddress) {
+        if self.timelock_proposers().insert(address.clone()) {
+            self.proposer_added_event(&address);
+        }
+    }
+
+    #[only_admin]
+    #[endpoint(removeProposer)]
+    fn remove_proposer(&self, address: ManagedAddress) {
+        if self.timelock_proposers().swap_remove(&address) {
+            self.proposer_removed_event(&address);
+        }
+    }
+
+    #[only_admin]
+    #[endpoint(addExecutor)]
+    fn add_executor(&self, address: ManagedAddress) {
+        if self.timelock_executors().insert(address.clone()) {
+            self.executor_added_event(&address);
+        }
+    }
+
+    #[only_admin]
+    #[endpoint(removeExecutor)]
+    fn remove_executor(&self, address: ManagedAddress) {
+        if self.timelock_executors().swap_remove(&address) {
+            self.executor_removed_event(&address);
+        }
+    }
+
+    #[view(isOperationReady)]
+    fn is_operation_ready(&self, operation_id: TimelockOperationId) -> bool {
+        let mapper = self.timelock_operation(operation_id);
+        !mapper.is_empty() && self.is_timelock_deadline_passed(mapper.get().ready_at)
+    }
+
+    #[view(getOperation)]
+    fn get_operation(
+        &self,
+        operation_id: TimelockOperationId,
+    ) -> OptionalValue<TimelockOperation<Self::Api>> {
+        let mapper = self.timelock_operation(operation_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    fn take_pending_operation(
+        &self,
+        operation_id: TimelockOperationId,
+    ) -> TimelockOperation<Self::Api> {
+        require!(
+            self.timelock_pending_operations()
+                .swap_remove(&operation_id),
+            "Operation not found"
+        );
+        self.timelock_operation(operation_id).take()
+    }
+
+    fn timelock_deadline(&self, delay: TimelockDelay) -> TimelockDeadline {
+        let deadline = match delay {
+            TimelockDelay::Rounds(rounds) => self
+                .blockchain()
+                .get_block_round()
+                .checked_add(rounds)
+                .map(TimelockDeadline::Round),
+            TimelockDelay::Seconds(seconds) => self
+                .blockchain()
+                .get_block_timestamp()
+                .checked_add(seconds)
+                .map(TimelockDeadline::Timestamp),
+        };
+        deadline.unwrap_or_else(|| sc_panic!("Timelock delay too long"))
+    }
+
+    fn is_timelock_deadline_passed(&self, deadline: TimelockDeadline) -> bool {
+        match deadline {
+            TimelockDeadline::Round(round) => self.blockchain().get_block_round() >= round,
+            TimelockDeadline::Timestamp(timestamp) => {
+                self.blockchain().get_block_timestamp() >= timestamp
+            },
+        }
+    }
+
+    #[event("operationScheduled")]
+    fn operation_scheduled_event(
+        &self,
+        #[indexed] operation_id: TimelockOperationId,
+        #[indexed] proposer: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] endpoint_name: &ManagedBuffer,
+        ready_at: TimelockDeadline,
+    );
+
+    #[event("operationExecuted")]
+    fn operation_executed_event(
+        &self,
+        #[indexed] operation_id: TimelockOperationId,
+        #[indexed] executor: &ManagedAddress,
+    );
+
+    #[event("operationCancelled")]
+    fn operation_cancelled_event(
+        &self,
+        #[indexed] operation_id: TimelockOperationId,
+        #[indexed] canceller: &ManagedAddress,
+    );
+
+    #[event("minDelayUpdated")]
+    fn min_delay_updated_event(&self, min_delay: TimelockDelay);
+
+    #[event("proposerAdded")]
+    fn proposer_added_event(&self, #[indexed] proposer: &ManagedAddress);
+
+    #[event("proposerRemoved")]
+    fn proposer_removed_event(&self, #[indexed] proposer: &ManagedAddress);
+
+    #[event("executorAdded")]
+    fn executor_added_event(&self, #[indexed] executor: &ManagedAddress);
+
+    #[event("executorRemoved")]
+    fn executor_removed_event(&self, #[indexed] executor: &ManagedAddress);
+
+    #[view(getMinDelay)]
+    #[storage_mapper("timelock:min_delay")]
+    fn timelock_min_delay(&self) -> SingleValueMapper<TimelockDelay>;
+
+    #[storage_mapper("timelock:last_operation_id")]
+    fn timelock_last_operation_id(&self) -> SingleValueMapper<TimelockOperationId>;
+
+    #[storage_mapper("timelock:operation")]
+    fn timelock_operation(
+        &self,
+        operation_id: TimelockOperationId,
+    ) -> SingleValueMapper<TimelockOperation<Self::Api>>;
+
+    #[view(getPendingOperations)]
+    #[storage_mapper("timelock:pending_operations")]
+    fn timelock_pending_operations(&self) -> UnorderedSetMapper<TimelockOperationId>;
+
+    #[view(getProposers)]
+    #[storage_mapper("timelock:proposers")]
+    fn timelock_proposers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getExecutors)]
+    #[storage_mapper("timelock:executors")]
+    fn timelock_executors(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -0,0 +1,74 @@
+use proc_macro::TokenStream;
+use quote::quote;
+
+use crate::{codec_attr::FieldCodecAttributes, util::*};
+
+/// Skipped fields are not encoded, so they always get their default value, to round-trip.
+fn field_arbitrary_snippet(_index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
+    let ty = &field.ty;
+    let field_attributes = FieldCodecAttributes::parse(field);
+    let value = if field_attributes.skip {
+        field_attributes.default_value_snippet()
+    } else {
+        quote! {
+            <#ty as codec::CodecArbitrary>::codec_arbitrary(__u__)?
+        }
+    };
+    if let Some(ident) = &field.ident {
+        quote! {
+            #ident: #value
+        }
+    } else {
+        value
+    }
+}
+
+pub fn codec_arbitrary_impl(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
+    let body = match &ast.data {
+        syn::Data::Struct(data_struct) => {
+            let field_snippets = fields_decl_syntax(&data_struct.fields, field_arbitrary_snippet);
+            quote! {
+                core::result::Result::Ok(#name #field_snippets)
+            }
+        },
+        syn::Data::Enum(data_enum) => {
+            assert!(
+                !data_enum.variants.is_empty(),
+                "cannot generate arbitrary values for an enum without variants"
+            );
+            let num_variants = data_enum.variants.len();
+            let variant_snippets = data_enum
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(index, variant)| {
+                    let variant_ident = &variant.ident;
+                    let field_snippets =
+                        fields_decl_syntax(&variant.fields, field_arbitrary_snippet);
+                    quote! {
+                        #index => core::result::Result::Ok(#name::#variant_ident #field_snippets),
+                    }
+                });
+            quote! {
+                match __u__.choose_index(#num_variants)? {
+                    #(#variant_snippets)*
+                    _ => core::unreachable!(),
+                }
+            }
+        },
+        syn::Data::Union(_) => panic!("Union not supported"),
+    };
+
+    let gen = quote! {
+        impl #impl_generics codec::CodecArbitrary for #name #ty_generics #where_clause {
+            fn codec_arbitrary(
+                __u__: &mut codec::arbitrary::Unstructured<'_>,
+            ) -> codec::arbitrary::Result<Self> {
+                #body
+            }
+        }
+    };
+    gen.into()
+}
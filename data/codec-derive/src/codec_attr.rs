@@ -0,0 +1,144 @@
+const CODEC_ATTR_NAME: &str = "codec";
+
+/// Field options, given as `#[codec(...)]` attributes.
+#[derive(Default)]
+pub struct FieldCodecAttributes {
+    /// The field is neither encoded, nor decoded. It gets the default value when decoding.
+    pub skip: bool,
+
+    /// The struct version that introduced the field.
+    ///
+    /// Such fields can be missing from older top-encoded values, they are then decoded as the default.
+    pub since: Option<u32>,
+
+    /// The value used when the field cannot be decoded. `Default::default()` if not specified.
+    pub default: Option<syn::Expr>,
+}
+
+impl FieldCodecAttributes {
+    pub fn parse(field: &syn::Field) -> Self {
+        let mut result = FieldCodecAttributes::default();
+        for attr in &field.attrs {
+            if !attr.path().is_ident(CODEC_ATTR_NAME) {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    result.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("since") {
+                    let version: syn::LitInt = meta.value()?.parse()?;
+                    result.since = Some(version.base10_parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    result.default = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown codec field attribute"))
+                }
+            })
+            .unwrap_or_else(|err| panic!("invalid codec attribute: {err}"));
+        }
+
+        assert!(
+            !(result.skip && result.since.is_some()),
+            "skipped fields cannot be versioned"
+        );
+        assert!(
+            result.default.is_none() || result.skip || result.since.is_some(),
+            "`#[codec(default = ...)]` is only allowed on skipped or versioned fields"
+        );
+        result
+    }
+
+    pub fn default_value_snippet(&self) -> proc_macro2::TokenStream {
+        if let Some(default) = &self.default {
+            quote::quote! { #default }
+        } else {
+            quote::quote! { core::default::Default::default() }
+        }
+    }
+}
+
+/// Reads `#[codec(discriminant = n)]` from an enum variant.
+fn explicit_discriminant(variant: &syn::Variant) -> Option<u8> {
+    let mut result = None;
+    for attr in &variant.attrs {
+        if !attr.path().is_ident(CODEC_ATTR_NAME) {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("discriminant") {
+                let discriminant: syn::LitInt = meta.value()?.parse()?;
+                result = Some(discriminant.base10_parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown codec variant attribute"))
+            }
+        })
+        .unwrap_or_else(|err| panic!("invalid codec attribute: {err}"));
+    }
+    result
+}
+
+/// The encoded discriminant of each variant, in declaration order.
+///
+/// Just like in Rust, variants without an explicit discriminant take the previous one plus one,
+/// starting from zero.
+pub fn variant_discriminants(data_enum: &syn::DataEnum) -> Vec<u8> {
+    let mut discriminants: Vec<u8> = Vec::new();
+    for variant in &data_enum.variants {
+        let discriminant = explicit_discriminant(variant).unwrap_or_else(|| {
+            discriminants.last().map_or(0, |previous| {
+                previous.checked_add(1).unwrap_or_else(|| {
+                    panic!("discriminant of variant `{}` exceeds 255", variant.ident)
+                })
+            })
+        });
+        assert!(
+            !discriminants.contains(&discriminant),
+            "discriminant {discriminant} is used by more than one variant"
+        );
+        discriminants.push(discriminant);
+    }
+    discriminants
+}
+
+/// Versioning only makes sense for structs.
+pub fn validate_enum_fields(data_enum: &syn::DataEnum) {
+    for field in data_enum
+        .variants
+        .iter()
+        .flat_map(|variant| variant.fields.iter())
+    {
+        assert!(
+            FieldCodecAttributes::parse(field).since.is_none(),
+            "versioned fields are only supported in structs"
+        );
+    }
+}
+
+/// Versioned fields can only be added at the end of the struct, in version order.
+///
+/// Skipped fields are not encoded, so they can be anywhere.
+pub fn validate_versioned_fields(fields: &syn::Fields) {
+    let mut last_since: Option<u32> = None;
+    for field in fields.iter() {
+        let field_attributes = FieldCodecAttributes::parse(field);
+        if field_attributes.skip {
+            continue;
+        }
+        match (last_since, field_attributes.since) {
+            (Some(_), None) => {
+                panic!(
+                    "fields without `#[codec(since = ...)]` must come before all versioned fields"
+                )
+            },
+            (Some(last), Some(since)) if since < last => {
+                panic!("versioned fields must be declared in increasing version order")
+            },
+            _ => {},
+        }
+        last_since = field_attributes.since.or(last_since);
+    }
+}
@@ -1,7 +1,13 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::util::*;
+use crate::{
+    codec_attr::{
+        validate_enum_fields, validate_versioned_fields, variant_discriminants,
+        FieldCodecAttributes,
+    },
+    util::*,
+};
 
 pub fn dep_decode_snippet(
     _index: usize,
@@ -9,14 +15,20 @@ pub fn dep_decode_snippet(
     input_value: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     let ty = &field.ty;
-    if let Some(ident) = &field.ident {
-        quote! {
-            #ident: <#ty as codec::NestedDecode>::dep_decode_or_handle_err(#input_value, __h__)?
-        }
+    let field_attributes = FieldCodecAttributes::parse(field);
+    let value = if field_attributes.skip {
+        field_attributes.default_value_snippet()
     } else {
         quote! {
             <#ty as codec::NestedDecode>::dep_decode_or_handle_err(#input_value, __h__)?
         }
+    };
+    if let Some(ident) = &field.ident {
+        quote! {
+            #ident: #value
+        }
+    } else {
+        value
     }
 }
 
@@ -28,15 +40,14 @@ pub fn variant_dep_decode_snippets(
     data_enum
 		.variants
 		.iter()
-		.enumerate()
-		.map(|(variant_index, variant)| {
-			let variant_index_u8 = variant_index as u8;
+		.zip(variant_discriminants(data_enum))
+		.map(|(variant, discriminant)| {
 			let variant_ident = &variant.ident;
 			let variant_field_snippets = fields_decl_syntax(&variant.fields, |index, field| {
 				dep_decode_snippet(index, field, input_value)
 			});
 			quote! {
-				#variant_index_u8 => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
+				#discriminant => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
 			}
 		})
 		.collect()
@@ -47,6 +58,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
+            validate_versioned_fields(&data_struct.fields);
             let field_dep_decode_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
                     dep_decode_snippet(index, field, &quote! {input})
@@ -70,6 +82,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                 data_enum.variants.len() < 256,
                 "enums with more than 256 variants not supported"
             );
+            validate_enum_fields(data_enum);
             let variant_dep_decode_snippets =
                 variant_dep_decode_snippets(name, data_enum, &quote! {input});
 
@@ -1,4 +1,7 @@
-use crate::util::*;
+use crate::{
+    codec_attr::{variant_discriminants, FieldCodecAttributes},
+    util::*,
+};
 use proc_macro::TokenStream;
 use quote::quote;
 
@@ -8,6 +11,18 @@ pub fn dep_encode_snippet(value: &proc_macro2::TokenStream) -> proc_macro2::Toke
     }
 }
 
+/// Skipped fields produce no output.
+pub fn field_dep_encode_snippet(
+    field: &syn::Field,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if FieldCodecAttributes::parse(field).skip {
+        quote! {}
+    } else {
+        dep_encode_snippet(value)
+    }
+}
+
 fn variant_dep_encode_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
@@ -15,18 +30,17 @@ fn variant_dep_encode_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             let local_var_declarations =
-                fields_decl_syntax(&variant.fields, local_variable_for_field);
+                fields_decl_syntax(&variant.fields, local_variable_pattern_for_field);
             let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                dep_encode_snippet(&local_variable_for_field(index, field))
+                field_dep_encode_snippet(field, &local_variable_for_field(index, field))
             });
             quote! {
                 #name::#variant_ident #local_var_declarations => {
-                    codec::NestedEncode::dep_encode_or_handle_err(&#variant_index_u8, __dest__, __h__)?;
+                    codec::NestedEncode::dep_encode_or_handle_err(&#discriminant, __dest__, __h__)?;
                     #(#variant_field_snippets)*
                 },
             }
@@ -40,7 +54,7 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                field_dep_encode_snippet(field, &self_field_expr(index, field))
             });
             quote! {
                 impl #impl_generics codec::NestedEncode for #name #ty_generics #where_clause {
@@ -0,0 +1,58 @@
+use crate::codec_attr::FieldCodecAttributes;
+use proc_macro::TokenStream;
+use quote::quote;
+
+/// Sum of the maximum lengths of the encoded fields. Skipped fields are not encoded.
+fn fields_max_len_snippet(fields: &syn::Fields) -> proc_macro2::TokenStream {
+    let field_max_len_snippets = fields
+        .iter()
+        .filter(|field| !FieldCodecAttributes::parse(field).skip)
+        .map(|field| {
+            let field_ty = &field.ty;
+            quote! {
+                let __max_len__ = codec::max_len_add(
+                    __max_len__,
+                    <#field_ty as codec::NestedEncodeMaxLen>::MAX_NESTED_LEN,
+                );
+            }
+        });
+    quote! {
+        {
+            let __max_len__ = core::option::Option::Some(0usize);
+            #(#field_max_len_snippets)*
+            __max_len__
+        }
+    }
+}
+
+pub fn nested_encode_max_len_impl(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
+    let max_len_snippet = match &ast.data {
+        syn::Data::Struct(data_struct) => fields_max_len_snippet(&data_struct.fields),
+        syn::Data::Enum(data_enum) => {
+            // discriminant, followed by the largest variant
+            let variant_max_len_snippets = data_enum.variants.iter().map(|variant| {
+                let variant_max_len = fields_max_len_snippet(&variant.fields);
+                quote! {
+                    let __variants_max_len__ = codec::max_len_max(__variants_max_len__, #variant_max_len);
+                }
+            });
+            quote! {
+                {
+                    let __variants_max_len__ = core::option::Option::Some(0usize);
+                    #(#variant_max_len_snippets)*
+                    codec::max_len_add(core::option::Option::Some(1), __variants_max_len__)
+                }
+            }
+        },
+        syn::Data::Union(_) => panic!("Union not supported"),
+    };
+
+    let gen = quote! {
+        impl #impl_generics codec::NestedEncodeMaxLen for #name #ty_generics #where_clause {
+            const MAX_NESTED_LEN: core::option::Option<usize> = #max_len_snippet;
+        }
+    };
+    gen.into()
+}
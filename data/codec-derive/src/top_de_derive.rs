@@ -1,7 +1,14 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::{nested_de_derive::*, util::*};
+use crate::{
+    codec_attr::{
+        validate_enum_fields, validate_versioned_fields, variant_discriminants,
+        FieldCodecAttributes,
+    },
+    nested_de_derive::*,
+    util::*,
+};
 
 fn fieldless_enum_match_arm_result_ok(
     name: &syn::Ident,
@@ -10,19 +17,42 @@ fn fieldless_enum_match_arm_result_ok(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             quote! {
-                #variant_index_u8 => core::result::Result::Ok( #name::#variant_ident ),
+                #discriminant => core::result::Result::Ok( #name::#variant_ident ),
             }
         })
         .collect()
 }
 
+/// Versioned fields missing from the end of older encodings get their default values.
+fn top_dep_decode_snippet(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
+    let field_attributes = FieldCodecAttributes::parse(field);
+    let decode_snippet = dep_decode_snippet(index, field, &quote! {&mut nested_buffer});
+    if field_attributes.since.is_none() {
+        return decode_snippet;
+    }
+
+    let ty = &field.ty;
+    let default_value = field_attributes.default_value_snippet();
+    let value = quote! {
+        if codec::NestedDecodeInput::is_depleted(&nested_buffer) {
+            #default_value
+        } else {
+            <#ty as codec::NestedDecode>::dep_decode_or_handle_err(&mut nested_buffer, __h__)?
+        }
+    };
+    if let Some(ident) = &field.ident {
+        quote! { #ident: #value }
+    } else {
+        value
+    }
+}
+
 /// Generates a default-value deserializer snippet automatically.
-/// Currently only does so for enums whose first variant is fieldless.
+/// Currently only does so for enums whose variant with discriminant 0 is fieldless.
 /// Not called for TopDecodeOrDefault, since that one already provides an explicit default.
 fn auto_default(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
@@ -31,12 +61,17 @@ fn auto_default(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
             !data_enum.variants.is_empty(),
             "cannot deserialize enums without variants"
         );
-        let first_variant = &data_enum.variants[0];
-        if first_variant.fields.is_empty() {
-            let first_variant_ident = &first_variant.ident;
+        let zero_variant = data_enum
+            .variants
+            .iter()
+            .zip(variant_discriminants(data_enum))
+            .find(|(_, discriminant)| *discriminant == 0)
+            .map(|(variant, _)| variant);
+        if let Some(zero_variant) = zero_variant.filter(|variant| variant.fields.is_empty()) {
+            let zero_variant_ident = &zero_variant.ident;
             let auto_default = quote! {
                 if top_input.byte_len() == 0 {
-                    return core::result::Result::Ok(#name::#first_variant_ident);
+                    return core::result::Result::Ok(#name::#zero_variant_ident);
                 }
             };
             return auto_default;
@@ -52,10 +87,9 @@ fn top_decode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
+            validate_versioned_fields(&data_struct.fields);
             let field_dep_decode_snippets =
-                fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {&mut nested_buffer})
-                });
+                fields_decl_syntax(&data_struct.fields, top_dep_decode_snippet);
 
             quote! {
                 let mut nested_buffer = top_input.into_nested_buffer();
@@ -71,6 +105,7 @@ fn top_decode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
                 data_enum.variants.len() < 256,
                 "enums with more than 256 variants not supported"
             );
+            validate_enum_fields(data_enum);
             if is_fieldless_enum(data_enum) {
                 // fieldless enums are special, they can be top-decoded as u8 directly
                 let top_decode_arms = fieldless_enum_match_arm_result_ok(name, data_enum);
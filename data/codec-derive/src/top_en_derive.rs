@@ -1,7 +1,9 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::{nested_en_derive::dep_encode_snippet, util::*};
+use crate::{
+    codec_attr::variant_discriminants, nested_en_derive::field_dep_encode_snippet, util::*,
+};
 
 pub fn variant_top_encode_snippets(
     name: &syn::Ident,
@@ -10,9 +12,8 @@ pub fn variant_top_encode_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let discriminant_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant_u8)| {
             let variant_ident = &variant.ident;
             if variant.fields.is_empty() {
                 // top-encode discriminant directly
@@ -23,9 +24,9 @@ pub fn variant_top_encode_snippets(
             } else {
                 // dep-encode to buffer first
                 let local_var_declarations =
-                    fields_decl_syntax(&variant.fields, local_variable_for_field);
+                    fields_decl_syntax(&variant.fields, local_variable_pattern_for_field);
                 let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                    dep_encode_snippet(&local_variable_for_field(index, field))
+                    field_dep_encode_snippet(field, &local_variable_for_field(index, field))
                 });
                 quote! {
                     #name::#variant_ident #local_var_declarations => {
@@ -48,7 +49,7 @@ fn top_encode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     match &ast.data {
         syn::Data::Struct(data_struct) => {
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                field_dep_encode_snippet(field, &self_field_expr(index, field))
             });
             quote! {
                 let mut __buffer__ = output.start_nested_encode();
@@ -0,0 +1,168 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use arbitrary::{Arbitrary, Result, Unstructured};
+use arrayvec::ArrayVec;
+use core::{marker::PhantomData, num::NonZeroUsize};
+
+use crate::Empty;
+
+/// Collections get at most this many items, to keep the generated values small.
+const MAX_ARBITRARY_LEN: usize = 16;
+
+/// Generates arbitrary values that survive an encode-decode round trip, for property tests.
+///
+/// Unlike `arbitrary::Arbitrary`, it can also be implemented for managed types,
+/// and the derive follows the codec attributes, e.g. skipped fields always get their default value.
+pub trait CodecArbitrary: Sized {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self>;
+}
+
+/// Length for a generated collection.
+pub fn codec_arbitrary_len(u: &mut Unstructured<'_>) -> Result<usize> {
+    u.int_in_range(0..=MAX_ARBITRARY_LEN)
+}
+
+macro_rules! codec_arbitrary_via_arbitrary {
+    ($($ty:ty)*) => {
+        $(
+            impl CodecArbitrary for $ty {
+                fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+                    <$ty as Arbitrary>::arbitrary(u)
+                }
+            }
+        )*
+    };
+}
+
+codec_arbitrary_via_arbitrary! {u8 u16 u32 u64 i8 i16 i32 i64 bool String}
+
+// usize and isize are always encoded on 32 bits.
+impl CodecArbitrary for usize {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(u32::arbitrary(u)? as usize)
+    }
+}
+
+impl CodecArbitrary for isize {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(i32::arbitrary(u)? as isize)
+    }
+}
+
+impl CodecArbitrary for NonZeroUsize {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        let value = u.int_in_range(1..=u32::MAX)?;
+        Ok(NonZeroUsize::new(value as usize).unwrap())
+    }
+}
+
+impl CodecArbitrary for Box<str> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(String::arbitrary(u)?.into_boxed_str())
+    }
+}
+
+impl CodecArbitrary for Empty {
+    fn codec_arbitrary(_u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(Empty)
+    }
+}
+
+impl<T> CodecArbitrary for PhantomData<T> {
+    fn codec_arbitrary(_u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(PhantomData)
+    }
+}
+
+impl<T: CodecArbitrary> CodecArbitrary for Box<T> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(Box::new(T::codec_arbitrary(u)?))
+    }
+}
+
+impl<T: CodecArbitrary> CodecArbitrary for Option<T> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        if bool::arbitrary(u)? {
+            Ok(Some(T::codec_arbitrary(u)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<T: CodecArbitrary> CodecArbitrary for Vec<T> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        let len = codec_arbitrary_len(u)?;
+        (0..len).map(|_| T::codec_arbitrary(u)).collect()
+    }
+}
+
+impl<T: CodecArbitrary> CodecArbitrary for Box<[T]> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(Vec::<T>::codec_arbitrary(u)?.into_boxed_slice())
+    }
+}
+
+impl<T: CodecArbitrary, const N: usize> CodecArbitrary for [T; N] {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        let items = (0..N)
+            .map(|_| T::codec_arbitrary(u))
+            .collect::<Result<ArrayVec<T, N>>>()?;
+        Ok(items.into_inner().unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+impl<T: CodecArbitrary, const CAP: usize> CodecArbitrary for ArrayVec<T, CAP> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        let len = u.int_in_range(0..=CAP)?;
+        (0..len).map(|_| T::codec_arbitrary(u)).collect()
+    }
+}
+
+macro_rules! tuple_impls {
+    ($(($($name:ident)+))+) => {
+        $(
+            impl<$($name),+> CodecArbitrary for ($($name,)+)
+            where
+                $($name: CodecArbitrary,)+
+            {
+                fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+                    Ok(($($name::codec_arbitrary(u)?,)+))
+                }
+            }
+        )+
+    }
+}
+
+tuple_impls! {
+    (T0)
+    (T0 T1)
+    (T0 T1 T2)
+    (T0 T1 T2 T3)
+    (T0 T1 T2 T3 T4)
+    (T0 T1 T2 T3 T4 T5)
+    (T0 T1 T2 T3 T4 T5 T6)
+    (T0 T1 T2 T3 T4 T5 T6 T7)
+}
+
+#[cfg(feature = "num-bigint")]
+mod big_int_impl {
+    use super::*;
+    use num_bigint::{BigInt, BigUint, Sign};
+
+    impl CodecArbitrary for BigUint {
+        fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+            Ok(BigUint::from_bytes_be(&Vec::<u8>::codec_arbitrary(u)?))
+        }
+    }
+
+    impl CodecArbitrary for BigInt {
+        fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+            let sign = if bool::arbitrary(u)? {
+                Sign::Minus
+            } else {
+                Sign::Plus
+            };
+            Ok(BigInt::from_biguint(sign, BigUint::codec_arbitrary(u)?))
+        }
+    }
+}
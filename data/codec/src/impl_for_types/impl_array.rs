@@ -1,7 +1,7 @@
 use crate::{
-    top_decode_from_nested_or_handle_err, DecodeError, DecodeErrorHandler, EncodeErrorHandler,
-    NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput,
-    TopEncode, TopEncodeOutput,
+    max_len_mul, top_decode_from_nested_or_handle_err, DecodeError, DecodeErrorHandler,
+    EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeMaxLen,
+    NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
 };
 use alloc::boxed::Box;
 use arrayvec::ArrayVec;
@@ -16,6 +16,9 @@ impl<T: NestedEncode, const N: usize> NestedEncode for [T; N] {
         super::impl_slice::dep_encode_slice_contents(&self[..], dest, h)
     }
 }
+impl<T: NestedEncodeMaxLen, const N: usize> NestedEncodeMaxLen for [T; N] {
+    const MAX_NESTED_LEN: Option<usize> = max_len_mul(T::MAX_NESTED_LEN, N);
+}
 
 impl<T: NestedEncode, const N: usize> TopEncode for [T; N] {
     #[inline]
@@ -1,6 +1,7 @@
 use crate::{
-    DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
-    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    max_len_add, max_len_mul, DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
+    NestedDecodeInput, NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode,
+    TopDecodeInput, TopEncode, TopEncodeOutput,
 };
 use arrayvec::ArrayVec;
 
@@ -55,6 +56,10 @@ impl<T: NestedEncode, const CAP: usize> NestedEncode for ArrayVec<T, CAP> {
         self.as_slice().dep_encode_or_handle_err(dest, h)
     }
 }
+/// Encoded like a slice: the length, followed by at most `CAP` items.
+impl<T: NestedEncodeMaxLen, const CAP: usize> NestedEncodeMaxLen for ArrayVec<T, CAP> {
+    const MAX_NESTED_LEN: Option<usize> = max_len_add(Some(4), max_len_mul(T::MAX_NESTED_LEN, CAP));
+}
 
 impl<T: NestedDecode, const CAP: usize> NestedDecode for ArrayVec<T, CAP> {
     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
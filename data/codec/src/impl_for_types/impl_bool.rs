@@ -1,7 +1,7 @@
 use crate::{
-    dep_encode_num_mimic, DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
-    NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
-    TopEncodeOutput,
+    dep_encode_num_mimic, nested_encode_max_len_fixed, DecodeError, DecodeErrorHandler,
+    EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeMaxLen,
+    NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
 };
 
 impl TopEncode for bool {
@@ -36,6 +36,8 @@ impl TopDecode for bool {
 
 dep_encode_num_mimic! {bool, u8}
 
+nested_encode_max_len_fixed!(bool, 1);
+
 impl NestedDecode for bool {
     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
     where
@@ -1,6 +1,7 @@
 use crate::{
     DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
-    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+    TopEncodeOutput,
 };
 
 /// Empty structure with an empty bytes representation. Equivalent to `false`, `0` or `[u8; 0]`, but more explicit.
@@ -45,6 +46,9 @@ impl NestedEncode for Empty {
         Ok(())
     }
 }
+impl NestedEncodeMaxLen for Empty {
+    const MAX_NESTED_LEN: Option<usize> = Some(0);
+}
 
 impl NestedDecode for Empty {
     #[inline]
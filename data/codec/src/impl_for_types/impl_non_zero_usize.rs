@@ -2,7 +2,8 @@ use core::num::NonZeroUsize;
 
 use crate::{
     DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
-    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+    TopEncodeOutput,
 };
 
 impl TopEncode for NonZeroUsize {
@@ -39,6 +40,9 @@ impl NestedEncode for NonZeroUsize {
         self.get().dep_encode_or_handle_err(dest, h)
     }
 }
+impl NestedEncodeMaxLen for NonZeroUsize {
+    const MAX_NESTED_LEN: Option<usize> = Some(4);
+}
 
 impl NestedDecode for NonZeroUsize {
     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
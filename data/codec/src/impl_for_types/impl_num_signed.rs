@@ -1,7 +1,8 @@
 use crate::{
-    dep_encode_num_mimic, num_conv::universal_decode_number, DecodeError, DecodeErrorHandler,
-    EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput,
-    TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    dep_encode_num_mimic, nested_encode_max_len_fixed, num_conv::universal_decode_number,
+    DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
+    NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+    TopEncodeOutput,
 };
 
 macro_rules! top_encode_num_signed {
@@ -32,6 +33,12 @@ dep_encode_num_mimic! {isize, u32}
 dep_encode_num_mimic! {i16, u16}
 dep_encode_num_mimic! {i8, u8}
 
+nested_encode_max_len_fixed!(i8, 1);
+nested_encode_max_len_fixed!(i16, 2);
+nested_encode_max_len_fixed!(i32, 4);
+nested_encode_max_len_fixed!(isize, 4);
+nested_encode_max_len_fixed!(i64, 8);
+
 macro_rules! dep_decode_num_signed {
     ($ty:ty, $num_bytes:expr) => {
         impl NestedDecode for $ty {
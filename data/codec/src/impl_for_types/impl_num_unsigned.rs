@@ -1,7 +1,8 @@
 use crate::{
-    dep_encode_num_mimic, num_conv::universal_decode_number, DecodeError, DecodeErrorHandler,
-    EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput,
-    TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    dep_encode_num_mimic, nested_encode_max_len_fixed, num_conv::universal_decode_number,
+    DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
+    NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+    TopEncodeOutput,
 };
 
 // No reversing needed for u8, because it is a single byte.
@@ -109,6 +110,12 @@ macro_rules! dep_decode_num_unsigned {
     };
 }
 
+nested_encode_max_len_fixed!(u8, 1);
+nested_encode_max_len_fixed!(u16, 2);
+nested_encode_max_len_fixed!(u32, 4);
+nested_encode_max_len_fixed!(usize, 4);
+nested_encode_max_len_fixed!(u64, 8);
+
 dep_decode_num_unsigned!(u16, 2);
 dep_decode_num_unsigned!(u32, 4);
 dep_decode_num_unsigned!(usize, 4);
@@ -1,6 +1,7 @@
 use crate::{
-    DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
-    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    max_len_add, DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
+    NestedDecodeInput, NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode,
+    TopDecodeInput, TopEncode, TopEncodeOutput,
 };
 
 impl<T: NestedEncode> NestedEncode for Option<T> {
@@ -21,6 +22,9 @@ impl<T: NestedEncode> NestedEncode for Option<T> {
         }
     }
 }
+impl<T: NestedEncodeMaxLen> NestedEncodeMaxLen for Option<T> {
+    const MAX_NESTED_LEN: Option<usize> = max_len_add(Some(1), T::MAX_NESTED_LEN);
+}
 
 impl<T: NestedDecode> NestedDecode for Option<T> {
     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
@@ -2,7 +2,8 @@ use core::marker::PhantomData;
 
 use crate::{
     DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
-    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+    TopEncodeOutput,
 };
 
 /// Empty structure with an empty bytes representation. Equivalent to `false`, `0` or `[u8; 0]`, but more explicit.
@@ -45,6 +46,9 @@ impl<T> NestedEncode for PhantomData<T> {
         Ok(())
     }
 }
+impl<T> NestedEncodeMaxLen for PhantomData<T> {
+    const MAX_NESTED_LEN: Option<usize> = Some(0);
+}
 
 impl<T> NestedDecode for PhantomData<T> {
     #[inline]
@@ -1,6 +1,6 @@
 use crate::{
     DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
-    NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
 };
 use alloc::boxed::Box;
 
@@ -46,6 +46,9 @@ impl<T: NestedEncode> NestedEncode for &T {
         (*self).dep_encode_or_handle_err(dest, h)
     }
 }
+impl<T: NestedEncodeMaxLen> NestedEncodeMaxLen for &T {
+    const MAX_NESTED_LEN: Option<usize> = T::MAX_NESTED_LEN;
+}
 
 impl<T: NestedEncode> NestedEncode for Box<T> {
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -56,6 +59,9 @@ impl<T: NestedEncode> NestedEncode for Box<T> {
         self.as_ref().dep_encode_or_handle_err(dest, h)
     }
 }
+impl<T: NestedEncodeMaxLen> NestedEncodeMaxLen for Box<T> {
+    const MAX_NESTED_LEN: Option<usize> = T::MAX_NESTED_LEN;
+}
 
 impl<T: NestedDecode> NestedDecode for Box<T> {
     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
@@ -3,7 +3,7 @@ use num_bigint::BigInt;
 
 use crate::{
     DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
-    NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
 };
 
 impl TopEncode for BigInt {
@@ -38,6 +38,9 @@ impl NestedEncode for BigInt {
         self.to_signed_bytes_be().dep_encode_or_handle_err(dest, h)
     }
 }
+impl NestedEncodeMaxLen for BigInt {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
 
 impl NestedDecode for BigInt {
     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
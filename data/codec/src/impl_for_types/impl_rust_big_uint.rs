@@ -3,7 +3,7 @@ use num_bigint::BigUint;
 
 use crate::{
     DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput, NestedEncode,
-    NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
 };
 
 impl TopEncode for BigUint {
@@ -37,6 +37,9 @@ impl NestedEncode for BigUint {
         self.to_bytes_be().dep_encode_or_handle_err(dest, h)
     }
 }
+impl NestedEncodeMaxLen for BigUint {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
 
 impl NestedDecode for BigUint {
     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
@@ -1,6 +1,6 @@
 use crate::{
     vec_into_boxed_slice, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedEncode,
-    NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
 };
 use alloc::{boxed::Box, vec::Vec};
 
@@ -109,6 +109,9 @@ impl<T: NestedEncode> NestedEncode for &[T] {
         dep_encode_slice_contents(self, dest, h)
     }
 }
+impl<T: NestedEncode> NestedEncodeMaxLen for &[T] {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
 
 impl<T: NestedEncode> NestedEncode for Box<[T]> {
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -119,5 +122,8 @@ impl<T: NestedEncode> NestedEncode for Box<[T]> {
         self.as_ref().dep_encode_or_handle_err(dest, h)
     }
 }
+impl<T: NestedEncode> NestedEncodeMaxLen for Box<[T]> {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
 
 // TODO: NestedDecode for Box<[T]> missing
@@ -1,6 +1,7 @@
 use crate::{
     DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
-    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+    NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+    TopEncodeOutput,
 };
 use alloc::{boxed::Box, string::String, vec::Vec};
 
@@ -71,6 +72,9 @@ impl NestedEncode for String {
         self.as_bytes().dep_encode_or_handle_err(dest, h)
     }
 }
+impl NestedEncodeMaxLen for String {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
 
 impl NestedEncode for &str {
     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
@@ -81,6 +85,9 @@ impl NestedEncode for &str {
         self.as_bytes().dep_encode_or_handle_err(dest, h)
     }
 }
+impl NestedEncodeMaxLen for &str {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
 
 impl NestedEncode for Box<str> {
     #[inline]
@@ -92,6 +99,9 @@ impl NestedEncode for Box<str> {
         self.as_ref().as_bytes().dep_encode_or_handle_err(dest, h)
     }
 }
+impl NestedEncodeMaxLen for Box<str> {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
 
 impl NestedDecode for String {
     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
@@ -1,7 +1,7 @@
 use crate::{
-    top_decode_from_nested_or_handle_err, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
-    NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
-    TopEncodeOutput,
+    max_len_add, top_decode_from_nested_or_handle_err, DecodeErrorHandler, EncodeErrorHandler,
+    NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput,
+    TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
 };
 
 macro_rules! tuple_impls {
@@ -54,6 +54,19 @@ macro_rules! tuple_impls {
 				}
             }
 
+            impl<$($name),+> NestedEncodeMaxLen for ($($name,)+)
+            where
+                $($name: NestedEncodeMaxLen,)+
+            {
+                const MAX_NESTED_LEN: Option<usize> = {
+                    let max_len = Some(0);
+                    $(
+                        let max_len = max_len_add(max_len, $name::MAX_NESTED_LEN);
+                    )+
+                    max_len
+                };
+            }
+
             impl<$($name),+> NestedDecode for ($($name,)+)
             where
                 $($name: NestedDecode,)+
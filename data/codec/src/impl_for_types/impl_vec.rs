@@ -1,7 +1,7 @@
 use crate::{
     boxed_slice_into_vec, DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
-    NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
-    TopEncodeOutput,
+    NestedDecodeInput, NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode,
+    TopDecodeInput, TopEncode, TopEncodeOutput,
 };
 use alloc::vec::Vec;
 
@@ -55,6 +55,9 @@ impl<T: NestedEncode> NestedEncode for Vec<T> {
         self.as_slice().dep_encode_or_handle_err(dest, h)
     }
 }
+impl<T: NestedEncode> NestedEncodeMaxLen for Vec<T> {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
 
 impl<T: NestedDecode> NestedDecode for Vec<T> {
     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
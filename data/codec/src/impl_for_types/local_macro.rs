@@ -18,3 +18,13 @@ macro_rules! dep_encode_num_mimic {
         }
     };
 }
+
+// Types with a fixed-size nested encoding.
+#[macro_export]
+macro_rules! nested_encode_max_len_fixed {
+    ($ty:ty, $num_bytes:expr) => {
+        impl NestedEncodeMaxLen for $ty {
+            const MAX_NESTED_LEN: Option<usize> = Some($num_bytes);
+        }
+    };
+}
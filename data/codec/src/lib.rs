@@ -20,8 +20,14 @@ pub use arrayvec;
 #[cfg(feature = "num-bigint")]
 pub use num_bigint;
 
+/// Reexport needed by the `CodecArbitrary` derive.
+#[cfg(feature = "arbitrary")]
+pub use arbitrary;
+
 // TODO: group into smaller sub-modules
 
+#[cfg(feature = "arbitrary")]
+mod codec_arbitrary;
 mod codec_err;
 mod codec_err_handler;
 mod default_traits;
@@ -41,6 +47,8 @@ pub use crate::{
         try_cast_execute_or_else, try_cast_ref, try_execute_then_cast, TryStaticCast,
     },
 };
+#[cfg(feature = "arbitrary")]
+pub use codec_arbitrary::{codec_arbitrary_len, CodecArbitrary};
 pub use codec_err::{DecodeError, EncodeError};
 pub use codec_err_handler::*;
 pub use default_traits::{DecodeDefault, EncodeDefault};
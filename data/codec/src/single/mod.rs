@@ -3,6 +3,7 @@ mod nested_de_input;
 mod nested_de_input_owned;
 mod nested_de_input_slice;
 mod nested_en;
+mod nested_en_max_len;
 mod nested_en_output;
 mod top_de;
 mod top_de_input;
@@ -14,6 +15,9 @@ pub use nested_de_input::NestedDecodeInput;
 pub use nested_de_input_owned::OwnedBytesNestedDecodeInput;
 pub use nested_de_input_slice::dep_decode_from_byte_slice;
 pub use nested_en::{dep_encode_to_vec, NestedEncode};
+pub use nested_en_max_len::{
+    max_len_add, max_len_max, max_len_mul, AssertMaxNestedLen, NestedEncodeMaxLen,
+};
 pub use nested_en_output::NestedEncodeOutput;
 pub use top_de::{top_decode_from_nested, top_decode_from_nested_or_handle_err, TopDecode};
 pub use top_de_input::TopDecodeInput;
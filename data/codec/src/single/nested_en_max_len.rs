@@ -0,0 +1,56 @@
+use core::marker::PhantomData;
+
+use crate::NestedEncode;
+
+/// Types whose nested encoding has a known upper bound, computed at compile time.
+///
+/// `None` means the encoding is unbounded, as is the case for vectors, strings or big numbers.
+///
+/// The top encoding is never longer than the nested encoding, so the bound applies to it too.
+pub trait NestedEncodeMaxLen: NestedEncode {
+    /// The maximum number of bytes the nested encoding can produce, if bounded.
+    const MAX_NESTED_LEN: Option<usize>;
+}
+
+/// Maximum length of two values encoded one after the other. Unbounded if any of them is.
+pub const fn max_len_add(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+    match (a, b) {
+        (Some(a), Some(b)) => a.checked_add(b),
+        _ => None,
+    }
+}
+
+/// Maximum length of `n` values of the same type, encoded one after the other.
+pub const fn max_len_mul(a: Option<usize>, n: usize) -> Option<usize> {
+    match a {
+        Some(a) => a.checked_mul(n),
+        None => None,
+    }
+}
+
+/// Maximum length of a value that can be either of two kinds, e.g. two enum variants.
+pub const fn max_len_max(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            if a > b {
+                Some(a)
+            } else {
+                Some(b)
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Compile-time check that the nested encoding of `T` is bounded, and never exceeds `MAX` bytes.
+///
+/// Referencing `AssertMaxNestedLen::<T, MAX>::VALID` in a generic function
+/// fails the build as soon as that function is instantiated with a type that does not fit.
+pub struct AssertMaxNestedLen<T, const MAX: usize>(PhantomData<T>);
+
+impl<T: NestedEncodeMaxLen, const MAX: usize> AssertMaxNestedLen<T, MAX> {
+    pub const VALID: () = assert!(
+        matches!(T::MAX_NESTED_LEN, Some(max_len) if max_len <= MAX),
+        "type encoding is unbounded or exceeds the maximum length"
+    );
+}
@@ -99,3 +99,62 @@ where
     let deserialized: V = check_dep_decode::<V>(&serialized_bytes[..]);
     assert_eq!(deserialized, element);
 }
+
+/// Checks that a value top-encodes and then top-decodes back to itself, whatever the encoding.
+pub fn check_top_round_trip<V>(element: &V)
+where
+    V: TopEncode + TopDecode + PartialEq + Debug,
+{
+    let serialized_bytes = check_top_encode(element);
+    let deserialized: V = check_top_decode::<V>(&serialized_bytes[..]);
+    assert_eq!(&deserialized, element);
+}
+
+/// Checks that a value nested-encodes and then nested-decodes back to itself, whatever the encoding.
+pub fn check_dep_round_trip<V>(element: &V)
+where
+    V: NestedEncode + NestedDecode + PartialEq + Debug,
+{
+    let serialized_bytes = check_dep_encode(element);
+    let deserialized: V = check_dep_decode::<V>(&serialized_bytes[..]);
+    assert_eq!(&deserialized, element);
+}
+
+/// Generates `iterations` arbitrary values and checks that each of them round-trips,
+/// both top-encoded and nested-encoded.
+///
+/// The values are derived from a pseudo-random sequence, so the same seed always checks the same values.
+#[cfg(feature = "arbitrary")]
+pub fn check_arbitrary_round_trips<V>(seed: u64, iterations: usize)
+where
+    V: CodecArbitrary + TopEncode + TopDecode + NestedEncode + NestedDecode + PartialEq + Debug,
+{
+    let mut rng_state = seed;
+    for _ in 0..iterations {
+        let raw_data = pseudo_random_bytes(&mut rng_state, ARBITRARY_RAW_DATA_LEN);
+        let mut unstructured = arbitrary::Unstructured::new(&raw_data);
+        let element = V::codec_arbitrary(&mut unstructured)
+            .unwrap_or_else(|err| panic!("could not generate arbitrary value: {err}"));
+        check_top_round_trip(&element);
+        check_dep_round_trip(&element);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+const ARBITRARY_RAW_DATA_LEN: usize = 512;
+
+/// SplitMix64, good enough to feed `arbitrary`, and avoids a dependency on a random number generator.
+#[cfg(feature = "arbitrary")]
+fn pseudo_random_bytes(state: &mut u64, len: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(len);
+    while bytes.len() < len {
+        *state = state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = *state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^= z >> 31;
+        bytes.extend_from_slice(&z.to_be_bytes());
+    }
+    bytes.truncate(len);
+    bytes
+}
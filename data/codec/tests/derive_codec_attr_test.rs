@@ -0,0 +1,112 @@
+use multiversx_sc_codec as codec;
+use multiversx_sc_codec_derive::*;
+
+use codec::{
+    test_util::{check_dep_encode_decode, check_top_encode, check_top_encode_decode},
+    DecodeError, TopDecode,
+};
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructWithSkip {
+    pub int: u16,
+    #[codec(skip)]
+    pub cache: Vec<u8>,
+    #[codec(skip, default = 7)]
+    pub counter: u32,
+    pub another_byte: u8,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct TupleStructWithSkip(pub u8, #[codec(skip)] pub u64, pub u8);
+
+/// Variants declared in a different order than their discriminants.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Status {
+    #[codec(discriminant = 2)]
+    Paused,
+    #[codec(discriminant = 0)]
+    Inactive,
+    // implicitly 1, following the previous discriminant
+    Active,
+    #[codec(discriminant = 10)]
+    Closed,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub enum Action {
+    #[codec(discriminant = 5)]
+    Transfer {
+        amount: u32,
+        #[codec(skip)]
+        memo: Vec<u8>,
+    },
+    #[codec(discriminant = 0)]
+    Nothing,
+    Stake(u8, #[codec(skip, default = true)] bool),
+}
+
+#[test]
+fn skip_struct_fields() {
+    let value = StructWithSkip {
+        int: 0x42,
+        cache: Vec::new(),
+        counter: 7,
+        another_byte: 6,
+    };
+    check_top_encode_decode(value.clone(), &[0, 0x42, 6]);
+    check_dep_encode_decode(value, &[0, 0x42, 6]);
+
+    // skipped fields are not encoded, and get their default value on decode
+    let value = StructWithSkip {
+        int: 0x42,
+        cache: vec![1, 2, 3],
+        counter: 100,
+        another_byte: 6,
+    };
+    let bytes = check_top_encode(&value);
+    assert_eq!(bytes, [0, 0x42, 6]);
+    let decoded = StructWithSkip::top_decode(bytes).unwrap();
+    assert!(decoded.cache.is_empty());
+    assert_eq!(decoded.counter, 7);
+}
+
+#[test]
+fn skip_tuple_struct_fields() {
+    check_top_encode_decode(TupleStructWithSkip(1, 0, 2), &[1, 2]);
+    check_dep_encode_decode(TupleStructWithSkip(1, 0, 2), &[1, 2]);
+}
+
+#[test]
+fn explicit_discriminants_fieldless() {
+    check_top_encode_decode(Status::Inactive, &[]);
+    check_top_encode_decode(Status::Active, &[1]);
+    check_top_encode_decode(Status::Paused, &[2]);
+    check_top_encode_decode(Status::Closed, &[10]);
+
+    check_dep_encode_decode(Status::Inactive, &[0]);
+    check_dep_encode_decode(Status::Active, &[1]);
+    check_dep_encode_decode(Status::Paused, &[2]);
+    check_dep_encode_decode(Status::Closed, &[10]);
+
+    assert_eq!(
+        Status::top_decode(&[3][..]),
+        Err(DecodeError::INVALID_VALUE)
+    );
+}
+
+#[test]
+fn explicit_discriminants_with_fields() {
+    // the variant with discriminant 0 is the default, even if not declared first
+    check_top_encode_decode(Action::Nothing, &[]);
+    check_dep_encode_decode(Action::Nothing, &[0]);
+
+    check_top_encode_decode(Action::Stake(3, true), &[1, 3]);
+    check_dep_encode_decode(Action::Stake(3, true), &[1, 3]);
+
+    let transfer = Action::Transfer {
+        amount: 4,
+        memo: Vec::new(),
+    };
+    check_top_encode_decode(transfer.clone(), &[5, 0, 0, 0, 4]);
+    check_dep_encode_decode(transfer, &[5, 0, 0, 0, 4]);
+}
@@ -0,0 +1,94 @@
+use multiversx_sc_codec as codec;
+use multiversx_sc_codec_derive::*;
+
+use codec::{arrayvec::ArrayVec, test_util::check_dep_encode, NestedEncodeMaxLen};
+
+#[derive(NestedEncode, NestedEncodeMaxLen, PartialEq, Eq, Clone, Debug)]
+pub struct FixedLayout {
+    pub int: u16,
+    pub flag: bool,
+    pub opt: Option<u64>,
+    pub arr: [u8; 5],
+    #[codec(skip)]
+    pub cache: Vec<u8>,
+}
+
+#[derive(NestedEncode, NestedEncodeMaxLen, PartialEq, Eq, Clone, Debug)]
+pub struct TupleLayout(pub u8, pub (u32, i64));
+
+#[derive(NestedEncode, NestedEncodeMaxLen, PartialEq, Eq, Clone, Debug)]
+pub struct Unbounded {
+    pub int: u16,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(NestedEncode, NestedEncodeMaxLen, PartialEq, Eq, Clone, Debug)]
+pub enum Shape {
+    Empty,
+    Point(u8),
+    Rect { width: u32, height: u32 },
+}
+
+#[derive(NestedEncode, NestedEncodeMaxLen, PartialEq, Eq, Clone, Debug)]
+pub enum MaybeUnbounded {
+    Small(u8),
+    Large(Vec<u8>),
+}
+
+#[derive(NestedEncode, NestedEncodeMaxLen, PartialEq, Eq, Clone, Debug)]
+pub struct Generic<T: NestedEncodeMaxLen> {
+    pub value: T,
+    pub nonce: u64,
+}
+
+#[test]
+fn nested_encode_max_len_primitives_test() {
+    assert_eq!(u8::MAX_NESTED_LEN, Some(1));
+    assert_eq!(usize::MAX_NESTED_LEN, Some(4));
+    assert_eq!(i64::MAX_NESTED_LEN, Some(8));
+    assert_eq!(bool::MAX_NESTED_LEN, Some(1));
+    assert_eq!(<Option<u32>>::MAX_NESTED_LEN, Some(5));
+    assert_eq!(<[u16; 3]>::MAX_NESTED_LEN, Some(6));
+    assert_eq!(<(u8, u32)>::MAX_NESTED_LEN, Some(5));
+    assert_eq!(<ArrayVec<u8, 10>>::MAX_NESTED_LEN, Some(14));
+    assert_eq!(<Box<u64>>::MAX_NESTED_LEN, Some(8));
+    assert_eq!(<Vec<u8>>::MAX_NESTED_LEN, None);
+    assert_eq!(String::MAX_NESTED_LEN, None);
+    assert_eq!(<Option<Vec<u8>>>::MAX_NESTED_LEN, None);
+}
+
+#[test]
+fn nested_encode_max_len_struct_test() {
+    assert_eq!(FixedLayout::MAX_NESTED_LEN, Some(2 + 1 + 9 + 5));
+    assert_eq!(TupleLayout::MAX_NESTED_LEN, Some(13));
+    assert_eq!(Unbounded::MAX_NESTED_LEN, None);
+    assert_eq!(<Generic<u32>>::MAX_NESTED_LEN, Some(12));
+    assert_eq!(<Generic<String>>::MAX_NESTED_LEN, None);
+
+    let largest = FixedLayout {
+        int: 1,
+        flag: true,
+        opt: Some(2),
+        arr: [3; 5],
+        cache: vec![4; 100],
+    };
+    assert_eq!(
+        Some(check_dep_encode(&largest).len()),
+        FixedLayout::MAX_NESTED_LEN
+    );
+}
+
+#[test]
+fn nested_encode_max_len_enum_test() {
+    assert_eq!(Shape::MAX_NESTED_LEN, Some(1 + 8));
+    assert_eq!(MaybeUnbounded::MAX_NESTED_LEN, None);
+
+    let largest = Shape::Rect {
+        width: 1,
+        height: 2,
+    };
+    assert_eq!(
+        Some(check_dep_encode(&largest).len()),
+        Shape::MAX_NESTED_LEN
+    );
+}
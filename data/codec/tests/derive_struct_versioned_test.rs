@@ -0,0 +1,115 @@
+use multiversx_sc_codec as codec;
+use multiversx_sc_codec_derive::*;
+
+use codec::{
+    dep_decode_from_byte_slice,
+    test_util::{check_dep_encode_decode, check_top_encode, check_top_encode_decode},
+    DecodeError, DefaultErrorHandler, TopDecode,
+};
+
+/// The original layout, as found in storage.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct PositionV1 {
+    pub amount: u32,
+    pub owner_id: u16,
+}
+
+/// Fields were added in later versions of the contract.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct Position {
+    pub amount: u32,
+    pub owner_id: u16,
+    #[codec(since = 2)]
+    pub locked: bool,
+    #[codec(since = 3, default = 100)]
+    pub multiplier: u64,
+    #[codec(since = 3)]
+    pub tags: Vec<u8>,
+}
+
+#[test]
+fn versioned_struct_round_trip() {
+    let position = Position {
+        amount: 5,
+        owner_id: 1,
+        locked: true,
+        multiplier: 7,
+        tags: vec![9],
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* amount */ 0, 0, 0, 5,
+        /* owner_id */ 0, 1,
+        /* locked */ 1,
+        /* multiplier */ 0, 0, 0, 0, 0, 0, 0, 7,
+        /* tags */ 0, 0, 0, 1, 9,
+    ];
+
+    check_top_encode_decode(position.clone(), bytes);
+    check_dep_encode_decode(position, bytes);
+}
+
+#[test]
+fn versioned_struct_decode_v1() {
+    let v1_bytes = check_top_encode(&PositionV1 {
+        amount: 5,
+        owner_id: 1,
+    });
+    assert_eq!(
+        Position::top_decode(v1_bytes).unwrap(),
+        Position {
+            amount: 5,
+            owner_id: 1,
+            locked: false,
+            multiplier: 100,
+            tags: Vec::new(),
+        }
+    );
+}
+
+#[test]
+fn versioned_struct_decode_v2() {
+    #[rustfmt::skip]
+    let v2_bytes: &[u8] = &[
+        /* amount */ 0, 0, 0, 5,
+        /* owner_id */ 0, 1,
+        /* locked */ 1,
+    ];
+    assert_eq!(
+        Position::top_decode(v2_bytes).unwrap(),
+        Position {
+            amount: 5,
+            owner_id: 1,
+            locked: true,
+            multiplier: 100,
+            tags: Vec::new(),
+        }
+    );
+}
+
+#[test]
+fn versioned_struct_decode_truncated_field() {
+    // the original fields are still mandatory
+    assert_eq!(
+        Position::top_decode(&[0, 0, 0, 5, 0][..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+    // a versioned field is either missing or complete
+    assert_eq!(
+        Position::top_decode(&[0, 0, 0, 5, 0, 1, 1, 0, 0][..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
+
+#[test]
+fn versioned_struct_nested_is_strict() {
+    // nested values are not delimited, so older encodings cannot be told apart
+    let v1_bytes = check_top_encode(&PositionV1 {
+        amount: 5,
+        owner_id: 1,
+    });
+    let result: Result<Position, DecodeError> =
+        dep_decode_from_byte_slice(&v1_bytes, DefaultErrorHandler);
+    assert_eq!(result, Err(DecodeError::INPUT_TOO_SHORT));
+}
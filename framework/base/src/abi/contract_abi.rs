@@ -13,6 +13,7 @@ pub struct ContractAbi {
     pub endpoints: Vec<EndpointAbi>,
     pub promise_callbacks: Vec<EndpointAbi>,
     pub events: Vec<EventAbi>,
+    pub storage: Vec<StorageAbi>,
     pub esdt_attributes: Vec<EsdtAttributeAbi>,
     pub has_callback: bool,
     pub type_descriptions: TypeDescriptionContainerImpl,
@@ -29,6 +30,7 @@ impl ContractAbi {
             endpoints: Vec::new(),
             promise_callbacks: Vec::new(),
             events: Vec::new(),
+            storage: Vec::new(),
             esdt_attributes: Vec::new(),
             has_callback,
             type_descriptions: TypeDescriptionContainerImpl::new(),
@@ -40,6 +42,7 @@ impl ContractAbi {
             .extend_from_slice(other.constructors.as_slice());
         self.endpoints.extend_from_slice(other.endpoints.as_slice());
         self.events.extend_from_slice(other.events.as_slice());
+        self.storage.extend_from_slice(other.storage.as_slice());
         self.promise_callbacks
             .extend_from_slice(other.promise_callbacks.as_slice());
         self.has_callback |= other.has_callback;
@@ -44,6 +44,8 @@ pub struct EndpointAbi {
     pub rust_method_name: String,
     pub only_owner: bool,
     pub only_admin: bool,
+    /// Set by `#[only_role("...")]`, only callers holding the role can call the endpoint.
+    pub only_role: Option<String>,
     pub labels: Vec<String>,
     pub endpoint_type: EndpointTypeAbi,
     pub mutability: EndpointMutabilityAbi,
@@ -64,6 +66,7 @@ impl EndpointAbi {
         rust_method_name: &str,
         only_owner: bool,
         only_admin: bool,
+        only_role: Option<&str>,
         mutability: EndpointMutabilityAbi,
         endpoint_type: EndpointTypeAbi,
         payable_in_tokens: &[&str],
@@ -76,6 +79,7 @@ impl EndpointAbi {
             rust_method_name: rust_method_name.to_string(),
             only_owner,
             only_admin,
+            only_role: only_role.map(|role| role.to_string()),
             labels: labels.iter().map(|s| s.to_string()).collect(),
             endpoint_type,
             mutability,
@@ -0,0 +1,106 @@
+use super::*;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::marker::PhantomData;
+
+/// Type name used in the storage section for types that do not implement `TypeAbi`.
+pub const STORAGE_UNKNOWN_TYPE_NAME: &str = "unknown";
+
+/// Mapper name of `#[storage_get]` methods.
+pub const STORAGE_GET_MAPPER_NAME: &str = "storage_get";
+
+/// Mapper name of `#[storage_set]` methods.
+pub const STORAGE_SET_MAPPER_NAME: &str = "storage_set";
+
+/// Describes a storage key declared in the contract, via `#[storage_mapper]`, `#[storage_get]` or `#[storage_set]`.
+#[derive(Clone, Debug)]
+pub struct StorageAbi {
+    pub docs: Vec<String>,
+
+    /// The base key, as given in the annotation.
+    pub key: String,
+
+    /// The name of the storage mapper type, or `storage_get`/`storage_set` for plain accessors.
+    pub mapper: String,
+
+    /// Method arguments that get appended to the base key.
+    pub key_args: Vec<InputAbi>,
+
+    /// The types of the stored values, as they appear in the generic arguments of the mapper.
+    ///
+    /// Their meaning depends on the mapper, e.g. for `MapMapper` it is the key and value type.
+    pub value_types: Vec<TypeName>,
+}
+
+impl StorageAbi {
+    /// Used in code generation.
+    pub fn new(docs: &[&str], key: &str, mapper: &str) -> Self {
+        StorageAbi {
+            docs: docs.iter().map(|s| s.to_string()).collect(),
+            key: key.to_string(),
+            mapper: mapper.to_string(),
+            key_args: Vec::new(),
+            value_types: Vec::new(),
+        }
+    }
+
+    /// Used in code generation.
+    pub fn add_key_arg(&mut self, arg_name: &str, type_name: TypeName) {
+        self.key_args.push(InputAbi {
+            arg_name: arg_name.to_string(),
+            type_name,
+            multi_arg: false,
+        });
+    }
+
+    /// Used in code generation.
+    pub fn add_value_type(&mut self, type_name: TypeName) {
+        self.value_types.push(type_name);
+    }
+}
+
+/// Storage only requires types to be encodable, they are not required to implement `TypeAbi`.
+///
+/// The generated ABI code uses this probe to retrieve the type name when available,
+/// and fall back to `"unknown"` otherwise. The selection relies on method resolution preferring
+/// the `StorageTypeAbiProbeSpecified` implementation, so it only works for concrete types.
+pub struct StorageTypeAbiProbe<T>(PhantomData<T>);
+
+impl<T> StorageTypeAbiProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        StorageTypeAbiProbe(PhantomData)
+    }
+}
+
+pub trait StorageTypeAbiProbeSpecified {
+    fn probe_type_name(&self) -> TypeName;
+
+    fn probe_type_descriptions(&self, contract_abi: &mut ContractAbi);
+}
+
+impl<T: TypeAbi> StorageTypeAbiProbeSpecified for StorageTypeAbiProbe<T> {
+    fn probe_type_name(&self) -> TypeName {
+        T::type_name()
+    }
+
+    fn probe_type_descriptions(&self, contract_abi: &mut ContractAbi) {
+        contract_abi.add_type_descriptions::<T>();
+    }
+}
+
+pub trait StorageTypeAbiProbeFallback {
+    fn probe_type_name(&self) -> TypeName;
+
+    fn probe_type_descriptions(&self, contract_abi: &mut ContractAbi);
+}
+
+impl<T> StorageTypeAbiProbeFallback for &StorageTypeAbiProbe<T> {
+    fn probe_type_name(&self) -> TypeName {
+        STORAGE_UNKNOWN_TYPE_NAME.into()
+    }
+
+    fn probe_type_descriptions(&self, _contract_abi: &mut ContractAbi) {}
+}
@@ -14,11 +14,7 @@ pub trait TypeAbi {
         let type_name = Self::type_name();
         accumulator.insert(
             type_name,
-            TypeDescription {
-                docs: Vec::new(),
-                name: Self::type_name(),
-                contents: TypeContents::NotSpecified,
-            },
+            TypeDescription::new(&[], Self::type_name(), TypeContents::NotSpecified),
         );
     }
 
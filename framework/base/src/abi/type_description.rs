@@ -8,6 +8,12 @@ pub struct TypeDescription {
     pub docs: Vec<String>,
     pub name: String,
     pub contents: TypeContents,
+
+    /// The maximum length of the nested encoding, if declared with `#[codec(max_len)]` and bounded.
+    pub max_nested_len: Option<usize>,
+
+    /// The states and transitions, if the enum is declared as a state machine.
+    pub state_machine: Option<StateMachineDescription>,
 }
 
 impl TypeDescription {
@@ -19,6 +25,8 @@ impl TypeDescription {
         docs: Vec::new(),
         name: String::new(),
         contents: TypeContents::NotSpecified,
+        max_nested_len: None,
+        state_machine: None,
     };
 }
 
@@ -29,8 +37,22 @@ impl TypeDescription {
             docs: docs.iter().map(|s| s.to_string()).collect(),
             name,
             contents,
+            max_nested_len: None,
+            state_machine: None,
         }
     }
+
+    /// Used in code generation.
+    pub fn max_nested_len(mut self, max_nested_len: Option<usize>) -> Self {
+        self.max_nested_len = max_nested_len;
+        self
+    }
+
+    /// Used in code generation.
+    pub fn state_machine(mut self, state_machine: StateMachineDescription) -> Self {
+        self.state_machine = Some(state_machine);
+        self
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -74,11 +96,56 @@ impl EnumVariantDescription {
     }
 }
 
+/// The state machine declared on an enum with `#[state(initial)]` and `#[transition(...)]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateMachineDescription {
+    /// The name of the initial variant.
+    pub initial_state: String,
+
+    pub transitions: Vec<StateTransitionDescription>,
+}
+
+impl StateMachineDescription {
+    /// Used in code generation.
+    pub fn new(initial_state: &str) -> Self {
+        StateMachineDescription {
+            initial_state: initial_state.to_string(),
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Used in code generation.
+    pub fn transition(mut self, from: &str, to: &str, guard: Option<&str>) -> Self {
+        self.transitions.push(StateTransitionDescription {
+            from: from.to_string(),
+            to: to.to_string(),
+            guard: guard.map(|guard| guard.to_string()),
+        });
+        self
+    }
+}
+
+/// A state machine transition, declared with `#[transition(...)]` on an enum variant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateTransitionDescription {
+    /// The name of the source variant.
+    pub from: String,
+
+    /// The name of the target variant.
+    pub to: String,
+
+    /// The name of the condition the contract checks before the transition, if any.
+    pub guard: Option<String>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StructFieldDescription {
     pub docs: Vec<String>,
     pub name: String,
     pub field_type: String,
+
+    /// The struct version that introduced the field, if declared with `#[codec(since = ...)]`.
+    pub since: Option<u32>,
 }
 
 impl StructFieldDescription {
@@ -88,8 +155,15 @@ impl StructFieldDescription {
             docs: docs.iter().map(|s| s.to_string()).collect(),
             name: name.to_string(),
             field_type,
+            since: None,
         }
     }
+
+    /// Used in code generation.
+    pub fn since(mut self, version: u32) -> Self {
+        self.since = Some(version);
+        self
+    }
 }
 
 /// An explicit enum is an enum that gets serialized by name instead of discriminant.
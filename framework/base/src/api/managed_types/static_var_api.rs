@@ -31,4 +31,13 @@ pub trait StaticVarApiImpl {
     fn set_call_value_multi_esdt_handle(&self, handle: RawHandle);
 
     fn get_call_value_multi_esdt_handle(&self) -> RawHandle;
+
+    /// Whether the current execution has set the reentrancy lock, and not yet released it.
+    ///
+    /// Implementations that do not track it never release the lock before async calls.
+    fn set_reentrancy_lock_held(&self, _held: bool) {}
+
+    fn get_reentrancy_lock_held(&self) -> bool {
+        false
+    }
 }
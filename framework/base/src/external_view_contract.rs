@@ -41,6 +41,7 @@ pub fn external_view_contract_constructor_abi() -> EndpointAbi {
         EXTERNAL_VIEW_CONSTRUCTOR_FLAG,
         false,
         false,
+        None,
         EndpointMutabilityAbi::Mutable,
         EndpointTypeAbi::Init,
         &[],
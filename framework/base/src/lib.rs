@@ -35,6 +35,8 @@ pub mod io;
 pub mod log_util;
 mod macros;
 pub mod non_zero_util;
+pub mod reentrancy_guard;
+pub mod state_machine;
 pub mod storage;
 pub mod types;
 
@@ -41,10 +41,10 @@ macro_rules! derive_imports {
         use multiversx_sc::{
             codec,
             codec::derive::{
-                NestedDecode, NestedEncode, TopDecode, TopDecodeOrDefault, TopEncode,
-                TopEncodeOrDefault,
+                CodecArbitrary, NestedDecode, NestedEncode, NestedEncodeMaxLen, TopDecode,
+                TopDecodeOrDefault, TopEncode, TopEncodeOrDefault,
             },
-            derive::{ManagedVecItem, TypeAbi},
+            derive::{ManagedVecItem, StateMachine, TypeAbi},
         };
     };
 }
@@ -0,0 +1,62 @@
+use crate::{
+    api::{ErrorApi, ErrorApiImpl, ManagedTypeApi, StaticVarApiImpl, StorageWriteApi, VMApi},
+    err_msg,
+    storage::{storage_clear, storage_get_len, storage_set, StorageKey},
+    types::ManagedRef,
+};
+
+/// Storage key of the reentrancy lock.
+///
+/// Shared by the `#[non_reentrant]` endpoints and the `reentrancy_guard` module in `multiversx-sc-modules`,
+/// so that they guard each other.
+pub const REENTRANCY_LOCK_KEY: &[u8] = b"reentrancy_guard:lock";
+
+pub fn is_locked<A>() -> bool
+where
+    A: VMApi,
+{
+    let key = StorageKey::<A>::new(REENTRANCY_LOCK_KEY);
+    storage_get_len(ManagedRef::new(&key)) > 0
+}
+
+/// Called in the generated code at the start of `#[non_reentrant]` endpoints.
+///
+/// Fails if the lock is already set, i.e. if the contract is being called back
+/// from within a guarded endpoint, typically via a sync call.
+pub fn enter<A>()
+where
+    A: VMApi,
+{
+    if is_locked::<A>() {
+        A::error_api_impl().signal_error(err_msg::REENTRANT_CALL);
+    }
+    let key = StorageKey::<A>::new(REENTRANCY_LOCK_KEY);
+    storage_set(ManagedRef::new(&key), &true);
+    A::static_var_api_impl().set_reentrancy_lock_held(true);
+}
+
+/// Called in the generated code at the end of `#[non_reentrant]` endpoints.
+///
+/// Failed executions do not need to clear the lock: all their storage changes are reverted,
+/// including the lock itself.
+pub fn exit<A>()
+where
+    A: StorageWriteApi + ManagedTypeApi + ErrorApi,
+{
+    let key = StorageKey::<A>::new(REENTRANCY_LOCK_KEY);
+    storage_clear(ManagedRef::new(&key));
+    A::static_var_api_impl().set_reentrancy_lock_held(false);
+}
+
+/// Called by the VM API implementations right before a legacy async call,
+/// which ends the execution without failing, so the end of a guarded endpoint is never reached.
+///
+/// Only touches the storage if the current execution holds the lock.
+pub fn exit_before_async_call<A>()
+where
+    A: StorageWriteApi + ManagedTypeApi + ErrorApi,
+{
+    if A::static_var_api_impl().get_reentrancy_lock_held() {
+        exit::<A>();
+    }
+}
@@ -0,0 +1,36 @@
+/// Result of checking whether a state machine may move from one state to another.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StateTransitionCheck {
+    /// The transition is not declared.
+    NotAllowed,
+
+    /// The transition is declared and has no guard.
+    Allowed,
+
+    /// The transition is declared, but the contract must first check the named guard condition.
+    Guarded(&'static str),
+}
+
+/// An enum describing the states of a contract (or of an entity in a contract)
+/// and the transitions allowed between them.
+///
+/// Normally implemented via `#[derive(StateMachine)]`, where:
+/// - `#[state(initial)]` marks the initial state (by default, the first variant);
+/// - `#[transition(to = Next)]` on a variant allows moving from it to `Next`;
+/// - `#[transition(to = Next, guard = "condition")]` also requires a named guard condition to hold.
+///
+/// The `state_machine` module in `multiversx-sc-modules` stores the current state and enforces the transitions.
+pub trait StateMachine: Sized + PartialEq {
+    /// The state of a freshly initialized state machine.
+    fn initial_state() -> Self;
+
+    /// The name of the variant, used in error messages and events.
+    fn state_name(&self) -> &'static str;
+
+    /// Checks whether moving from the current state to the given one is declared.
+    fn check_transition(&self, next: &Self) -> StateTransitionCheck;
+
+    fn can_transition_to(&self, next: &Self) -> bool {
+        self.check_transition(next) != StateTransitionCheck::NotAllowed
+    }
+}
@@ -72,6 +72,10 @@ impl NestedEncode for CodeMetadata {
     }
 }
 
+impl NestedEncodeMaxLen for CodeMetadata {
+    const MAX_NESTED_LEN: Option<usize> = Some(2);
+}
+
 impl TopEncode for CodeMetadata {
     #[inline]
     fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
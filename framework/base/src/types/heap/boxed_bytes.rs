@@ -203,6 +203,10 @@ impl NestedEncode for BoxedBytes {
     }
 }
 
+impl NestedEncodeMaxLen for BoxedBytes {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
+
 impl TopEncode for BoxedBytes {
     #[inline]
     fn top_encode_or_handle_err<O, H>(&self, output: O, _h: H) -> Result<(), H::HandledErr>
@@ -170,6 +170,10 @@ impl NestedEncode for H256 {
     }
 }
 
+impl NestedEncodeMaxLen for H256 {
+    const MAX_NESTED_LEN: Option<usize> = Some(32);
+}
+
 impl TopEncode for H256 {
     fn top_encode_or_handle_err<O, H>(&self, output: O, _h: H) -> Result<(), H::HandledErr>
     where
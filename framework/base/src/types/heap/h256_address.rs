@@ -172,6 +172,10 @@ impl NestedEncode for Address {
     }
 }
 
+impl NestedEncodeMaxLen for Address {
+    const MAX_NESTED_LEN: Option<usize> = Some(32);
+}
+
 impl TopEncode for Address {
     fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
     where
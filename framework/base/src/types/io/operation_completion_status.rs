@@ -98,6 +98,8 @@ impl TypeAbi for OperationCompletionStatus {
                         INTERRUPTED_STR,
                     )
                 ].to_vec()),
+                max_nested_len: None,
+                state_machine: None,
             },
         );
     }
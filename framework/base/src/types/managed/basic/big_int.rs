@@ -8,8 +8,8 @@ use crate::{
     },
     codec::{
         CodecFrom, CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
-        NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
-        TopEncodeOutput, TryStaticCast,
+        NestedDecodeInput, NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode,
+        TopDecodeInput, TopEncode, TopEncodeOutput, TryStaticCast,
     },
     formatter::{hex_util::encode_bytes_as_hex, FormatByteReceiver, SCDisplay},
     types::{heap::BoxedBytes, BigUint, ManagedBuffer, ManagedOption, ManagedType, Sign},
@@ -268,6 +268,10 @@ impl<M: ManagedTypeApi> NestedEncode for BigInt<M> {
     }
 }
 
+impl<M: ManagedTypeApi> NestedEncodeMaxLen for BigInt<M> {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
+
 impl<M: ManagedTypeApi> NestedDecode for BigInt<M> {
     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
     where
@@ -8,8 +8,8 @@ use crate::{
     },
     codec::{
         CodecFrom, CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, NestedDecode,
-        NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
-        TopEncodeOutput, TryStaticCast,
+        NestedDecodeInput, NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode,
+        TopDecodeInput, TopEncode, TopEncodeOutput, TryStaticCast,
     },
     formatter::{hex_util::encode_bytes_as_hex, FormatByteReceiver, SCDisplay},
     types::{heap::BoxedBytes, ManagedBuffer, ManagedType},
@@ -257,6 +257,10 @@ impl<M: ManagedTypeApi> NestedEncode for BigUint<M> {
     }
 }
 
+impl<M: ManagedTypeApi> NestedEncodeMaxLen for BigUint<M> {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
+
 impl<M: ManagedTypeApi> NestedDecode for BigUint<M> {
     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
     where
@@ -6,8 +6,8 @@ use crate::{
     },
     codec::{
         CodecFrom, CodecFromSelf, DecodeErrorHandler, Empty, EncodeErrorHandler, NestedDecode,
-        NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
-        TopEncodeOutput, TryStaticCast,
+        NestedDecodeInput, NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode,
+        TopDecodeInput, TopEncode, TopEncodeOutput, TryStaticCast,
     },
     formatter::{
         hex_util::encode_bytes_as_hex, FormatByteReceiver, SCBinary, SCDisplay, SCLowerHex,
@@ -368,6 +368,10 @@ impl<M: ManagedTypeApi> NestedEncode for ManagedBuffer<M> {
     }
 }
 
+impl<M: ManagedTypeApi> NestedEncodeMaxLen for ManagedBuffer<M> {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
+
 impl<M: ManagedTypeApi> TopEncode for ManagedBuffer<M> {
     #[inline]
     fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+
+use crate::{
+    api::ManagedTypeApi,
+    codec::{
+        arbitrary::{Arbitrary, Result, Unstructured},
+        codec_arbitrary_len, CodecArbitrary,
+    },
+    types::{
+        BigInt, BigUint, EgldOrEsdtTokenIdentifier, ManagedAddress, ManagedBuffer,
+        ManagedByteArray, ManagedVec, ManagedVecItem, TokenIdentifier,
+    },
+};
+
+impl<M: ManagedTypeApi> CodecArbitrary for ManagedBuffer<M> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(ManagedBuffer::new_from_bytes(&Vec::<u8>::codec_arbitrary(
+            u,
+        )?))
+    }
+}
+
+impl<M: ManagedTypeApi> CodecArbitrary for BigUint<M> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(BigUint::from_bytes_be(&Vec::<u8>::codec_arbitrary(u)?))
+    }
+}
+
+impl<M: ManagedTypeApi> CodecArbitrary for BigInt<M> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(BigInt::from_signed_bytes_be(&Vec::<u8>::codec_arbitrary(
+            u,
+        )?))
+    }
+}
+
+impl<M: ManagedTypeApi> CodecArbitrary for TokenIdentifier<M> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(TokenIdentifier::from_esdt_bytes(
+            ManagedBuffer::codec_arbitrary(u)?,
+        ))
+    }
+}
+
+/// Parsed just like when decoding, so identifiers spelled like EGLD become EGLD.
+impl<M: ManagedTypeApi> CodecArbitrary for EgldOrEsdtTokenIdentifier<M> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        if bool::arbitrary(u)? {
+            Ok(EgldOrEsdtTokenIdentifier::egld())
+        } else {
+            Ok(EgldOrEsdtTokenIdentifier::parse(
+                ManagedBuffer::codec_arbitrary(u)?,
+            ))
+        }
+    }
+}
+
+impl<M: ManagedTypeApi> CodecArbitrary for ManagedAddress<M> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        Ok(ManagedAddress::new_from_bytes(&<[u8; 32]>::arbitrary(u)?))
+    }
+}
+
+impl<M: ManagedTypeApi, const N: usize> CodecArbitrary for ManagedByteArray<M, N> {
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        let mut bytes = [0u8; N];
+        u.fill_buffer(&mut bytes[..])?;
+        Ok(ManagedByteArray::new_from_bytes(&bytes))
+    }
+}
+
+impl<M, T> CodecArbitrary for ManagedVec<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedVecItem + CodecArbitrary,
+{
+    fn codec_arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        let mut result = ManagedVec::new();
+        for _ in 0..codec_arbitrary_len(u)? {
+            result.push(T::codec_arbitrary(u)?);
+        }
+        Ok(result)
+    }
+}
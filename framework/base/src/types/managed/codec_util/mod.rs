@@ -2,5 +2,7 @@ mod managed_buffer_nested_de_input;
 mod managed_buffer_nested_en_output;
 mod managed_buffer_top_de_input;
 mod managed_buffer_top_en_output;
+#[cfg(feature = "arbitrary")]
+mod managed_codec_arbitrary;
 
 pub use managed_buffer_nested_de_input::ManagedBufferNestedDecodeInput;
@@ -163,6 +163,10 @@ impl<M: ManagedTypeApi> NestedEncode for EgldOrEsdtTokenIdentifier<M> {
     }
 }
 
+impl<M: ManagedTypeApi> NestedEncodeMaxLen for EgldOrEsdtTokenIdentifier<M> {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
+
 impl<M: ManagedTypeApi> TopEncode for EgldOrEsdtTokenIdentifier<M> {
     #[inline]
     fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
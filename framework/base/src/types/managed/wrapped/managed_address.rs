@@ -5,8 +5,8 @@ use crate::{
     api::ManagedTypeApi,
     codec::{
         CodecFrom, CodecFromSelf, DecodeError, DecodeErrorHandler, EncodeErrorHandler,
-        NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput, TopDecode,
-        TopDecodeInput, TopEncode, TopEncodeOutput, TryStaticCast,
+        NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput,
+        TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput, TryStaticCast,
     },
     formatter::{hex_util::encode_bytes_as_hex, FormatByteReceiver, SCLowerHex},
     types::{heap::Address, ManagedBuffer, ManagedByteArray, ManagedType},
@@ -219,6 +219,13 @@ where
     }
 }
 
+impl<M> NestedEncodeMaxLen for ManagedAddress<M>
+where
+    M: ManagedTypeApi,
+{
+    const MAX_NESTED_LEN: Option<usize> = Some(32);
+}
+
 impl<M> NestedDecode for ManagedAddress<M>
 where
     M: ManagedTypeApi,
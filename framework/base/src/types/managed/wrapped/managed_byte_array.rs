@@ -5,8 +5,8 @@ use crate::{
     api::ManagedTypeApi,
     codec::{
         DecodeError, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
-        NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
-        TryStaticCast,
+        NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+        TopEncodeOutput, TryStaticCast,
     },
     formatter::{hex_util::encode_bytes_as_hex, FormatByteReceiver, SCLowerHex},
     types::{ManagedBuffer, ManagedType},
@@ -184,6 +184,13 @@ where
     }
 }
 
+impl<M, const N: usize> NestedEncodeMaxLen for ManagedByteArray<M, N>
+where
+    M: ManagedTypeApi,
+{
+    const MAX_NESTED_LEN: Option<usize> = Some(N);
+}
+
 impl<M, const N: usize> NestedDecode for ManagedByteArray<M, N>
 where
     M: ManagedTypeApi,
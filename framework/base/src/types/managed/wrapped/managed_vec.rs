@@ -3,7 +3,7 @@ use crate::{
     api::{ErrorApiImpl, InvalidSliceError, ManagedTypeApi},
     codec::{
         DecodeErrorHandler, EncodeErrorHandler, IntoMultiValue, NestedDecode, NestedDecodeInput,
-        NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+        NestedEncode, NestedEncodeMaxLen, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
         TopEncodeMultiOutput, TopEncodeOutput,
     },
     types::{
@@ -581,6 +581,14 @@ where
     }
 }
 
+impl<M, T> NestedEncodeMaxLen for ManagedVec<M, T>
+where
+    M: ManagedTypeApi,
+    T: ManagedVecItem + NestedEncode,
+{
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
+
 impl<M, T> TopDecode for ManagedVec<M, T>
 where
     M: ManagedTypeApi,
@@ -126,6 +126,10 @@ impl<M: ManagedTypeApi> NestedEncode for TokenIdentifier<M> {
     }
 }
 
+impl<M: ManagedTypeApi> NestedEncodeMaxLen for TokenIdentifier<M> {
+    const MAX_NESTED_LEN: Option<usize> = None;
+}
+
 impl<M: ManagedTypeApi> TopEncode for TokenIdentifier<M> {
     #[inline]
     fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
@@ -1,6 +1,9 @@
 use core::marker::PhantomData;
 
-use crate::api::{InvalidSliceError, StaticVarApi, StaticVarApiImpl};
+use crate::{
+    api::{InvalidSliceError, StaticVarApi, StaticVarApiImpl},
+    codec::{AssertMaxNestedLen, NestedEncodeMaxLen, NestedEncodeOutput},
+};
 
 use super::LockableStaticBuffer;
 
@@ -37,6 +40,28 @@ where
     pub fn try_new(bytes: &[u8]) -> Option<Self> {
         Self::try_new_from_copy_bytes(bytes.len(), |dest| dest.copy_from_slice(bytes))
     }
+
+    /// Nested-encodes a value directly into the static buffer.
+    ///
+    /// Only types whose encoding is guaranteed to fit in the buffer are accepted,
+    /// all others are rejected at compile time.
+    pub fn try_new_from_nested_encode<T: NestedEncodeMaxLen>(value: &T) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertMaxNestedLen::<T, { LockableStaticBuffer::capacity() }>::VALID;
+
+        let mut output = StaticBufferNestedEncodeOutput(Self::try_new(&[])?);
+        value.dep_encode(&mut output).ok()?;
+        Some(output.0)
+    }
+}
+
+/// Only used for types whose maximum encoded length fits in the buffer, so writes cannot fail.
+struct StaticBufferNestedEncodeOutput<M: StaticVarApi>(StaticBufferRef<M>);
+
+impl<M: StaticVarApi> NestedEncodeOutput for StaticBufferNestedEncodeOutput<M> {
+    fn write(&mut self, bytes: &[u8]) {
+        let _ = self.0.try_extend_from_slice(bytes);
+    }
 }
 
 impl<M: StaticVarApi> Drop for StaticBufferRef<M> {
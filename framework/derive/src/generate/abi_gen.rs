@@ -1,7 +1,7 @@
 use super::util::*;
 use crate::model::{
-    AutoImpl, ContractTrait, EndpointMutabilityMetadata, EndpointTypeMetadata, Method, MethodImpl,
-    PublicRole,
+    AutoImpl, ContractTrait, EndpointMetadata, EndpointMutabilityMetadata, EndpointTypeMetadata,
+    Method, MethodArgument, MethodImpl, PublicRole,
 };
 
 fn generate_endpoint_snippet(
@@ -49,6 +49,13 @@ fn generate_endpoint_snippet(
         },
     };
 
+    let only_role_tokens = match &m.public_role {
+        PublicRole::Endpoint(EndpointMetadata {
+            only_role: Some(role),
+            ..
+        }) => quote! { Some(#role) },
+        _ => quote! { None },
+    };
     let label_names = &m.label_names;
     let mutability_tokens = mutability.to_tokens();
     let endpoint_type_tokens = endpoint_type.to_tokens();
@@ -60,6 +67,7 @@ fn generate_endpoint_snippet(
             #rust_method_name,
             #only_owner,
             #only_admin,
+            #only_role_tokens,
             #mutability_tokens,
             #endpoint_type_tokens,
             &[ #(#payable_in_tokens),* ],
@@ -171,6 +179,126 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .collect()
 }
 
+/// Storage types are not required to implement `TypeAbi`, so their names are retrieved via a probe,
+/// which falls back to "unknown".
+fn generate_storage_type_probe(ty: &syn::Type) -> proc_macro2::TokenStream {
+    let mut ty = ty.clone();
+    clear_all_type_lifetimes(&mut ty);
+    quote! {
+        &multiversx_sc::abi::StorageTypeAbiProbe::<#ty>::new()
+    }
+}
+
+fn is_self_api(ty: &syn::Type) -> bool {
+    quote! { #ty }.to_string() == quote! { Self::Api }.to_string()
+}
+
+/// Splits a mapper type, e.g. `SingleValueMapper<Self::Api, BigUint<Self::Api>>`,
+/// into the mapper name and the types of the stored values.
+fn split_mapper_type(ty: &syn::Type) -> (String, Vec<syn::Type>) {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(last_segment) = type_path.path.segments.last() {
+            let mut value_types = Vec::new();
+            if let syn::PathArguments::AngleBracketed(angle_bracketed) = &last_segment.arguments {
+                for gen_arg in &angle_bracketed.args {
+                    if let syn::GenericArgument::Type(gen_ty) = gen_arg {
+                        if !is_self_api(gen_ty) {
+                            value_types.push(gen_ty.clone());
+                        }
+                    }
+                }
+            }
+            return (last_segment.ident.to_string(), value_types);
+        }
+    }
+    (quote! { #ty }.to_string(), Vec::new())
+}
+
+fn generate_storage_snippet(
+    m: &Method,
+    identifier: &str,
+    mapper_name: &str,
+    key_args: &[MethodArgument],
+    value_types: &[syn::Type],
+) -> proc_macro2::TokenStream {
+    let storage_docs = &m.docs;
+    let key_arg_snippets: Vec<proc_macro2::TokenStream> = key_args
+        .iter()
+        .map(|arg| {
+            let arg_name_str = pat_string(&arg.pat);
+            let probe = generate_storage_type_probe(&arg.ty);
+            quote! {
+                storage_abi.add_key_arg(#arg_name_str, (#probe).probe_type_name());
+            }
+        })
+        .collect();
+    let value_type_snippets: Vec<proc_macro2::TokenStream> = value_types
+        .iter()
+        .map(|ty| {
+            let probe = generate_storage_type_probe(ty);
+            quote! {
+                storage_abi.add_value_type((#probe).probe_type_name());
+                (#probe).probe_type_descriptions(&mut contract_abi);
+            }
+        })
+        .collect();
+
+    quote! {
+        let mut storage_abi = multiversx_sc::abi::StorageAbi::new(
+            &[ #(#storage_docs),* ],
+            #identifier,
+            #mapper_name,
+        );
+        #(#key_arg_snippets)*
+        #(#value_type_snippets)*
+        contract_abi.storage.push(storage_abi);
+    }
+}
+
+fn generate_storage_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract
+        .methods
+        .iter()
+        .filter(|m| m.generics.params.is_empty())
+        .filter_map(|m| match (&m.implementation, &m.return_type) {
+            (
+                MethodImpl::Generated(AutoImpl::StorageMapper { identifier }),
+                syn::ReturnType::Type(_, ty),
+            ) => {
+                let (mapper_name, value_types) = split_mapper_type(ty);
+                Some(generate_storage_snippet(
+                    m,
+                    identifier,
+                    &mapper_name,
+                    &m.method_args,
+                    &value_types,
+                ))
+            },
+            (
+                MethodImpl::Generated(AutoImpl::StorageGetter { identifier }),
+                syn::ReturnType::Type(_, ty),
+            ) => Some(generate_storage_snippet(
+                m,
+                identifier,
+                "storage_get",
+                &m.method_args,
+                &[ty.as_ref().clone()],
+            )),
+            (MethodImpl::Generated(AutoImpl::StorageSetter { identifier }), _) => {
+                let (value_arg, key_args) = m.method_args.split_last()?;
+                Some(generate_storage_snippet(
+                    m,
+                    identifier,
+                    "storage_set",
+                    key_args,
+                    &[value_arg.ty.clone()],
+                ))
+            },
+            _ => None,
+        })
+        .collect()
+}
+
 fn has_callback(contract: &ContractTrait) -> bool {
     contract.methods.iter().any(|m| {
         matches!(
@@ -217,6 +345,7 @@ fn generate_abi_method_body(
     let contract_name = &contract.trait_name.to_string();
     let endpoint_snippets = generate_endpoint_snippets(contract);
     let event_snippets = generate_event_snippets(contract);
+    let storage_snippets = generate_storage_snippets(contract);
     let has_callbacks = has_callback(contract);
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         generate_supertrait_snippets(contract)
@@ -245,6 +374,11 @@ fn generate_abi_method_body(
         );
         #(#endpoint_snippets)*
         #(#event_snippets)*
+        {
+            #[allow(unused_imports)]
+            use multiversx_sc::abi::{StorageTypeAbiProbeFallback, StorageTypeAbiProbeSpecified};
+            #(#storage_snippets)*
+        }
         #(#supertrait_snippets)*
         #(#esdt_attributes)*
         contract_abi
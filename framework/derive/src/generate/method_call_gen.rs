@@ -5,6 +5,7 @@ use crate::{
             load_cb_closure_args_snippet,
         },
         method_gen::generate_arg_call_name,
+        non_reentrant_gen::*,
         payable_gen::*,
         restricted_caller_gen::*,
         snippets,
@@ -66,7 +67,10 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
     let payable_snippet = generate_payable_snippet(m);
     let only_owner_snippet = generate_only_owner_snippet(m);
     let only_admin_snippet = generate_only_admin_snippet(m);
+    let only_role_snippet = generate_only_role_snippet(m);
     let only_user_account_snippet = generate_only_user_account_snippet(m);
+    let non_reentrant_enter_snippet = generate_non_reentrant_enter_snippet(m);
+    let non_reentrant_exit_snippet = generate_non_reentrant_exit_snippet(m);
     let arg_load = generate_call_method_arg_load(m);
 
     let call = generate_call_to_method_expr(m);
@@ -77,9 +81,12 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
         #payable_snippet
         #only_owner_snippet
         #only_admin_snippet
+        #only_role_snippet
         #only_user_account_snippet
+        #non_reentrant_enter_snippet
         #arg_load
         #body_with_result
+        #non_reentrant_exit_snippet
     }
 }
 
@@ -11,6 +11,7 @@ pub mod function_selector;
 pub mod method_call_gen;
 mod method_call_gen_arg;
 pub mod method_gen;
+pub mod non_reentrant_gen;
 pub mod payable_gen;
 pub mod proxy_callback_gen;
 pub mod proxy_gen;
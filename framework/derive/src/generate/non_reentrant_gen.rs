@@ -0,0 +1,28 @@
+use crate::model::{Method, PublicRole};
+
+fn is_non_reentrant(m: &Method) -> bool {
+    matches!(&m.public_role, PublicRole::Endpoint(endpoint_metadata) if endpoint_metadata.non_reentrant)
+}
+
+pub fn generate_non_reentrant_enter_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if is_non_reentrant(m) {
+        quote! {
+            multiversx_sc::reentrancy_guard::enter::<Self::Api>();
+        }
+    } else {
+        quote! {}
+    }
+}
+
+/// Only reached if the endpoint does not fail. Failed calls revert the lock together with all other storage changes.
+///
+/// Endpoints ending in a legacy async call never get here either, the lock is released right before the call.
+pub fn generate_non_reentrant_exit_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if is_non_reentrant(m) {
+        quote! {
+            multiversx_sc::reentrancy_guard::exit::<Self::Api>();
+        }
+    } else {
+        quote! {}
+    }
+}
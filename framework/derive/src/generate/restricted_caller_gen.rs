@@ -22,6 +22,17 @@ pub fn generate_only_admin_snippet(m: &Method) -> proc_macro2::TokenStream {
     quote! {}
 }
 
+pub fn generate_only_role_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if let Some(role) = &endpoint_metadata.only_role {
+            return quote! {
+                self.require_caller_has_role(&multiversx_sc::types::ManagedBuffer::<Self::Api>::from(#role));
+            };
+        }
+    }
+    quote! {}
+}
+
 pub fn generate_only_user_account_snippet(m: &Method) -> proc_macro2::TokenStream {
     if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
         if endpoint_metadata.only_user_account {
@@ -19,6 +19,7 @@ mod managed_vec_item_derive;
 mod model;
 mod parse;
 mod preprocessing;
+mod state_machine_derive;
 mod type_abi_derive;
 mod validate;
 
@@ -46,18 +47,24 @@ pub fn proxy(
     macro_proxy::process_proxy(args, input)
 }
 
-#[proc_macro_derive(TypeAbi)]
+#[proc_macro_derive(TypeAbi, attributes(codec))]
 pub fn type_abi_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse(input).unwrap();
     type_abi_derive::type_abi_derive(&ast)
 }
 
-#[proc_macro_derive(ManagedVecItem)]
+#[proc_macro_derive(ManagedVecItem, attributes(codec))]
 pub fn managed_vec_item_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse(input).unwrap();
     managed_vec_item_derive::managed_vec_item_derive(&ast)
 }
 
+#[proc_macro_derive(StateMachine, attributes(state, transition))]
+pub fn state_machine_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+    state_machine_derive::state_machine_derive(&ast)
+}
+
 #[proc_macro]
 pub fn format_receiver_args(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     format::format_receiver_args_macro(input)
@@ -1,3 +1,4 @@
+use super::type_abi_derive::extract_codec_attributes;
 use proc_macro::TokenStream;
 use quote::quote;
 
@@ -92,19 +93,44 @@ fn generate_to_byte_writer_snippets(fields: &syn::Fields) -> Vec<proc_macro2::To
     }
 }
 
-fn generate_array_init_snippet(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+fn self_expr_snippet(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
-    let self_expr = if ast.generics.params.is_empty() {
+    if ast.generics.params.is_empty() {
         quote! { #name }
     } else {
         quote! { #name <multiversx_sc::api::uncallable::UncallableApi> }
-    };
+    }
+}
+
+fn generate_array_init_snippet(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let self_expr = self_expr_snippet(ast);
     quote! {
         const SELF_PAYLOAD_SIZE: usize = <#self_expr as multiversx_sc::types::ManagedVecItem>::PAYLOAD_SIZE;
         let mut arr: [u8; SELF_PAYLOAD_SIZE] = [0u8; SELF_PAYLOAD_SIZE];
     }
 }
 
+/// With `#[codec(max_len)]`, checks at compile time that the nested encoding fits in the payload.
+///
+/// Only relevant for items that skip reserialization, since their payload is used as-is as encoding.
+fn generate_max_len_check_snippet(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    if !extract_codec_attributes(ast.attrs.as_slice()).max_len {
+        return quote! {};
+    }
+
+    let self_expr = self_expr_snippet(ast);
+    quote! {
+        const _: () = assert!(
+            !<#self_expr as multiversx_sc::types::ManagedVecItem>::SKIPS_RESERIALIZATION
+                || matches!(
+                    <#self_expr as multiversx_sc::codec::NestedEncodeMaxLen>::MAX_NESTED_LEN,
+                    Some(max_len) if max_len <= <#self_expr as multiversx_sc::types::ManagedVecItem>::PAYLOAD_SIZE
+                ),
+            "nested encoding exceeds the ManagedVecItem payload size"
+        );
+    }
+}
+
 fn enum_derive(data_enum: &syn::DataEnum, ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
@@ -124,6 +150,7 @@ fn enum_derive(data_enum: &syn::DataEnum, ast: &syn::DeriveInput) -> TokenStream
     }
 
     let first_variant_ident = &data_enum.variants[0];
+    let max_len_check_snippet = generate_max_len_check_snippet(ast);
     reader_match_arms.push(quote! {
         _ => #name::#first_variant_ident,
     });
@@ -154,6 +181,8 @@ fn enum_derive(data_enum: &syn::DataEnum, ast: &syn::DeriveInput) -> TokenStream
                 writer(&arr[..])
             }
         }
+
+        #max_len_check_snippet
     };
     gen.into()
 }
@@ -168,6 +197,7 @@ fn struct_derive(data_struct: &syn::DataStruct, ast: &syn::DeriveInput) -> Token
     let to_byte_writer_snippets = generate_to_byte_writer_snippets(&data_struct.fields);
 
     let array_init_snippet = generate_array_init_snippet(ast);
+    let max_len_check_snippet = generate_max_len_check_snippet(ast);
 
     let gen = quote! {
         impl #impl_generics multiversx_sc::types::ManagedVecItem for #name #ty_generics #where_clause {
@@ -198,6 +228,8 @@ fn struct_derive(data_struct: &syn::DataStruct, ast: &syn::DeriveInput) -> Token
                 writer(&arr[..])
             }
         }
+
+        #max_len_check_snippet
     };
     gen.into()
 }
@@ -12,7 +12,9 @@ pub struct EndpointMetadata {
     pub payable: MethodPayableMetadata,
     pub only_owner: bool,
     pub only_admin: bool,
+    pub only_role: Option<String>,
     pub only_user_account: bool,
+    pub non_reentrant: bool,
     pub mutability: EndpointMutabilityMetadata,
     pub allow_multiple_var_args: bool,
 }
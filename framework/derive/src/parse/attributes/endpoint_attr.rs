@@ -12,10 +12,19 @@ pub fn is_only_admin(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_ADMIN)
 }
 
+/// Role name from `#[only_role("...")]`.
+pub fn only_role_name(attr: &syn::Attribute) -> Option<String> {
+    is_attr_one_string_arg(attr, ATTR_ONLY_ROLE)
+}
+
 pub fn is_only_user_account(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_USER_ACCOUNT)
 }
 
+pub fn is_non_reentrant(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_NON_REENTRANT)
+}
+
 pub fn is_callback_raw(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_CALLBACK_RAW_DECL)
 }
@@ -5,10 +5,10 @@ use crate::model::{
 
 use super::{
     attributes::{
-        is_allow_multiple_var_args, is_callback_raw, is_init, is_only_admin, is_only_owner,
-        is_only_user_account, is_upgrade, CallbackAttribute, EndpointAttribute,
-        ExternalViewAttribute, LabelAttribute, OutputNameAttribute, PromisesCallbackAttribute,
-        ViewAttribute,
+        is_allow_multiple_var_args, is_callback_raw, is_init, is_non_reentrant, is_only_admin,
+        is_only_owner, is_only_user_account, is_upgrade, only_role_name, CallbackAttribute,
+        EndpointAttribute, ExternalViewAttribute, LabelAttribute, OutputNameAttribute,
+        PromisesCallbackAttribute, ViewAttribute,
     },
     MethodAttributesPass1,
 };
@@ -49,7 +49,9 @@ pub fn process_upgrade_attribute(
             payable: first_pass_data.payable.clone(),
             only_owner: false,
             only_admin: false,
+            only_role: None,
             only_user_account: false,
+            non_reentrant: false,
             mutability: EndpointMutabilityMetadata::Mutable,
             allow_multiple_var_args: first_pass_data.allow_multiple_var_args,
         });
@@ -93,6 +95,22 @@ pub fn process_only_admin_attribute(
     is_only_admin
 }
 
+pub fn process_only_role_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    if let Some(role) = only_role_name(attr) {
+        assert!(
+            pass_1_data.only_role.is_none(),
+            "only one `#[only_role]` attribute allowed per endpoint"
+        );
+        pass_1_data.only_role = Some(role);
+        true
+    } else {
+        false
+    }
+}
+
 pub fn process_only_user_account_attribute(
     attr: &syn::Attribute,
     pass_1_data: &mut MethodAttributesPass1,
@@ -104,6 +122,17 @@ pub fn process_only_user_account_attribute(
     is_only_user_account
 }
 
+pub fn process_non_reentrant_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    let is_non_reentrant = is_non_reentrant(attr);
+    if is_non_reentrant {
+        pass_1_data.non_reentrant = true;
+    }
+    is_non_reentrant
+}
+
 pub fn process_endpoint_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -121,7 +150,9 @@ pub fn process_endpoint_attribute(
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
+                only_role: pass_1_data.only_role.clone(),
                 only_user_account: pass_1_data.only_user_account,
+                non_reentrant: pass_1_data.non_reentrant,
                 mutability: EndpointMutabilityMetadata::Mutable,
                 allow_multiple_var_args: pass_1_data.allow_multiple_var_args,
             });
@@ -146,7 +177,9 @@ pub fn process_view_attribute(
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
+                only_role: pass_1_data.only_role.clone(),
                 only_user_account: pass_1_data.only_user_account,
+                non_reentrant: false,
                 mutability: EndpointMutabilityMetadata::Readonly,
                 allow_multiple_var_args: pass_1_data.allow_multiple_var_args,
             });
@@ -171,7 +204,9 @@ pub fn process_external_view_attribute(
                 payable: pass_1_data.payable.clone(),
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
+                only_role: pass_1_data.only_role.clone(),
                 only_user_account: pass_1_data.only_user_account,
+                non_reentrant: false,
                 mutability: EndpointMutabilityMetadata::Readonly,
                 allow_multiple_var_args: pass_1_data.allow_multiple_var_args,
             });
@@ -9,17 +9,19 @@ use super::{
     },
     extract_method_args, process_allow_multiple_var_args_attribute, process_callback_attribute,
     process_callback_raw_attribute, process_endpoint_attribute, process_external_view_attribute,
-    process_init_attribute, process_label_names_attribute, process_only_admin_attribute,
-    process_only_owner_attribute, process_only_user_account_attribute,
-    process_output_names_attribute, process_payable_attribute, process_promises_callback_attribute,
-    process_upgrade_attribute, process_view_attribute,
+    process_init_attribute, process_label_names_attribute, process_non_reentrant_attribute,
+    process_only_admin_attribute, process_only_owner_attribute, process_only_role_attribute,
+    process_only_user_account_attribute, process_output_names_attribute, process_payable_attribute,
+    process_promises_callback_attribute, process_upgrade_attribute, process_view_attribute,
 };
 pub struct MethodAttributesPass1 {
     pub method_name: String,
     pub payable: MethodPayableMetadata,
     pub only_owner: bool,
     pub only_admin: bool,
+    pub only_role: Option<String>,
     pub only_user_account: bool,
+    pub non_reentrant: bool,
     pub allow_multiple_var_args: bool,
 }
 
@@ -37,7 +39,9 @@ pub fn process_method(m: &syn::TraitItemFn, trait_attributes: &TraitProperties)
         payable: MethodPayableMetadata::NotPayable,
         only_owner: trait_attributes.only_owner,
         only_admin: trait_attributes.only_admin,
+        only_role: None,
         only_user_account: trait_attributes.only_user_account,
+        non_reentrant: false,
         allow_multiple_var_args: trait_attributes.allow_multiple_var_args,
     };
     let mut first_pass_unprocessed_attributes = Vec::new();
@@ -68,6 +72,7 @@ pub fn process_method(m: &syn::TraitItemFn, trait_attributes: &TraitProperties)
     );
 
     validate_method(&method);
+    validate_non_reentrant(&method, &first_pass_data);
 
     method
 }
@@ -92,7 +97,9 @@ fn process_attribute_first_pass(
     process_payable_attribute(attr, first_pass_data)
         || process_only_owner_attribute(attr, first_pass_data)
         || process_only_admin_attribute(attr, first_pass_data)
+        || process_only_role_attribute(attr, first_pass_data)
         || process_only_user_account_attribute(attr, first_pass_data)
+        || process_non_reentrant_attribute(attr, first_pass_data)
         || process_allow_multiple_var_args_attribute(attr, first_pass_data)
 }
 
@@ -143,3 +150,16 @@ fn validate_method(method: &Method) {
         &method.name.to_string()
     )
 }
+
+/// Views cannot write the lock, and the other kinds of methods would silently ignore it.
+fn validate_non_reentrant(method: &Method, first_pass_data: &MethodAttributesPass1) {
+    assert!(
+        !first_pass_data.non_reentrant
+            || matches!(
+                &method.public_role,
+                PublicRole::Endpoint(endpoint_metadata) if endpoint_metadata.non_reentrant
+            ),
+        "`#[non_reentrant]` only allowed on endpoints, not on views, init, upgrade or callbacks (method: `{}`)",
+        method.name
+    )
+}
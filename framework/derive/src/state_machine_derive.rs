@@ -0,0 +1,166 @@
+use proc_macro::TokenStream;
+use quote::quote;
+
+pub struct StateTransitionAttribute {
+    pub to: syn::Ident,
+    pub guard: Option<String>,
+}
+
+/// The `#[state(...)]` and `#[transition(...)]` attributes of an enum variant.
+#[derive(Default)]
+pub struct StateMachineVariantAttributes {
+    pub initial: bool,
+    pub transitions: Vec<StateTransitionAttribute>,
+}
+
+impl StateMachineVariantAttributes {
+    pub fn is_declared(&self) -> bool {
+        self.initial || !self.transitions.is_empty()
+    }
+}
+
+pub fn extract_state_machine_attributes(attrs: &[syn::Attribute]) -> StateMachineVariantAttributes {
+    let mut result = StateMachineVariantAttributes::default();
+    for attr in attrs {
+        if attr.path().is_ident("state") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("initial") {
+                    result.initial = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown state option"))
+                }
+            })
+            .unwrap_or_else(|err| panic!("invalid state attribute: {err}"));
+        } else if attr.path().is_ident("transition") {
+            let mut to = None;
+            let mut guard = None;
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("to") {
+                    to = Some(meta.value()?.parse::<syn::Ident>()?);
+                    Ok(())
+                } else if meta.path.is_ident("guard") {
+                    guard = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown transition option"))
+                }
+            })
+            .unwrap_or_else(|err| panic!("invalid transition attribute: {err}"));
+            result.transitions.push(StateTransitionAttribute {
+                to: to.expect("transition target missing, expected `to = <Variant>`"),
+                guard,
+            });
+        }
+    }
+    result
+}
+
+/// The variant marked `#[state(initial)]`, or the first one.
+pub fn initial_state_index(variants_attributes: &[StateMachineVariantAttributes]) -> usize {
+    let mut initial_indexes = variants_attributes
+        .iter()
+        .enumerate()
+        .filter(|(_, attributes)| attributes.initial)
+        .map(|(index, _)| index);
+    let initial_index = initial_indexes.next().unwrap_or_default();
+    assert!(
+        initial_indexes.next().is_none(),
+        "only one variant can be marked as the initial state"
+    );
+    initial_index
+}
+
+fn variant_pattern(name: &syn::Ident, variant: &syn::Variant) -> proc_macro2::TokenStream {
+    let variant_ident = &variant.ident;
+    match &variant.fields {
+        syn::Fields::Named(_) => quote! { #name::#variant_ident { .. } },
+        syn::Fields::Unnamed(_) => quote! { #name::#variant_ident(..) },
+        syn::Fields::Unit => quote! { #name::#variant_ident },
+    }
+}
+
+pub fn state_machine_derive(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let syn::Data::Enum(data_enum) = &ast.data else {
+        panic!("only enums can be state machines");
+    };
+    assert!(
+        !data_enum.variants.is_empty(),
+        "a state machine needs at least one state"
+    );
+
+    let variants_attributes: Vec<StateMachineVariantAttributes> = data_enum
+        .variants
+        .iter()
+        .map(|variant| extract_state_machine_attributes(variant.attrs.as_slice()))
+        .collect();
+
+    let initial_variant = &data_enum.variants[initial_state_index(&variants_attributes)];
+    assert!(
+        matches!(initial_variant.fields, syn::Fields::Unit),
+        "the initial state cannot have fields"
+    );
+    let initial_ident = &initial_variant.ident;
+
+    let name_arms = data_enum.variants.iter().map(|variant| {
+        let pattern = variant_pattern(name, variant);
+        let variant_name_str = variant.ident.to_string();
+        quote! {
+            #pattern => #variant_name_str,
+        }
+    });
+
+    let transition_arms = data_enum
+        .variants
+        .iter()
+        .zip(variants_attributes.iter())
+        .flat_map(|(variant, attributes)| {
+            let from_pattern = variant_pattern(name, variant);
+            attributes.transitions.iter().map(move |transition| {
+                let to_variant = data_enum
+                    .variants
+                    .iter()
+                    .find(|variant| variant.ident == transition.to)
+                    .unwrap_or_else(|| {
+                        panic!("unknown transition target state: {}", transition.to)
+                    });
+                let to_pattern = variant_pattern(name, to_variant);
+                let check = match &transition.guard {
+                    Some(guard) => quote! {
+                        multiversx_sc::state_machine::StateTransitionCheck::Guarded(#guard)
+                    },
+                    None => quote! {
+                        multiversx_sc::state_machine::StateTransitionCheck::Allowed
+                    },
+                };
+                quote! {
+                    (#from_pattern, #to_pattern) => #check,
+                }
+            })
+        });
+
+    let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_generics multiversx_sc::state_machine::StateMachine for #name #ty_generics #where_clause {
+            fn initial_state() -> Self {
+                #name::#initial_ident
+            }
+
+            fn state_name(&self) -> &'static str {
+                match self {
+                    #(#name_arms)*
+                }
+            }
+
+            #[allow(unreachable_patterns)]
+            fn check_transition(&self, next: &Self) -> multiversx_sc::state_machine::StateTransitionCheck {
+                match (self, next) {
+                    #(#transition_arms)*
+                    _ => multiversx_sc::state_machine::StateTransitionCheck::NotAllowed,
+                }
+            }
+        }
+    };
+    gen.into()
+}
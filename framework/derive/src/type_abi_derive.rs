@@ -1,7 +1,49 @@
-use super::parse::attributes::extract_doc;
+use super::{
+    parse::attributes::extract_doc,
+    state_machine_derive::{
+        extract_state_machine_attributes, initial_state_index, StateMachineVariantAttributes,
+    },
+};
 use proc_macro::TokenStream;
 use quote::quote;
 
+/// The `#[codec(...)]` options that are reflected in the ABI.
+///
+/// They are validated by the codec derive, the others (e.g. `default`) are ignored here.
+#[derive(Default)]
+pub struct CodecAbiAttributes {
+    pub skip: bool,
+    pub since: Option<u32>,
+    pub discriminant: Option<usize>,
+
+    /// Type-level `#[codec(max_len)]`: the type implements `NestedEncodeMaxLen`.
+    pub max_len: bool,
+}
+
+pub fn extract_codec_attributes(attrs: &[syn::Attribute]) -> CodecAbiAttributes {
+    let mut result = CodecAbiAttributes::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("codec")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                result.skip = true;
+            } else if meta.path.is_ident("since") {
+                let version: syn::LitInt = meta.value()?.parse()?;
+                result.since = Some(version.base10_parse()?);
+            } else if meta.path.is_ident("max_len") {
+                result.max_len = true;
+            } else if meta.path.is_ident("discriminant") {
+                let discriminant: syn::LitInt = meta.value()?.parse()?;
+                result.discriminant = Some(discriminant.base10_parse()?);
+            } else if meta.input.peek(syn::Token![=]) {
+                let _: syn::Expr = meta.value()?.parse()?;
+            }
+            Ok(())
+        })
+        .unwrap_or_else(|err| panic!("invalid codec attribute: {err}"));
+    }
+    result
+}
+
 fn field_snippet(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
     let field_docs = extract_doc(field.attrs.as_slice());
     let field_name_str = if let Some(ident) = &field.ident {
@@ -10,12 +52,20 @@ fn field_snippet(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
         index.to_string()
     };
     let field_ty = &field.ty;
+    let codec_attributes = extract_codec_attributes(field.attrs.as_slice());
+    if codec_attributes.skip {
+        // not part of the encoding
+        return quote! {};
+    }
+    let since_snippet = codec_attributes.since.map(|since| {
+        quote! { .since(#since) }
+    });
     quote! {
         field_descriptions.push(multiversx_sc::abi::StructFieldDescription::new(
             &[ #(#field_docs),* ],
             #field_name_str,
             <#field_ty>::type_name(),
-        ));
+        )#since_snippet);
         <#field_ty>::provide_type_descriptions(accumulator);
     }
 }
@@ -38,8 +88,54 @@ fn fields_snippets(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
     }
 }
 
+/// State machine declarations, if any, are also part of the ABI.
+fn state_machine_snippet(data_enum: &syn::DataEnum) -> Option<proc_macro2::TokenStream> {
+    let state_machine_attributes: Vec<StateMachineVariantAttributes> = data_enum
+        .variants
+        .iter()
+        .map(|variant| extract_state_machine_attributes(variant.attrs.as_slice()))
+        .collect();
+    if !state_machine_attributes
+        .iter()
+        .any(StateMachineVariantAttributes::is_declared)
+    {
+        return None;
+    }
+
+    let initial_state_str = data_enum.variants[initial_state_index(&state_machine_attributes)]
+        .ident
+        .to_string();
+    let transition_snippets = data_enum
+        .variants
+        .iter()
+        .zip(state_machine_attributes.iter())
+        .flat_map(|(variant, attributes)| {
+            let from_str = variant.ident.to_string();
+            attributes.transitions.iter().map(move |transition| {
+                let to_str = transition.to.to_string();
+                let guard_snippet = match &transition.guard {
+                    Some(guard) => quote! { Some(#guard) },
+                    None => quote! { None },
+                };
+                quote! { .transition(#from_str, #to_str, #guard_snippet) }
+            })
+        });
+    Some(quote! {
+        .state_machine(
+            multiversx_sc::abi::StateMachineDescription::new(#initial_state_str)
+                #(#transition_snippets)*
+        )
+    })
+}
+
 pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
     let type_docs = extract_doc(ast.attrs.as_slice());
+    let codec_attributes = extract_codec_attributes(ast.attrs.as_slice());
+    let max_nested_len_snippet = codec_attributes.max_len.then(|| {
+        quote! {
+            .max_nested_len(<Self as multiversx_sc::codec::NestedEncodeMaxLen>::MAX_NESTED_LEN)
+        }
+    });
     let type_description_impl = match &ast.data {
         syn::Data::Struct(data_struct) => {
             let struct_field_snippets = fields_snippets(&data_struct.fields);
@@ -56,18 +152,25 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                                 &[ #(#type_docs),* ],
                                 type_name,
                                 multiversx_sc::abi::TypeContents::Struct(field_descriptions),
-                            ),
+                            )#max_nested_len_snippet,
                         );
                     }
                 }
             }
         },
         syn::Data::Enum(data_enum) => {
+            let mut previous_discriminant: Option<usize> = None;
             let enum_variant_snippets: Vec<proc_macro2::TokenStream> = data_enum
                 .variants
                 .iter()
-                .enumerate()
-                .map(|(variant_index, variant)| {
+                .map(|variant| {
+                    // same numbering as the codec derive
+                    let discriminant = extract_codec_attributes(variant.attrs.as_slice())
+                        .discriminant
+                        .unwrap_or_else(|| {
+                            previous_discriminant.map_or(0, |previous| previous + 1)
+                        });
+                    previous_discriminant = Some(discriminant);
                     let variant_docs = extract_doc(variant.attrs.as_slice());
                     let variant_name_str = variant.ident.to_string();
                     let variant_field_snippets = fields_snippets(&variant.fields);
@@ -77,12 +180,13 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                         variant_descriptions.push(multiversx_sc::abi::EnumVariantDescription::new(
                             &[ #(#variant_docs),* ],
                             #variant_name_str,
-                            #variant_index,
+                            #discriminant,
                             field_descriptions,
                         ));
                     }
                 })
                 .collect();
+            let state_machine_snippet = state_machine_snippet(data_enum);
             quote! {
                 fn provide_type_descriptions<TDC: multiversx_sc::abi::TypeDescriptionContainer>(accumulator: &mut TDC) {
                     let type_name = Self::type_name();
@@ -96,7 +200,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                                 &[ #(#type_docs),* ],
                                 type_name,
                                 multiversx_sc::abi::TypeContents::Enum(variant_descriptions),
-                            ),
+                            )#max_nested_len_snippet #state_machine_snippet,
                         );
                     }
                 }
@@ -1,11 +1,16 @@
+mod abi_json_load;
+mod abi_type_name;
 mod build_info_abi_json;
 mod contract_abi_json;
 mod endpoint_abi_json;
 mod esdt_attribute_abi_json;
 mod esdt_attribute_json;
 mod event_abi_json;
+mod storage_abi_json;
 mod type_abi_json;
 
+pub use abi_json_load::load_abi_json_from_file;
+pub use abi_type_name::AbiTypeName;
 pub use build_info_abi_json::{BuildInfoAbiJson, RustcAbiJson};
 pub use contract_abi_json::*;
 pub use endpoint_abi_json::*;
@@ -13,6 +18,7 @@ pub use esdt_attribute_abi_json::EsdtAttributeAbiJson;
 pub use esdt_attribute_json::EsdtAttributeJson;
 pub use event_abi_json::*;
 use multiversx_sc::{abi::ContractAbi, contract_base::ContractAbiProvider};
+pub use storage_abi_json::*;
 pub use type_abi_json::*;
 
 /// Function provided for convenience.
@@ -0,0 +1,20 @@
+use std::{fs, path::Path};
+
+use super::{deserialize_abi_from_json, ContractAbiJson};
+use crate::mxsc_file_json::MxscFileJson;
+
+/// Loads a contract ABI either from an `.abi.json` file, or from the ABI packed in a `.mxsc.json` file.
+pub fn load_abi_json_from_file(path: impl AsRef<Path>) -> ContractAbiJson {
+    let path = path.as_ref();
+    let contents = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+
+    if path.to_string_lossy().ends_with(".mxsc.json") {
+        let mxsc_file_json: MxscFileJson = serde_json::from_str(&contents)
+            .unwrap_or_else(|err| panic!("failed to parse {}: {err}", path.display()));
+        return mxsc_file_json.abi;
+    }
+
+    deserialize_abi_from_json(&contents)
+        .unwrap_or_else(|err| panic!("failed to parse {}: {err}", path.display()))
+}
@@ -0,0 +1,136 @@
+use std::fmt::Display;
+
+/// Structured representation of an ABI type name, such as `variadic<multi<Address,BigUint>>`.
+///
+/// The ABI only contains type names as strings, tools that need to reason about them
+/// (code generators, checkers, decoders) should parse them into this form first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbiTypeName {
+    /// The outer type name, without type parameters, e.g. `variadic`, `array32`, `BigUint`.
+    pub name: String,
+
+    /// Type parameters, in order. Empty for simple types.
+    pub type_params: Vec<AbiTypeName>,
+}
+
+impl AbiTypeName {
+    pub fn new_simple(name: &str) -> Self {
+        AbiTypeName {
+            name: name.to_string(),
+            type_params: Vec::new(),
+        }
+    }
+
+    /// Parses a type name, as it appears in the ABI JSON.
+    ///
+    /// The parser is lenient: whitespace around names is ignored and unbalanced brackets are tolerated.
+    ///
+    /// Whitespace inside names is kept, since some of them contain it (`utf-8 string`).
+    pub fn parse(type_name: &str) -> Self {
+        let chars: Vec<char> = type_name.chars().collect();
+        let mut index = 0;
+        parse_from(&chars, &mut index)
+    }
+
+    /// The first type parameter, if any.
+    pub fn first_param(&self) -> Option<&AbiTypeName> {
+        self.type_params.first()
+    }
+
+    /// For names of the form `array32`, yields the array length.
+    pub fn array_len(&self) -> Option<usize> {
+        self.name
+            .strip_prefix("array")
+            .and_then(|len_str| len_str.parse().ok())
+    }
+}
+
+fn parse_from(chars: &[char], index: &mut usize) -> AbiTypeName {
+    let mut name = String::new();
+    while *index < chars.len() {
+        let c = chars[*index];
+        if c == '<' || c == '>' || c == ',' {
+            break;
+        }
+        name.push(c);
+        *index += 1;
+    }
+
+    let mut type_params = Vec::new();
+    if *index < chars.len() && chars[*index] == '<' {
+        *index += 1;
+        loop {
+            type_params.push(parse_from(chars, index));
+            if *index >= chars.len() {
+                break;
+            }
+            let c = chars[*index];
+            *index += 1;
+            if c == '>' {
+                break;
+            }
+        }
+    }
+
+    AbiTypeName {
+        name: name.trim().to_string(),
+        type_params,
+    }
+}
+
+impl Display for AbiTypeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)?;
+        if !self.type_params.is_empty() {
+            f.write_str("<")?;
+            for (i, param) in self.type_params.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(",")?;
+                }
+                param.fmt(f)?;
+            }
+            f.write_str(">")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AbiTypeName;
+
+    #[test]
+    fn parse_simple() {
+        let parsed = AbiTypeName::parse("BigUint");
+        assert_eq!(parsed, AbiTypeName::new_simple("BigUint"));
+        assert_eq!(AbiTypeName::parse("()"), AbiTypeName::new_simple("()"));
+    }
+
+    #[test]
+    fn parse_nested() {
+        let type_name = "variadic<multi<OnlyShowsUpAsNested05,bool,()>>";
+        let parsed = AbiTypeName::parse(type_name);
+        assert_eq!(parsed.name, "variadic");
+        let multi = parsed.first_param().unwrap();
+        assert_eq!(multi.name, "multi");
+        assert_eq!(multi.type_params.len(), 3);
+        assert_eq!(multi.type_params[2].name, "()");
+        assert_eq!(parsed.to_string(), type_name);
+    }
+
+    #[test]
+    fn parse_array() {
+        let parsed = AbiTypeName::parse("array5<OnlyShowsUpAsNestedInArray>");
+        assert_eq!(parsed.array_len(), Some(5));
+        assert_eq!(AbiTypeName::parse("Option<u8>").array_len(), None);
+    }
+
+    #[test]
+    fn parse_whitespace() {
+        let parsed = AbiTypeName::parse("multi<Address, BigUint>");
+        assert_eq!(parsed.to_string(), "multi<Address,BigUint>");
+
+        let parsed = AbiTypeName::parse("List< utf-8 string >");
+        assert_eq!(parsed.first_param().unwrap().name, "utf-8 string");
+    }
+}
@@ -0,0 +1,34 @@
+use super::*;
+use multiversx_sc::abi::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageAbiJson {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+
+    pub key: String,
+
+    pub mapper: String,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub key_args: Vec<InputAbiJson>,
+
+    #[serde(default)]
+    pub value_types: Vec<String>,
+}
+
+impl From<&StorageAbi> for StorageAbiJson {
+    fn from(abi: &StorageAbi) -> Self {
+        StorageAbiJson {
+            docs: abi.docs.iter().map(|d| d.to_string()).collect(),
+            key: abi.key.to_string(),
+            mapper: abi.mapper.to_string(),
+            key_args: abi.key_args.iter().map(InputAbiJson::from).collect(),
+            value_types: abi.value_types.clone(),
+        }
+    }
+}
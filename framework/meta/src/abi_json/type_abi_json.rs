@@ -22,6 +22,16 @@ pub struct TypeDescriptionJson {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
+
+    #[serde(rename = "maxNestedLen")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_nested_len: Option<usize>,
+
+    #[serde(rename = "stateMachine")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_machine: Option<StateMachineDescriptionJson>,
 }
 
 impl From<&TypeDescription> for TypeDescriptionJson {
@@ -37,6 +47,11 @@ impl From<&TypeDescription> for TypeDescriptionJson {
             docs: abi.docs.iter().map(|line| line.to_string()).collect(),
             variants: Vec::new(),
             fields: Vec::new(),
+            max_nested_len: abi.max_nested_len,
+            state_machine: abi
+                .state_machine
+                .as_ref()
+                .map(StateMachineDescriptionJson::from),
         };
         match &abi.contents {
             TypeContents::Struct(fields) => {
@@ -87,6 +102,11 @@ impl TypeDescriptionJson {
                 ),
                 _ => TypeContents::NotSpecified,
             },
+            max_nested_len: self.max_nested_len,
+            state_machine: self
+                .state_machine
+                .as_ref()
+                .map(StateMachineDescriptionJson::to_state_machine_description),
         }
     }
 }
@@ -101,6 +121,10 @@ pub struct StructFieldDescriptionJson {
 
     #[serde(rename = "type")]
     pub field_type: String,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<u32>,
 }
 
 impl From<&StructFieldDescription> for StructFieldDescriptionJson {
@@ -109,6 +133,7 @@ impl From<&StructFieldDescription> for StructFieldDescriptionJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             field_type: abi.field_type.clone(),
+            since: abi.since,
         }
     }
 }
@@ -119,6 +144,7 @@ impl StructFieldDescriptionJson {
             docs: self.docs.clone(),
             name: self.name.clone(),
             field_type: self.field_type.clone(),
+            since: self.since,
         }
     }
 }
@@ -180,3 +206,70 @@ impl EnumVariantDescriptionJson {
         }
     }
 }
+
+#[derive(Serialize, Deserialize)]
+pub struct StateMachineDescriptionJson {
+    #[serde(rename = "initialState")]
+    pub initial_state: String,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub transitions: Vec<StateTransitionDescriptionJson>,
+}
+
+impl From<&StateMachineDescription> for StateMachineDescriptionJson {
+    fn from(abi: &StateMachineDescription) -> Self {
+        StateMachineDescriptionJson {
+            initial_state: abi.initial_state.clone(),
+            transitions: abi
+                .transitions
+                .iter()
+                .map(StateTransitionDescriptionJson::from)
+                .collect(),
+        }
+    }
+}
+
+impl StateMachineDescriptionJson {
+    pub fn to_state_machine_description(&self) -> StateMachineDescription {
+        StateMachineDescription {
+            initial_state: self.initial_state.clone(),
+            transitions: self
+                .transitions
+                .iter()
+                .map(StateTransitionDescriptionJson::to_state_transition_description)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StateTransitionDescriptionJson {
+    pub from: String,
+
+    pub to: String,
+
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guard: Option<String>,
+}
+
+impl From<&StateTransitionDescription> for StateTransitionDescriptionJson {
+    fn from(abi: &StateTransitionDescription) -> Self {
+        StateTransitionDescriptionJson {
+            from: abi.from.clone(),
+            to: abi.to.clone(),
+            guard: abi.guard.clone(),
+        }
+    }
+}
+
+impl StateTransitionDescriptionJson {
+    pub fn to_state_transition_description(&self) -> StateTransitionDescription {
+        StateTransitionDescription {
+            from: self.from.clone(),
+            to: self.to.clone(),
+            guard: self.guard.clone(),
+        }
+    }
+}
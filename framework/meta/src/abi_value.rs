@@ -0,0 +1,12 @@
+mod abi_value_decoder;
+mod abi_value_encoder;
+mod abi_value_tree;
+mod abi_value_util;
+
+pub use abi_value_decoder::AbiValueDecoder;
+pub use abi_value_encoder::AbiValueEncoder;
+pub use abi_value_tree::AbiValue;
+pub use abi_value_util::{
+    address_bech32, buffer_text, check_top_int_len, fixed_int_len, hex_repr, required_first_param,
+    take, take_len, take_length_prefixed,
+};
@@ -0,0 +1,449 @@
+use std::collections::BTreeMap;
+
+use multiversx_sc::codec::num_bigint::{BigInt, BigUint};
+
+use crate::abi_json::{
+    AbiTypeName, EventAbiJson, StructFieldDescriptionJson, TypeDescriptionJson,
+    TYPE_DESCRIPTION_JSON_TYPE_ENUM, TYPE_DESCRIPTION_JSON_TYPE_STRUCT,
+};
+
+use super::{
+    address_bech32, buffer_text, check_top_int_len, fixed_int_len, hex_repr, required_first_param,
+    take, take_len, take_length_prefixed, AbiValue,
+};
+
+/// Decodes encoded values into JSON, based only on the type names and descriptions in the ABI.
+///
+/// Structs become JSON objects, with their fields in declaration order,
+/// fieldless enum variants become strings,
+/// enum variants with fields become `{ "<variant>": { <fields> } }`,
+/// big numbers become decimal strings and raw bytes become `0x`-prefixed hex strings.
+pub struct AbiValueDecoder<'a> {
+    types: &'a BTreeMap<String, TypeDescriptionJson>,
+}
+
+impl<'a> AbiValueDecoder<'a> {
+    pub fn new(types: &'a BTreeMap<String, TypeDescriptionJson>) -> Self {
+        AbiValueDecoder { types }
+    }
+
+    /// Decodes a top-encoded value, e.g. a storage value or an endpoint argument.
+    pub fn decode_top(&self, type_name: &str, bytes: &[u8]) -> Result<AbiValue, String> {
+        self.decode_top_parsed(&AbiTypeName::parse(type_name), bytes)
+    }
+
+    /// Decodes a nested-encoded value from the beginning of the input and advances it.
+    pub fn decode_nested(&self, type_name: &str, input: &mut &[u8]) -> Result<AbiValue, String> {
+        self.decode_nested_parsed(&AbiTypeName::parse(type_name), input)
+    }
+
+    /// Decodes a sequence of named fields, e.g. a struct or a fixed-layout storage record.
+    pub fn decode_nested_fields<'n>(
+        &self,
+        fields: impl IntoIterator<Item = (&'n str, &'n str)>,
+        input: &mut &[u8],
+    ) -> Result<AbiValue, String> {
+        let mut result = Vec::new();
+        for (field_name, field_type) in fields {
+            let field_value = self.decode_nested(field_type, input)?;
+            result.push((field_name.to_string(), field_value));
+        }
+        Ok(AbiValue::Object(result))
+    }
+
+    /// Decodes a list of raw arguments, e.g. endpoint arguments or results, one value per type.
+    ///
+    /// Multi-value types (`variadic`, `counted-variadic`, `optional`, `multi`) can consume
+    /// any number of arguments, the values they produce are JSON arrays, or `null` for a missing optional.
+    pub fn decode_multi<'n>(
+        &self,
+        type_names: impl IntoIterator<Item = &'n str>,
+        args: &[&[u8]],
+    ) -> Result<Vec<AbiValue>, String> {
+        let mut input = args;
+        let mut result = Vec::new();
+        for type_name in type_names {
+            result.push(self.decode_multi_parsed(&AbiTypeName::parse(type_name), &mut input)?);
+        }
+        if !input.is_empty() {
+            return Err(format!("{} leftover arguments", input.len()));
+        }
+        Ok(result)
+    }
+
+    /// Decodes an event log, given all its topics, including the identifier, and its data.
+    ///
+    /// Indexed inputs are read from the topics, the non-indexed one (if any) from the data.
+    pub fn decode_event(
+        &self,
+        event: &EventAbiJson,
+        topics: &[&[u8]],
+        data: &[u8],
+    ) -> Result<AbiValue, String> {
+        let Some((identifier, mut topics)) = topics.split_first() else {
+            return Err("missing event identifier".to_string());
+        };
+        if *identifier != event.identifier.as_bytes() {
+            return Err(format!(
+                "event identifier mismatch, expected {}, got 0x{}",
+                event.identifier,
+                hex::encode(identifier)
+            ));
+        }
+
+        let mut result = Vec::new();
+        for input in &event.inputs {
+            let input_type = AbiTypeName::parse(&input.type_name);
+            let input_value = if input.indexed.unwrap_or_default() {
+                self.decode_multi_parsed(&input_type, &mut topics)?
+            } else {
+                self.decode_top_parsed(&input_type, data)?
+            };
+            result.push((input.arg_name.clone(), input_value));
+        }
+        if !topics.is_empty() {
+            return Err(format!("{} leftover event topics", topics.len()));
+        }
+        Ok(AbiValue::Object(result))
+    }
+
+    fn decode_multi_parsed(
+        &self,
+        abi_type: &AbiTypeName,
+        args: &mut &[&[u8]],
+    ) -> Result<AbiValue, String> {
+        match abi_type.name.as_str() {
+            "variadic" => {
+                let item_type = required_first_param(abi_type)?;
+                let mut items = Vec::new();
+                while !args.is_empty() {
+                    items.push(self.decode_multi_parsed(item_type, args)?);
+                }
+                Ok(AbiValue::Array(items))
+            },
+            "counted-variadic" => {
+                let item_type = required_first_param(abi_type)?;
+                let count_arg = take_arg(abi_type, args)?;
+                check_top_int_len(abi_type, count_arg, 4)?;
+                let mut items = Vec::new();
+                for _ in 0..decode_unsigned(count_arg) {
+                    items.push(self.decode_multi_parsed(item_type, args)?);
+                }
+                Ok(AbiValue::Array(items))
+            },
+            "optional" => {
+                if args.is_empty() {
+                    Ok(AbiValue::Null)
+                } else {
+                    self.decode_multi_parsed(required_first_param(abi_type)?, args)
+                }
+            },
+            "multi" => {
+                let mut items = Vec::new();
+                for item_type in &abi_type.type_params {
+                    items.push(self.decode_multi_parsed(item_type, args)?);
+                }
+                Ok(AbiValue::Array(items))
+            },
+            "ignore" => {
+                *args = &[];
+                Ok(AbiValue::Null)
+            },
+            _ => {
+                let arg = take_arg(abi_type, args)?;
+                self.decode_top_parsed(abi_type, arg)
+            },
+        }
+    }
+
+    fn decode_top_parsed(&self, abi_type: &AbiTypeName, bytes: &[u8]) -> Result<AbiValue, String> {
+        match abi_type.name.as_str() {
+            "u8" | "u16" | "u32" | "usize" | "u64" => {
+                let max_len = fixed_int_len(&abi_type.name);
+                check_top_int_len(abi_type, bytes, max_len)?;
+                Ok(AbiValue::from(decode_unsigned(bytes)))
+            },
+            "i8" | "i16" | "i32" | "isize" | "i64" => {
+                let max_len = fixed_int_len(&abi_type.name);
+                check_top_int_len(abi_type, bytes, max_len)?;
+                Ok(AbiValue::from(decode_signed(bytes)))
+            },
+            "bool" => match bytes {
+                [] => Ok(AbiValue::Bool(false)),
+                [1] => Ok(AbiValue::Bool(true)),
+                _ => Err(format!("invalid bool: 0x{}", hex::encode(bytes))),
+            },
+            "BigUint" | "NonZeroBigUint" => Ok(biguint_value(bytes)),
+            "BigInt" => Ok(bigint_value(bytes)),
+            "bytes" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+                Ok(buffer_value(&abi_type.name, bytes))
+            },
+            "Option" => {
+                if bytes.is_empty() {
+                    Ok(AbiValue::Null)
+                } else {
+                    self.decode_top_entirely(abi_type, bytes)
+                }
+            },
+            "List" | "vec" => {
+                let item_type = required_first_param(abi_type)?;
+                let mut input = bytes;
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.decode_nested_parsed(item_type, &mut input)?);
+                }
+                Ok(AbiValue::Array(items))
+            },
+            _ => {
+                if self.is_enum(&abi_type.name) && bytes.is_empty() {
+                    return self.decode_enum_variant(&abi_type.name, 0, &mut &bytes[..]);
+                }
+                if let Some(fields) = self.struct_fields(&abi_type.to_string()) {
+                    return self.decode_top_struct_fields(abi_type, fields, bytes);
+                }
+                self.decode_top_entirely(abi_type, bytes)
+            },
+        }
+    }
+
+    /// Types whose top encoding is the same as the nested encoding.
+    fn decode_top_entirely(
+        &self,
+        abi_type: &AbiTypeName,
+        bytes: &[u8],
+    ) -> Result<AbiValue, String> {
+        let mut input = bytes;
+        let result = self.decode_nested_parsed(abi_type, &mut input)?;
+        if !input.is_empty() {
+            return Err(format!(
+                "{} leftover bytes after decoding {abi_type}",
+                input.len()
+            ));
+        }
+        Ok(result)
+    }
+
+    fn decode_nested_parsed(
+        &self,
+        abi_type: &AbiTypeName,
+        input: &mut &[u8],
+    ) -> Result<AbiValue, String> {
+        match abi_type.name.as_str() {
+            "u8" | "u16" | "u32" | "usize" | "u64" => {
+                let bytes = take(input, fixed_int_len(&abi_type.name))?;
+                Ok(AbiValue::from(decode_unsigned(bytes)))
+            },
+            "i8" | "i16" | "i32" | "isize" | "i64" => {
+                let bytes = take(input, fixed_int_len(&abi_type.name))?;
+                Ok(AbiValue::from(decode_signed(bytes)))
+            },
+            "bool" => match take(input, 1)? {
+                [0] => Ok(AbiValue::Bool(false)),
+                [1] => Ok(AbiValue::Bool(true)),
+                other => Err(format!("invalid bool: 0x{}", hex::encode(other))),
+            },
+            "BigUint" | "NonZeroBigUint" => {
+                let bytes = take_length_prefixed(input)?;
+                Ok(biguint_value(bytes))
+            },
+            "BigInt" => {
+                let bytes = take_length_prefixed(input)?;
+                Ok(bigint_value(bytes))
+            },
+            "bytes" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+                let bytes = take_length_prefixed(input)?;
+                Ok(buffer_value(&abi_type.name, bytes))
+            },
+            "Address" => {
+                let bytes = take(input, 32)?;
+                Ok(AbiValue::String(address_bech32(bytes)))
+            },
+            "H256" => {
+                let bytes = take(input, 32)?;
+                Ok(hex_value(bytes))
+            },
+            "Option" => match take(input, 1)? {
+                [0] => Ok(AbiValue::Null),
+                [1] => self.decode_nested_parsed(required_first_param(abi_type)?, input),
+                other => Err(format!("invalid Option prefix: 0x{}", hex::encode(other))),
+            },
+            "List" | "vec" => {
+                let item_type = required_first_param(abi_type)?;
+                let len = take_len(input)?;
+                let mut items = Vec::new();
+                for _ in 0..len {
+                    items.push(self.decode_nested_parsed(item_type, input)?);
+                }
+                Ok(AbiValue::Array(items))
+            },
+            "tuple" => {
+                let mut items = Vec::new();
+                for item_type in &abi_type.type_params {
+                    items.push(self.decode_nested_parsed(item_type, input)?);
+                }
+                Ok(AbiValue::Array(items))
+            },
+            _ => {
+                if let Some(len) = abi_type.array_len() {
+                    let item_type = required_first_param(abi_type)?;
+                    if item_type.name == "u8" {
+                        return Ok(hex_value(take(input, len)?));
+                    }
+                    let mut items = Vec::new();
+                    for _ in 0..len {
+                        items.push(self.decode_nested_parsed(item_type, input)?);
+                    }
+                    return Ok(AbiValue::Array(items));
+                }
+
+                self.decode_nested_described(&abi_type.to_string(), input)
+            },
+        }
+    }
+
+    fn is_enum(&self, type_name: &str) -> bool {
+        self.types.get(type_name).map_or(false, |type_description| {
+            type_description.content_type == TYPE_DESCRIPTION_JSON_TYPE_ENUM
+        })
+    }
+
+    fn struct_fields(&self, type_name: &str) -> Option<&'a [StructFieldDescriptionJson]> {
+        self.types
+            .get(type_name)
+            .filter(|type_description| {
+                type_description.content_type == TYPE_DESCRIPTION_JSON_TYPE_STRUCT
+            })
+            .map(|type_description| type_description.fields.as_slice())
+    }
+
+    /// Values encoded by older versions of a struct can be missing the versioned fields at the end.
+    ///
+    /// These fields are left out of the resulting object.
+    fn decode_top_struct_fields(
+        &self,
+        abi_type: &AbiTypeName,
+        fields: &[StructFieldDescriptionJson],
+        bytes: &[u8],
+    ) -> Result<AbiValue, String> {
+        let mut input = bytes;
+        let mut result = Vec::new();
+        for field in fields {
+            if field.since.is_some() && input.is_empty() {
+                break;
+            }
+            let field_value = self.decode_nested(&field.field_type, &mut input)?;
+            result.push((field.name.clone(), field_value));
+        }
+        if !input.is_empty() {
+            return Err(format!(
+                "{} leftover bytes after decoding {abi_type}",
+                input.len()
+            ));
+        }
+        Ok(AbiValue::Object(result))
+    }
+
+    fn decode_nested_described(
+        &self,
+        type_name: &str,
+        input: &mut &[u8],
+    ) -> Result<AbiValue, String> {
+        let Some(type_description) = self.types.get(type_name) else {
+            return Err(format!(
+                "cannot decode type {type_name}, no description found"
+            ));
+        };
+
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_STRUCT => {
+                self.decode_nested_struct_fields(&type_description.fields, input)
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+                let discriminant = take(input, 1)?[0] as usize;
+                self.decode_enum_variant(type_name, discriminant, input)
+            },
+            other => Err(format!("cannot decode type {type_name} of kind {other}")),
+        }
+    }
+
+    fn decode_nested_struct_fields(
+        &self,
+        fields: &[StructFieldDescriptionJson],
+        input: &mut &[u8],
+    ) -> Result<AbiValue, String> {
+        self.decode_nested_fields(
+            fields
+                .iter()
+                .map(|field| (field.name.as_str(), field.field_type.as_str())),
+            input,
+        )
+    }
+
+    fn decode_enum_variant(
+        &self,
+        type_name: &str,
+        discriminant: usize,
+        input: &mut &[u8],
+    ) -> Result<AbiValue, String> {
+        let type_description = &self.types[type_name];
+        let Some(variant) = type_description
+            .variants
+            .iter()
+            .find(|variant| variant.discriminant.unwrap_or_default() == discriminant)
+        else {
+            return Err(format!(
+                "invalid discriminant {discriminant} for enum {type_name}"
+            ));
+        };
+
+        if variant.fields.is_empty() {
+            return Ok(AbiValue::String(variant.name.clone()));
+        }
+
+        let fields = self.decode_nested_struct_fields(&variant.fields, input)?;
+        Ok(AbiValue::Object(vec![(variant.name.clone(), fields)]))
+    }
+}
+
+fn decode_unsigned(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(0u64, |acc, byte| (acc << 8) | *byte as u64)
+}
+
+fn decode_signed(bytes: &[u8]) -> i64 {
+    let Some(first) = bytes.first() else {
+        return 0;
+    };
+    let initial = if *first >= 0x80 { -1i64 } else { 0i64 };
+    bytes
+        .iter()
+        .fold(initial, |acc, byte| (acc << 8) | *byte as i64)
+}
+
+fn biguint_value(bytes: &[u8]) -> AbiValue {
+    AbiValue::String(BigUint::from_bytes_be(bytes).to_string())
+}
+
+fn bigint_value(bytes: &[u8]) -> AbiValue {
+    AbiValue::String(BigInt::from_signed_bytes_be(bytes).to_string())
+}
+
+fn hex_value(bytes: &[u8]) -> AbiValue {
+    AbiValue::String(hex_repr(bytes))
+}
+
+fn buffer_value(type_name: &str, bytes: &[u8]) -> AbiValue {
+    match buffer_text(type_name, bytes) {
+        Some(s) => AbiValue::String(s.to_string()),
+        None => hex_value(bytes),
+    }
+}
+
+fn take_arg<'b>(abi_type: &AbiTypeName, args: &mut &[&'b [u8]]) -> Result<&'b [u8], String> {
+    let Some((arg, rest)) = args.split_first() else {
+        return Err(format!("not enough arguments, expected {abi_type}"));
+    };
+    *args = rest;
+    Ok(arg)
+}
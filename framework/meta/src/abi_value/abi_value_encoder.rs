@@ -0,0 +1,567 @@
+use std::collections::BTreeMap;
+
+use bech32::FromBase32;
+use multiversx_sc::codec::num_bigint::{BigInt, BigUint};
+use serde_json::{Map, Value};
+
+use crate::abi_json::{
+    AbiTypeName, StructFieldDescriptionJson, TypeDescriptionJson, TYPE_DESCRIPTION_JSON_TYPE_ENUM,
+    TYPE_DESCRIPTION_JSON_TYPE_STRUCT,
+};
+
+use super::{check_top_int_len, fixed_int_len, required_first_param};
+
+/// Encodes JSON values, based only on the type names and descriptions in the ABI.
+///
+/// Expects the same JSON representation that the `AbiValueDecoder` produces.
+/// On top of that, numbers can also be given as decimal strings
+/// and addresses can also be given as `0x`-prefixed hex strings.
+pub struct AbiValueEncoder<'a> {
+    types: &'a BTreeMap<String, TypeDescriptionJson>,
+}
+
+impl<'a> AbiValueEncoder<'a> {
+    pub fn new(types: &'a BTreeMap<String, TypeDescriptionJson>) -> Self {
+        AbiValueEncoder { types }
+    }
+
+    /// Top-encodes a value, e.g. a storage value or an endpoint argument.
+    pub fn encode_top(&self, type_name: &str, value: &Value) -> Result<Vec<u8>, String> {
+        self.encode_top_parsed(&AbiTypeName::parse(type_name), value)
+    }
+
+    /// Nested-encodes a value, appending it to the output.
+    pub fn encode_nested(
+        &self,
+        type_name: &str,
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        self.encode_nested_parsed(&AbiTypeName::parse(type_name), value, dest)
+    }
+
+    /// Encodes a list of values as raw arguments, e.g. for an endpoint call, one value per type.
+    ///
+    /// Multi-value types expect JSON arrays, a `null` optional produces no argument.
+    pub fn encode_multi<'n>(
+        &self,
+        type_names: impl IntoIterator<Item = &'n str>,
+        values: &[Value],
+    ) -> Result<Vec<Vec<u8>>, String> {
+        let type_names: Vec<&str> = type_names.into_iter().collect();
+        if type_names.len() != values.len() {
+            return Err(format!(
+                "expected {} values, got {}",
+                type_names.len(),
+                values.len()
+            ));
+        }
+
+        let mut args = Vec::new();
+        for (type_name, value) in type_names.into_iter().zip(values) {
+            self.encode_multi_parsed(&AbiTypeName::parse(type_name), value, &mut args)?;
+        }
+        Ok(args)
+    }
+
+    fn encode_multi_parsed(
+        &self,
+        abi_type: &AbiTypeName,
+        value: &Value,
+        args: &mut Vec<Vec<u8>>,
+    ) -> Result<(), String> {
+        match abi_type.name.as_str() {
+            "variadic" => {
+                let item_type = required_first_param(abi_type)?;
+                for item in expect_array(abi_type, value)? {
+                    self.encode_multi_parsed(item_type, item, args)?;
+                }
+                Ok(())
+            },
+            "counted-variadic" => {
+                let item_type = required_first_param(abi_type)?;
+                let items = expect_array(abi_type, value)?;
+                args.push(top_encode_unsigned(items.len() as u64));
+                for item in items {
+                    self.encode_multi_parsed(item_type, item, args)?;
+                }
+                Ok(())
+            },
+            "optional" => {
+                if value.is_null() {
+                    return Ok(());
+                }
+                self.encode_multi_parsed(required_first_param(abi_type)?, value, args)
+            },
+            "multi" => {
+                let items = expect_array(abi_type, value)?;
+                if items.len() != abi_type.type_params.len() {
+                    return Err(format!(
+                        "expected {} items for {abi_type}, got {}",
+                        abi_type.type_params.len(),
+                        items.len()
+                    ));
+                }
+                for (item_type, item) in abi_type.type_params.iter().zip(items) {
+                    self.encode_multi_parsed(item_type, item, args)?;
+                }
+                Ok(())
+            },
+            "ignore" => Ok(()),
+            _ => {
+                args.push(self.encode_top_parsed(abi_type, value)?);
+                Ok(())
+            },
+        }
+    }
+
+    fn encode_top_parsed(&self, abi_type: &AbiTypeName, value: &Value) -> Result<Vec<u8>, String> {
+        match abi_type.name.as_str() {
+            "u8" | "u16" | "u32" | "usize" | "u64" => {
+                let n = parse_unsigned(abi_type, value)?;
+                let bytes = top_encode_unsigned(n);
+                check_top_int_len(abi_type, &bytes, fixed_int_len(&abi_type.name))?;
+                Ok(bytes)
+            },
+            "i8" | "i16" | "i32" | "isize" | "i64" => {
+                let n = parse_signed(abi_type, value)?;
+                let bytes = top_encode_signed(n);
+                check_top_int_len(abi_type, &bytes, fixed_int_len(&abi_type.name))?;
+                Ok(bytes)
+            },
+            "bool" => match value {
+                Value::Bool(false) => Ok(Vec::new()),
+                Value::Bool(true) => Ok(vec![1]),
+                _ => Err(invalid_value(abi_type, value)),
+            },
+            "BigUint" | "NonZeroBigUint" => {
+                let n = parse_biguint(abi_type, value)?;
+                Ok(biguint_top_bytes(&n))
+            },
+            "BigInt" => {
+                let n = parse_bigint(abi_type, value)?;
+                Ok(bigint_top_bytes(&n))
+            },
+            "bytes" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+                parse_buffer(abi_type, value)
+            },
+            "Option" => {
+                if value.is_null() {
+                    return Ok(Vec::new());
+                }
+                let mut dest = Vec::new();
+                self.encode_nested_parsed(abi_type, value, &mut dest)?;
+                Ok(dest)
+            },
+            "List" | "vec" => {
+                let item_type = required_first_param(abi_type)?;
+                let mut dest = Vec::new();
+                for item in expect_array(abi_type, value)? {
+                    self.encode_nested_parsed(item_type, item, &mut dest)?;
+                }
+                Ok(dest)
+            },
+            _ => {
+                let type_name = abi_type.to_string();
+                if let Some(type_description) = self.types.get(&type_name) {
+                    match type_description.content_type.as_str() {
+                        TYPE_DESCRIPTION_JSON_TYPE_STRUCT => {
+                            let mut dest = Vec::new();
+                            self.encode_struct_fields(
+                                &type_name,
+                                &type_description.fields,
+                                value,
+                                true,
+                                &mut dest,
+                            )?;
+                            return Ok(dest);
+                        },
+                        TYPE_DESCRIPTION_JSON_TYPE_ENUM if value.is_string() => {
+                            // fieldless variants are top-encoded as a single top-encoded u8
+                            let mut dest = Vec::new();
+                            self.encode_enum_variant(&type_name, value, &mut dest)?;
+                            if dest == [0] {
+                                dest.clear();
+                            }
+                            return Ok(dest);
+                        },
+                        _ => {},
+                    }
+                }
+
+                let mut dest = Vec::new();
+                self.encode_nested_parsed(abi_type, value, &mut dest)?;
+                Ok(dest)
+            },
+        }
+    }
+
+    fn encode_nested_parsed(
+        &self,
+        abi_type: &AbiTypeName,
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        match abi_type.name.as_str() {
+            "u8" | "u16" | "u32" | "usize" | "u64" => {
+                let len = fixed_int_len(&abi_type.name);
+                let bytes = top_encode_unsigned(parse_unsigned(abi_type, value)?);
+                check_top_int_len(abi_type, &bytes, len)?;
+                dest.resize(dest.len() + len - bytes.len(), 0);
+                dest.extend_from_slice(&bytes);
+                Ok(())
+            },
+            "i8" | "i16" | "i32" | "isize" | "i64" => {
+                let len = fixed_int_len(&abi_type.name);
+                let n = parse_signed(abi_type, value)?;
+                let bytes = top_encode_signed(n);
+                check_top_int_len(abi_type, &bytes, len)?;
+                let padding = if n < 0 { 0xff } else { 0 };
+                dest.resize(dest.len() + len - bytes.len(), padding);
+                dest.extend_from_slice(&bytes);
+                Ok(())
+            },
+            "bool" => match value {
+                Value::Bool(b) => {
+                    dest.push(*b as u8);
+                    Ok(())
+                },
+                _ => Err(invalid_value(abi_type, value)),
+            },
+            "BigUint" | "NonZeroBigUint" => {
+                let n = parse_biguint(abi_type, value)?;
+                push_length_prefixed(dest, &biguint_top_bytes(&n));
+                Ok(())
+            },
+            "BigInt" => {
+                let n = parse_bigint(abi_type, value)?;
+                push_length_prefixed(dest, &bigint_top_bytes(&n));
+                Ok(())
+            },
+            "bytes" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+                push_length_prefixed(dest, &parse_buffer(abi_type, value)?);
+                Ok(())
+            },
+            "Address" => {
+                dest.extend_from_slice(&parse_address(abi_type, value)?);
+                Ok(())
+            },
+            "H256" => {
+                dest.extend_from_slice(&parse_fixed_hex(abi_type, value, 32)?);
+                Ok(())
+            },
+            "Option" => {
+                if value.is_null() {
+                    dest.push(0);
+                    return Ok(());
+                }
+                dest.push(1);
+                self.encode_nested_parsed(required_first_param(abi_type)?, value, dest)
+            },
+            "List" | "vec" => {
+                let item_type = required_first_param(abi_type)?;
+                let items = expect_array(abi_type, value)?;
+                dest.extend_from_slice(&(items.len() as u32).to_be_bytes());
+                for item in items {
+                    self.encode_nested_parsed(item_type, item, dest)?;
+                }
+                Ok(())
+            },
+            "tuple" => {
+                let items = expect_array(abi_type, value)?;
+                if items.len() != abi_type.type_params.len() {
+                    return Err(invalid_value(abi_type, value));
+                }
+                for (item_type, item) in abi_type.type_params.iter().zip(items) {
+                    self.encode_nested_parsed(item_type, item, dest)?;
+                }
+                Ok(())
+            },
+            _ => {
+                if let Some(len) = abi_type.array_len() {
+                    let item_type = required_first_param(abi_type)?;
+                    if item_type.name == "u8" {
+                        dest.extend_from_slice(&parse_fixed_hex(abi_type, value, len)?);
+                        return Ok(());
+                    }
+                    let items = expect_array(abi_type, value)?;
+                    if items.len() != len {
+                        return Err(invalid_value(abi_type, value));
+                    }
+                    for item in items {
+                        self.encode_nested_parsed(item_type, item, dest)?;
+                    }
+                    return Ok(());
+                }
+
+                self.encode_nested_described(&abi_type.to_string(), value, dest)
+            },
+        }
+    }
+
+    fn encode_nested_described(
+        &self,
+        type_name: &str,
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        let Some(type_description) = self.types.get(type_name) else {
+            return Err(format!(
+                "cannot encode type {type_name}, no description found"
+            ));
+        };
+
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_STRUCT => {
+                self.encode_struct_fields(type_name, &type_description.fields, value, false, dest)
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => self.encode_enum_variant(type_name, value, dest),
+            other => Err(format!("cannot encode type {type_name} of kind {other}")),
+        }
+    }
+
+    /// When top-encoding, versioned fields missing from the object are left out,
+    /// which yields the encoding of an older version of the struct.
+    fn encode_struct_fields(
+        &self,
+        type_name: &str,
+        fields: &[StructFieldDescriptionJson],
+        value: &Value,
+        allow_missing_versioned: bool,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        let Value::Object(object) = value else {
+            return Err(format!("expected object for {type_name}, got {value}"));
+        };
+        check_unknown_fields(type_name, fields, object)?;
+
+        let mut missing_versioned_field: Option<&str> = None;
+        for field in fields {
+            match object.get(&field.name) {
+                Some(field_value) => {
+                    if let Some(missing) = missing_versioned_field {
+                        return Err(format!(
+                            "field {} of {type_name} cannot be encoded without field {missing}",
+                            field.name
+                        ));
+                    }
+                    self.encode_nested(&field.field_type, field_value, dest)?;
+                },
+                None if allow_missing_versioned && field.since.is_some() => {
+                    missing_versioned_field.get_or_insert(&field.name);
+                },
+                None => {
+                    return Err(format!("missing field {} of {type_name}", field.name));
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_enum_variant(
+        &self,
+        type_name: &str,
+        value: &Value,
+        dest: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        let (variant_name, fields_value) = match value {
+            Value::String(variant_name) => (variant_name, None),
+            Value::Object(object) if object.len() == 1 => {
+                let (variant_name, fields_value) = object.iter().next().unwrap();
+                (variant_name, Some(fields_value))
+            },
+            _ => {
+                return Err(format!(
+                    "expected variant name or single-key object for {type_name}, got {value}"
+                ))
+            },
+        };
+
+        let type_description = &self.types[type_name];
+        let Some(variant) = type_description
+            .variants
+            .iter()
+            .find(|variant| &variant.name == variant_name)
+        else {
+            return Err(format!(
+                "unknown variant {variant_name} of enum {type_name}"
+            ));
+        };
+
+        let discriminant = variant.discriminant.unwrap_or_default();
+        let discriminant = u8::try_from(discriminant)
+            .map_err(|_| format!("discriminant {discriminant} of {type_name} exceeds 255"))?;
+        dest.push(discriminant);
+
+        match fields_value {
+            None if variant.fields.is_empty() => Ok(()),
+            None => Err(format!(
+                "variant {variant_name} of {type_name} has fields, expected an object"
+            )),
+            Some(fields_value) => {
+                let variant_type_name = format!("{type_name}::{variant_name}");
+                self.encode_struct_fields(
+                    &variant_type_name,
+                    &variant.fields,
+                    fields_value,
+                    false,
+                    dest,
+                )
+            },
+        }
+    }
+}
+
+fn check_unknown_fields(
+    type_name: &str,
+    fields: &[StructFieldDescriptionJson],
+    object: &Map<String, Value>,
+) -> Result<(), String> {
+    for key in object.keys() {
+        if !fields.iter().any(|field| &field.name == key) {
+            return Err(format!("unknown field {key} of {type_name}"));
+        }
+    }
+    Ok(())
+}
+
+fn invalid_value(abi_type: &AbiTypeName, value: &Value) -> String {
+    format!("invalid value for {abi_type}: {value}")
+}
+
+fn expect_array<'v>(abi_type: &AbiTypeName, value: &'v Value) -> Result<&'v Vec<Value>, String> {
+    value
+        .as_array()
+        .ok_or_else(|| format!("expected array for {abi_type}, got {value}"))
+}
+
+fn parse_unsigned(abi_type: &AbiTypeName, value: &Value) -> Result<u64, String> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| invalid_value(abi_type, value))
+}
+
+fn parse_signed(abi_type: &AbiTypeName, value: &Value) -> Result<i64, String> {
+    match value {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| invalid_value(abi_type, value))
+}
+
+fn parse_biguint(abi_type: &AbiTypeName, value: &Value) -> Result<BigUint, String> {
+    match value {
+        Value::Number(n) => n.as_u64().map(BigUint::from),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| invalid_value(abi_type, value))
+}
+
+fn parse_bigint(abi_type: &AbiTypeName, value: &Value) -> Result<BigInt, String> {
+    match value {
+        Value::Number(n) => n.as_i64().map(BigInt::from),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| invalid_value(abi_type, value))
+}
+
+fn parse_hex(abi_type: &AbiTypeName, value: &Value) -> Result<Vec<u8>, String> {
+    value
+        .as_str()
+        .and_then(|s| s.strip_prefix("0x"))
+        .and_then(|hex_str| hex::decode(hex_str).ok())
+        .ok_or_else(|| format!("expected 0x-prefixed hex for {abi_type}, got {value}"))
+}
+
+fn parse_fixed_hex(abi_type: &AbiTypeName, value: &Value, len: usize) -> Result<Vec<u8>, String> {
+    let bytes = parse_hex(abi_type, value)?;
+    if bytes.len() != len {
+        return Err(format!(
+            "expected {len} bytes for {abi_type}, got {}",
+            bytes.len()
+        ));
+    }
+    Ok(bytes)
+}
+
+/// Mirrors the decoder: text is only shown as hex when it is not valid UTF-8.
+fn parse_buffer(abi_type: &AbiTypeName, value: &Value) -> Result<Vec<u8>, String> {
+    if abi_type.name == "bytes" {
+        return parse_hex(abi_type, value);
+    }
+    let Value::String(s) = value else {
+        return Err(invalid_value(abi_type, value));
+    };
+    if let Ok(bytes) = parse_hex(abi_type, value) {
+        if std::str::from_utf8(&bytes).is_err() {
+            return Ok(bytes);
+        }
+    }
+    Ok(s.as_bytes().to_vec())
+}
+
+fn parse_address(abi_type: &AbiTypeName, value: &Value) -> Result<Vec<u8>, String> {
+    let Value::String(s) = value else {
+        return Err(invalid_value(abi_type, value));
+    };
+    if s.starts_with("0x") {
+        return parse_fixed_hex(abi_type, value, 32);
+    }
+    let (_, data, _) =
+        bech32::decode(s).map_err(|err| format!("invalid bech32 address {s}: {err}"))?;
+    let bytes = Vec::<u8>::from_base32(&data)
+        .map_err(|err| format!("invalid bech32 address {s}: {err}"))?;
+    if bytes.len() != 32 {
+        return Err(format!("invalid address length for {s}: {}", bytes.len()));
+    }
+    Ok(bytes)
+}
+
+fn top_encode_unsigned(n: u64) -> Vec<u8> {
+    let bytes = n.to_be_bytes();
+    let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    bytes[first_non_zero..].to_vec()
+}
+
+/// Minimal two's complement representation, empty for zero.
+fn top_encode_signed(n: i64) -> Vec<u8> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let bytes = n.to_be_bytes();
+    let mut start = 0;
+    while start < bytes.len() - 1 {
+        let redundant = (bytes[start] == 0 && bytes[start + 1] < 0x80)
+            || (bytes[start] == 0xff && bytes[start + 1] >= 0x80);
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    bytes[start..].to_vec()
+}
+
+fn biguint_top_bytes(n: &BigUint) -> Vec<u8> {
+    if n.bits() == 0 {
+        return Vec::new();
+    }
+    n.to_bytes_be()
+}
+
+fn bigint_top_bytes(n: &BigInt) -> Vec<u8> {
+    if n.bits() == 0 {
+        return Vec::new();
+    }
+    n.to_signed_bytes_be()
+}
+
+fn push_length_prefixed(dest: &mut Vec<u8>, bytes: &[u8]) {
+    dest.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    dest.extend_from_slice(bytes);
+}
@@ -0,0 +1,88 @@
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde_json::{Map, Number, Value};
+
+/// A value produced by the `AbiValueDecoder`.
+///
+/// Same shape as a JSON value, but objects are kept as a list of entries,
+/// so that struct fields stay in the order in which they are declared.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AbiValue {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Array(Vec<AbiValue>),
+    Object(Vec<(String, AbiValue)>),
+}
+
+impl From<u64> for AbiValue {
+    fn from(n: u64) -> Self {
+        AbiValue::Number(n.into())
+    }
+}
+
+impl From<i64> for AbiValue {
+    fn from(n: i64) -> Self {
+        AbiValue::Number(n.into())
+    }
+}
+
+/// Object entry order is lost in the conversion.
+impl From<AbiValue> for Value {
+    fn from(value: AbiValue) -> Self {
+        match value {
+            AbiValue::Null => Value::Null,
+            AbiValue::Bool(b) => Value::Bool(b),
+            AbiValue::Number(n) => Value::Number(n),
+            AbiValue::String(s) => Value::String(s),
+            AbiValue::Array(items) => Value::Array(items.into_iter().map(Value::from).collect()),
+            AbiValue::Object(entries) => Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, Value::from(value)))
+                    .collect::<Map<String, Value>>(),
+            ),
+        }
+    }
+}
+
+/// Objects are compared regardless of the order of their entries.
+impl PartialEq<Value> for AbiValue {
+    fn eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (AbiValue::Null, Value::Null) => true,
+            (AbiValue::Bool(a), Value::Bool(b)) => a == b,
+            (AbiValue::Number(a), Value::Number(b)) => a == b,
+            (AbiValue::String(a), Value::String(b)) => a == b,
+            (AbiValue::Array(a), Value::Array(b)) => a == b,
+            (AbiValue::Object(a), Value::Object(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .all(|(key, value)| b.get(key).map_or(false, |other| value == other))
+            },
+            _ => false,
+        }
+    }
+}
+
+impl Serialize for AbiValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AbiValue::Null => serializer.serialize_unit(),
+            AbiValue::Bool(b) => serializer.serialize_bool(*b),
+            AbiValue::Number(n) => n.serialize(serializer),
+            AbiValue::String(s) => serializer.serialize_str(s),
+            AbiValue::Array(items) => items.serialize(serializer),
+            AbiValue::Object(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            },
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use bech32::{ToBase32, Variant};
+
+use crate::abi_json::AbiTypeName;
+
+/// Length of the nested encoding of fixed-width integer types, also the maximum length of their top encoding.
+pub fn fixed_int_len(type_name: &str) -> usize {
+    match type_name {
+        "u8" | "i8" => 1,
+        "u16" | "i16" => 2,
+        "u32" | "i32" | "usize" | "isize" => 4,
+        _ => 8,
+    }
+}
+
+pub fn check_top_int_len(
+    abi_type: &AbiTypeName,
+    bytes: &[u8],
+    max_len: usize,
+) -> Result<(), String> {
+    if bytes.len() > max_len {
+        return Err(format!(
+            "value too long for {abi_type}: 0x{}",
+            hex::encode(bytes)
+        ));
+    }
+    Ok(())
+}
+
+pub fn required_first_param(abi_type: &AbiTypeName) -> Result<&AbiTypeName, String> {
+    abi_type
+        .first_param()
+        .ok_or_else(|| format!("missing type parameter in {abi_type}"))
+}
+
+/// Takes the given number of bytes from the beginning of the input and advances it.
+pub fn take<'b>(input: &mut &'b [u8], len: usize) -> Result<&'b [u8], String> {
+    if input.len() < len {
+        return Err(format!(
+            "input too short, expected {len} more bytes, got {}",
+            input.len()
+        ));
+    }
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    Ok(taken)
+}
+
+/// Takes the 4-byte length prefix of nested buffers and lists.
+pub fn take_len(input: &mut &[u8]) -> Result<usize, String> {
+    let bytes = take(input, 4)?;
+    Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+}
+
+pub fn take_length_prefixed<'b>(input: &mut &'b [u8]) -> Result<&'b [u8], String> {
+    let len = take_len(input)?;
+    take(input, len)
+}
+
+pub fn hex_repr(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+/// Text types are shown as text, as long as they are valid UTF-8, `bytes` never.
+pub fn buffer_text<'b>(type_name: &str, bytes: &'b [u8]) -> Option<&'b str> {
+    if type_name == "bytes" {
+        return None;
+    }
+    std::str::from_utf8(bytes).ok()
+}
+
+pub fn address_bech32(bytes: &[u8]) -> String {
+    bech32::encode("erd", bytes.to_base32(), Variant::Bech32).expect("bech32 encode error")
+}
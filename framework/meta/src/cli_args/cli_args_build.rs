@@ -47,6 +47,12 @@ pub struct BuildArgs {
     )]
     pub extract_imports: bool,
 
+    /// Runs a static analysis pass over the built contracts and prints a summary.
+    /// Flags float instructions, bulk memory instructions, memory growth, large data segments,
+    /// panic formatting code and exported functions that are not endpoints.
+    #[arg(long = "analyze", verbatim_doc_comment)]
+    pub analyze: bool,
+
     /// For the wasm crate, allows specifying the target directory where the Rust compiler will build the intermediary files.
     /// Sharing the same target directory can speed up building multiple contract crates at once.
     /// Has alias `target-dir` for backwards compatibility.
@@ -74,6 +80,18 @@ pub struct BuildArgs {
     #[arg(long = "target", verbatim_doc_comment)]
     pub target: Option<String>,
 
+    #[arg(
+        long = "no-size-check",
+        help = "Skips enforcing the max-size and max-size-growth limits from sc-config.toml. The size report is still written.",
+        action = ArgAction::SetFalse,
+    )]
+    pub check_size: bool,
+
+    /// Saves the sizes of the newly built contracts as the new size baseline, configured in sc-config.toml.
+    /// Growth limits are not enforced in this case.
+    #[arg(long = "update-size-baseline", verbatim_doc_comment)]
+    pub update_size_baseline: bool,
+
     /// Backwards compatibility with mxpy, delete when github actions are fixed.
     #[deprecated]
     #[arg(long, verbatim_doc_comment)]
@@ -98,12 +116,15 @@ impl Default for BuildArgs {
             emit_mir: false,
             emit_llvm_ir: false,
             extract_imports: true,
+            analyze: false,
             target_dir_wasm: None,
             twiggy_top: false,
             twiggy_paths: false,
             twiggy_monos: false,
             twiggy_dominators: false,
             target: None,
+            check_size: true,
+            update_size_baseline: false,
             release: false,
             out_dir: None,
         }
@@ -148,6 +169,9 @@ impl CliArgsToRaw for BuildArgs {
         if !self.extract_imports {
             raw.push("--no-imports".to_string());
         }
+        if self.analyze {
+            raw.push("--analyze".to_string());
+        }
         if let Some(target_dir_wasm) = &self.target_dir_wasm {
             // not using --target-dir-wasm, for backward compatibility
             raw.push("--target-dir".to_string());
@@ -165,6 +189,12 @@ impl CliArgsToRaw for BuildArgs {
         if self.twiggy_dominators {
             raw.push("--twiggy-dominators".to_string());
         }
+        if !self.check_size {
+            raw.push("--no-size-check".to_string());
+        }
+        if self.update_size_baseline {
+            raw.push("--update-size-baseline".to_string());
+        }
         raw
     }
 }
@@ -209,6 +239,7 @@ impl BuildDbgArgs {
             twiggy_paths: self.twiggy_paths,
             twiggy_monos: self.twiggy_monos,
             twiggy_dominators: self.twiggy_dominators,
+            check_size: false,
             ..BuildArgs::default()
         }
     }
@@ -66,6 +66,12 @@ pub enum ContractCliAction {
         about = "Generates a snippets project, based on the contract ABI."
     )]
     GenerateSnippets(GenerateSnippetsArgs),
+
+    #[command(
+        name = "generate-ts",
+        about = "Generates TypeScript bindings, based on the contract ABI. Once generated, they are kept up to date by the abi and build commands."
+    )]
+    GenerateTs,
 }
 
 impl CliArgsToRaw for ContractCliAction {
@@ -97,6 +103,9 @@ impl CliArgsToRaw for ContractCliAction {
                 raw.push("snippets".to_string());
                 raw.append(&mut args.to_raw());
             },
+            ContractCliAction::GenerateTs => {
+                raw.push("generate-ts".to_string());
+            },
         }
         raw
     }
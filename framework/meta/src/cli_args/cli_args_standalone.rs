@@ -63,6 +63,24 @@ pub enum StandaloneCliAction {
     TestGen(TestGenArgs),
     #[command(name = "test", about = "Runs cargo test")]
     Test(TestArgs),
+
+    #[command(
+        name = "abi-diff",
+        about = "Compares two versions of a contract ABI and reports changes that break upgrade compatibility."
+    )]
+    AbiDiff(AbiDiffArgs),
+
+    #[command(
+        name = "verify",
+        about = "Rebuilds a contract with the recorded toolchain and checks that it reproduces a given .mxsc.json or deployed code."
+    )]
+    Verify(VerifyArgs),
+
+    #[command(
+        name = "ei-check",
+        about = "Checks the VM hooks imported by all contract binaries in a workspace against an EI version."
+    )]
+    EiCheck(EiCheckArgs),
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -223,6 +241,16 @@ pub struct TemplateArgs {
     /// Will be current directory if not specified.
     #[arg(long, verbatim_doc_comment)]
     pub path: Option<PathBuf>,
+
+    /// Local checkout of the framework repository to take the templates from, instead of downloading it.
+    /// Templates are searched in its `contracts/examples` folder.
+    #[arg(long, verbatim_doc_comment, conflicts_with = "template_dir")]
+    pub repo_path: Option<PathBuf>,
+
+    /// Local directory containing contract templates, each with its own `mxsc-template.toml`.
+    /// No download is performed in this case.
+    #[arg(long, verbatim_doc_comment)]
+    pub template_dir: Option<PathBuf>,
 }
 
 impl CliArgsToRaw for TemplateArgs {
@@ -236,6 +264,16 @@ pub struct TemplateListArgs {
     /// The framework version referred to.
     #[arg(long = "tag", verbatim_doc_comment)]
     pub tag: Option<String>,
+
+    /// Local checkout of the framework repository to take the templates from, instead of downloading it.
+    /// Templates are searched in its `contracts/examples` folder.
+    #[arg(long, verbatim_doc_comment, conflicts_with = "template_dir")]
+    pub repo_path: Option<PathBuf>,
+
+    /// Local directory containing contract templates, each with its own `mxsc-template.toml`.
+    /// No download is performed in this case.
+    #[arg(long, verbatim_doc_comment)]
+    pub template_dir: Option<PathBuf>,
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
@@ -254,3 +292,76 @@ pub struct TestGenArgs {
     #[arg(long, verbatim_doc_comment)]
     pub create: bool,
 }
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AbiDiffArgs {
+    /// The ABI of the deployed contract version.
+    /// Can be either an .abi.json or an .mxsc.json file.
+    #[arg(long, verbatim_doc_comment)]
+    pub old: String,
+
+    /// The ABI of the new contract version, usually from the output folder of a fresh build.
+    /// Can be either an .abi.json or an .mxsc.json file.
+    #[arg(long, verbatim_doc_comment)]
+    pub new: String,
+
+    /// Do not exit with an error code on breaking changes.
+    #[arg(long = "allow-breaking", verbatim_doc_comment)]
+    pub allow_breaking: bool,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct VerifyArgs {
+    /// The contract crate to rebuild.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// The .mxsc.json file to verify, usually the one published with a release.
+    #[arg(
+        long,
+        conflicts_with = "address",
+        required_unless_present = "address",
+        verbatim_doc_comment
+    )]
+    pub mxsc: Option<String>,
+
+    /// The bech32 address of a deployed contract, whose code is fetched from the gateway.
+    #[arg(long, verbatim_doc_comment)]
+    pub address: Option<String>,
+
+    /// The gateway used to fetch deployed code.
+    #[arg(
+        long,
+        default_value = "https://gateway.multiversx.com",
+        verbatim_doc_comment
+    )]
+    pub gateway: String,
+
+    /// Overrides the rustup toolchain used for the build.
+    /// By default it is derived from the rustc info recorded in the .mxsc.json file.
+    #[arg(long, verbatim_doc_comment)]
+    pub toolchain: Option<String>,
+
+    /// Skips the build and only compares the existing contents of the output folder.
+    #[arg(long = "no-build", verbatim_doc_comment)]
+    pub no_build: bool,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct EiCheckArgs {
+    /// Target directory where to look for contract crates, the .wasm files are taken from their output folders.
+    /// Will be current directory if not specified.
+    #[arg(long, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// Ignore all directories with these names.
+    #[arg(long, verbatim_doc_comment)]
+    #[clap(global = true, default_value = "target")]
+    pub ignore: Vec<String>,
+
+    /// The EI version all contracts must be compatible with.
+    /// By default it is the version currently on mainnet.
+    #[arg(long, verbatim_doc_comment)]
+    pub ei: Option<String>,
+}
@@ -1,4 +1,5 @@
 mod generate_snippets;
+pub mod generate_ts;
 mod meta_abi;
 mod meta_config;
 pub mod sc_config;
@@ -31,6 +32,7 @@ pub fn cli_main<AbiObj: ContractAbiProvider>() {
         ContractCliAction::GenerateSnippets(gs_args) => {
             meta_config_opt.generate_rust_snippets(&gs_args)
         },
+        ContractCliAction::GenerateTs => meta_config_opt.generate_ts(),
     }
 }
 
@@ -40,6 +42,7 @@ fn process_original_abi<AbiObj: ContractAbiProvider>(cli_args: &ContractCliArgs)
     meta_config.sc_config.validate_contract_variants();
     meta_config.write_contract_abis();
     meta_config.write_esdt_attribute_abis();
+    meta_config.refresh_ts();
     meta_config.generate_wasm_crates();
     meta_config
 }
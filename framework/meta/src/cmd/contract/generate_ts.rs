@@ -0,0 +1,8 @@
+mod ts_gen_common;
+mod ts_gen_contract;
+mod ts_gen_main;
+mod ts_gen_types;
+mod ts_type_map;
+
+pub use ts_gen_main::generate_ts_bindings;
+pub use ts_type_map::map_abi_type_to_ts_type;
@@ -0,0 +1,107 @@
+use std::fmt::Write;
+
+use convert_case::{Case, Casing};
+
+const TS_RESERVED_WORDS: &[&str] = &[
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+];
+
+pub(crate) fn write_ts_docs(out: &mut String, indent: &str, docs: &[String]) {
+    if docs.is_empty() {
+        return;
+    }
+
+    writeln!(out, "{indent}/**").unwrap();
+    for line in docs {
+        let line = line.trim().replace("*/", "*\\/");
+        if line.is_empty() {
+            writeln!(out, "{indent} *").unwrap();
+        } else {
+            writeln!(out, "{indent} * {line}").unwrap();
+        }
+    }
+    writeln!(out, "{indent} */").unwrap();
+}
+
+fn is_valid_ts_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' || first == '$' => {},
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Local identifiers (method parameters) follow the TypeScript camelCase convention.
+pub(crate) fn ts_identifier(name: &str) -> String {
+    let mut result = name.to_case(Case::Camel);
+    if !is_valid_ts_identifier(&result) {
+        result = format!(
+            "_{}",
+            result.replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+        );
+    }
+    if TS_RESERVED_WORDS.contains(&result.as_str()) {
+        result.push('_');
+    }
+    result
+}
+
+/// Object properties keep the ABI names, so that they match the JSON/encoder representation.
+///
+/// Names that are not valid identifiers (e.g. tuple fields `0`, `1`) get quoted.
+pub(crate) fn ts_property_name(name: &str) -> String {
+    if is_valid_ts_identifier(name) {
+        name.to_string()
+    } else {
+        format!("\"{name}\"")
+    }
+}
+
+/// Class/interface names derived from contract names or event identifiers.
+pub(crate) fn ts_pascal_case_name(name: &str) -> String {
+    let result = name.to_case(Case::Pascal);
+    if is_valid_ts_identifier(&result) {
+        result
+    } else {
+        format!(
+            "_{}",
+            result.replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+        )
+    }
+}
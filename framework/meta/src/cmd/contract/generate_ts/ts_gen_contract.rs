@@ -0,0 +1,241 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use crate::abi_json::{
+    ConstructorAbiJson, ContractAbiJson, EndpointAbiJson, EndpointMutabilityAbiJson, InputAbiJson,
+    OutputAbiJson, TypeDescriptionJson,
+};
+
+use super::{
+    ts_gen_common::{ts_identifier, ts_pascal_case_name, ts_property_name, write_ts_docs},
+    ts_type_map::map_abi_type_to_ts_type,
+};
+
+/// Common declarations, so that the generated file is self-contained.
+const TS_PRELUDE: &str = r#"export type Mutability = "mutable" | "readonly" | "pure";
+
+/**
+ * Description of a contract call, with typed arguments.
+ *
+ * Encoding the arguments and sending the transaction is left to the SDK of choice.
+ */
+export interface ContractCall<Args extends unknown[], Result> {
+    readonly contractAddress?: string;
+    readonly endpoint: string;
+    readonly args: Args;
+    readonly mutability: Mutability;
+    /** Tokens accepted as payment, "*" means any token. Empty if not payable. */
+    readonly payableInTokens: readonly string[];
+    readonly onlyOwner: boolean;
+    readonly onlyAdmin: boolean;
+    /** Role required from the caller, if any. */
+    readonly onlyRole?: string;
+    /** Type marker only, never set at runtime. */
+    readonly __result?: Result;
+}
+"#;
+
+pub(crate) fn write_prelude(out: &mut String) {
+    writeln!(out, "{TS_PRELUDE}").unwrap();
+}
+
+pub(crate) fn write_contract_class(out: &mut String, abi: &ContractAbiJson) {
+    let class_name = format!("{}Contract", ts_pascal_case_name(&abi.name));
+
+    write_ts_docs(out, "", &abi.docs);
+    writeln!(out, "export class {class_name} {{").unwrap();
+    writeln!(out, "    static readonly contractName = \"{}\";", abi.name).unwrap();
+    writeln!(out).unwrap();
+    write_endpoint_name_list(out, "endpoints", abi, |endpoint| {
+        matches!(endpoint.mutability, EndpointMutabilityAbiJson::Mutable)
+    });
+    write_endpoint_name_list(out, "views", abi, |endpoint| {
+        !matches!(endpoint.mutability, EndpointMutabilityAbiJson::Mutable)
+    });
+    writeln!(out, "    constructor(readonly address: string) {{}}").unwrap();
+
+    if let Some(constructor) = &abi.constructor {
+        write_deploy_method(out, constructor, &abi.types);
+    }
+
+    for endpoint in &abi.endpoints {
+        write_endpoint_method(out, endpoint, &abi.types);
+    }
+
+    writeln!(out, "}}").unwrap();
+}
+
+fn write_endpoint_name_list(
+    out: &mut String,
+    list_name: &str,
+    abi: &ContractAbiJson,
+    filter: impl Fn(&EndpointAbiJson) -> bool,
+) {
+    let names: Vec<String> = abi
+        .endpoints
+        .iter()
+        .filter(|endpoint| filter(endpoint))
+        .map(|endpoint| format!("\"{}\"", endpoint.name))
+        .collect();
+    writeln!(
+        out,
+        "    static readonly {list_name}: readonly string[] = [{}];",
+        names.join(", ")
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+}
+
+fn write_deploy_method(
+    out: &mut String,
+    constructor: &ConstructorAbiJson,
+    types: &BTreeMap<String, TypeDescriptionJson>,
+) {
+    writeln!(out).unwrap();
+    write_ts_docs(out, "    ", &constructor.docs);
+    let signature = CallSignature::new(&constructor.inputs, &constructor.outputs, types);
+    writeln!(
+        out,
+        "    static deploy({}): ContractCall<[{}], {}> {{",
+        signature.params, signature.args_type, signature.result_type
+    )
+    .unwrap();
+    writeln!(out, "        return {{").unwrap();
+    writeln!(out, "            endpoint: \"init\",").unwrap();
+    write_call_fields(
+        out,
+        &signature,
+        "mutable",
+        &constructor.payable_in_tokens,
+        false,
+        false,
+        None,
+    );
+    writeln!(out, "        }};").unwrap();
+    writeln!(out, "    }}").unwrap();
+}
+
+fn write_endpoint_method(
+    out: &mut String,
+    endpoint: &EndpointAbiJson,
+    types: &BTreeMap<String, TypeDescriptionJson>,
+) {
+    writeln!(out).unwrap();
+    write_ts_docs(out, "    ", &endpoint.docs);
+    let signature = CallSignature::new(&endpoint.inputs, &endpoint.outputs, types);
+    writeln!(
+        out,
+        "    {}({}): ContractCall<[{}], {}> {{",
+        ts_property_name(&endpoint.name),
+        signature.params,
+        signature.args_type,
+        signature.result_type
+    )
+    .unwrap();
+    writeln!(out, "        return {{").unwrap();
+    writeln!(out, "            contractAddress: this.address,").unwrap();
+    writeln!(out, "            endpoint: \"{}\",", endpoint.name).unwrap();
+    let mutability = match endpoint.mutability {
+        EndpointMutabilityAbiJson::Mutable => "mutable",
+        EndpointMutabilityAbiJson::Readonly => "readonly",
+        EndpointMutabilityAbiJson::Pure => "pure",
+    };
+    write_call_fields(
+        out,
+        &signature,
+        mutability,
+        &endpoint.payable_in_tokens,
+        endpoint.only_owner.unwrap_or_default(),
+        endpoint.only_admin.unwrap_or_default(),
+        endpoint.only_role.as_deref(),
+    );
+    writeln!(out, "        }};").unwrap();
+    writeln!(out, "    }}").unwrap();
+}
+
+fn write_call_fields(
+    out: &mut String,
+    signature: &CallSignature,
+    mutability: &str,
+    payable_in_tokens: &[String],
+    only_owner: bool,
+    only_admin: bool,
+    only_role: Option<&str>,
+) {
+    let payable: Vec<String> = payable_in_tokens
+        .iter()
+        .map(|token| format!("\"{token}\""))
+        .collect();
+    writeln!(out, "            args: [{}],", signature.arg_names).unwrap();
+    writeln!(out, "            mutability: \"{mutability}\",").unwrap();
+    writeln!(
+        out,
+        "            payableInTokens: [{}],",
+        payable.join(", ")
+    )
+    .unwrap();
+    writeln!(out, "            onlyOwner: {only_owner},").unwrap();
+    writeln!(out, "            onlyAdmin: {only_admin},").unwrap();
+    if let Some(role) = only_role {
+        writeln!(out, "            onlyRole: \"{role}\",").unwrap();
+    }
+}
+
+/// The pieces of a generated method declaration, derived from the endpoint inputs and outputs.
+struct CallSignature {
+    params: String,
+    args_type: String,
+    arg_names: String,
+    result_type: String,
+}
+
+impl CallSignature {
+    fn new(
+        inputs: &[InputAbiJson],
+        outputs: &[OutputAbiJson],
+        types: &BTreeMap<String, TypeDescriptionJson>,
+    ) -> Self {
+        // trailing `optional<T>` arguments can be omitted by the caller
+        let num_required = inputs
+            .iter()
+            .rposition(|input| !input.type_name.starts_with("optional<"))
+            .map_or(0, |pos| pos + 1);
+
+        let mut params = Vec::new();
+        let mut arg_types = Vec::new();
+        let mut arg_names = Vec::new();
+        for (index, input) in inputs.iter().enumerate() {
+            let name = ts_identifier(&input.arg_name);
+            let ts_type = map_abi_type_to_ts_type(&input.type_name, types);
+            if index < num_required {
+                params.push(format!("{name}: {ts_type}"));
+            } else {
+                params.push(format!("{name}?: {}", strip_undefined(&ts_type)));
+            }
+            arg_types.push(ts_type);
+            arg_names.push(name);
+        }
+
+        let result_type = match outputs.len() {
+            0 => "void".to_string(),
+            1 => map_abi_type_to_ts_type(&outputs[0].type_name, types),
+            _ => {
+                let output_types: Vec<String> = outputs
+                    .iter()
+                    .map(|output| map_abi_type_to_ts_type(&output.type_name, types))
+                    .collect();
+                format!("[{}]", output_types.join(", "))
+            },
+        };
+
+        CallSignature {
+            params: params.join(", "),
+            args_type: arg_types.join(", "),
+            arg_names: arg_names.join(", "),
+            result_type,
+        }
+    }
+}
+
+fn strip_undefined(ts_type: &str) -> &str {
+    ts_type.strip_suffix(" | undefined").unwrap_or(ts_type)
+}
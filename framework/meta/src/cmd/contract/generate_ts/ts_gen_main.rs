@@ -0,0 +1,53 @@
+use std::{fs, path::Path};
+
+use crate::{abi_json::ContractAbiJson, print_util::print_generate_ts};
+
+use super::{
+    super::meta_config::MetaConfig,
+    ts_gen_contract::{write_contract_class, write_prelude},
+    ts_gen_types::{write_event_definitions, write_type_definitions},
+};
+
+const TS_HEADER: &str = "// Code generated by the multiversx-sc build system. DO NOT EDIT.
+";
+
+impl MetaConfig {
+    /// Generates TypeScript bindings for all contract variants, in the output folder.
+    pub fn generate_ts(&self) {
+        fs::create_dir_all(&self.output_dir).unwrap();
+        for contract_variant in &self.sc_config.contracts {
+            let ts_path = format!("{}/{}", self.output_dir, contract_variant.ts_output_name());
+            write_ts_file(&ContractAbiJson::from(&contract_variant.abi), &ts_path);
+        }
+    }
+
+    /// Keeps previously generated TypeScript bindings in sync with the ABI.
+    ///
+    /// Only variants that already have bindings in the output folder get regenerated,
+    /// the initial generation is always explicit, via the `generate-ts` action.
+    pub fn refresh_ts(&self) {
+        for contract_variant in &self.sc_config.contracts {
+            let ts_path = format!("{}/{}", self.output_dir, contract_variant.ts_output_name());
+            if Path::new(&ts_path).exists() {
+                write_ts_file(&ContractAbiJson::from(&contract_variant.abi), &ts_path);
+            }
+        }
+    }
+}
+
+fn write_ts_file(abi_json: &ContractAbiJson, ts_path: &str) {
+    print_generate_ts(ts_path);
+    fs::write(ts_path, generate_ts_bindings(abi_json)).expect("failed to write TypeScript file");
+}
+
+/// Produces the contents of the TypeScript bindings file, based on the contract ABI.
+pub fn generate_ts_bindings(abi_json: &ContractAbiJson) -> String {
+    let mut out = String::new();
+    out.push_str(TS_HEADER);
+    out.push('\n');
+    write_prelude(&mut out);
+    write_type_definitions(&mut out, &abi_json.types);
+    write_event_definitions(&mut out, &abi_json.events, &abi_json.types);
+    write_contract_class(&mut out, abi_json);
+    out
+}
@@ -0,0 +1,163 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use crate::abi_json::{
+    EnumVariantDescriptionJson, EventAbiJson, StructFieldDescriptionJson, TypeDescriptionJson,
+    TYPE_DESCRIPTION_JSON_TYPE_ENUM, TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM,
+    TYPE_DESCRIPTION_JSON_TYPE_STRUCT,
+};
+
+use super::{
+    ts_gen_common::{ts_pascal_case_name, ts_property_name, write_ts_docs},
+    ts_type_map::map_abi_type_to_ts_type,
+};
+
+pub(crate) fn write_type_definitions(
+    out: &mut String,
+    types: &BTreeMap<String, TypeDescriptionJson>,
+) {
+    for (type_name, type_description) in types {
+        write_ts_docs(out, "", &type_description.docs);
+        match type_description.content_type.as_str() {
+            TYPE_DESCRIPTION_JSON_TYPE_STRUCT => {
+                write_struct(out, type_name, &type_description.fields, types)
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_ENUM => {
+                write_enum(out, type_name, &type_description.variants, types)
+            },
+            TYPE_DESCRIPTION_JSON_TYPE_EXPLICIT_ENUM => {
+                write_explicit_enum(out, type_name, &type_description.variants)
+            },
+            _ => {
+                writeln!(out, "export type {type_name} = unknown;").unwrap();
+            },
+        }
+        writeln!(out).unwrap();
+    }
+}
+
+fn write_struct(
+    out: &mut String,
+    type_name: &str,
+    fields: &[StructFieldDescriptionJson],
+    types: &BTreeMap<String, TypeDescriptionJson>,
+) {
+    writeln!(out, "export interface {type_name} {{").unwrap();
+    write_fields(out, "    ", fields, types);
+    writeln!(out, "}}").unwrap();
+}
+
+fn write_fields(
+    out: &mut String,
+    indent: &str,
+    fields: &[StructFieldDescriptionJson],
+    types: &BTreeMap<String, TypeDescriptionJson>,
+) {
+    for field in fields {
+        write_ts_docs(out, indent, &field.docs);
+        writeln!(
+            out,
+            "{indent}{}: {};",
+            ts_property_name(&field.name),
+            map_abi_type_to_ts_type(&field.field_type, types)
+        )
+        .unwrap();
+    }
+}
+
+/// Fieldless enums become TypeScript enums, with explicit discriminants.
+///
+/// Enums with fields become discriminated unions, keyed by the variant name.
+fn write_enum(
+    out: &mut String,
+    type_name: &str,
+    variants: &[EnumVariantDescriptionJson],
+    types: &BTreeMap<String, TypeDescriptionJson>,
+) {
+    if variants.iter().all(|variant| variant.fields.is_empty()) {
+        writeln!(out, "export enum {type_name} {{").unwrap();
+        for (index, variant) in variants.iter().enumerate() {
+            write_ts_docs(out, "    ", &variant.docs);
+            writeln!(
+                out,
+                "    {} = {},",
+                variant.name,
+                variant.discriminant.unwrap_or(index)
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+        return;
+    }
+
+    writeln!(out, "export type {type_name} =").unwrap();
+    for (index, variant) in variants.iter().enumerate() {
+        write_ts_docs(out, "    ", &variant.docs);
+        let discriminant = variant.discriminant.unwrap_or(index);
+        let terminator = if index + 1 == variants.len() { ";" } else { "" };
+        if variant.fields.is_empty() {
+            writeln!(
+                out,
+                "    | {{ name: \"{}\"; discriminant: {discriminant} }}{terminator}",
+                variant.name
+            )
+            .unwrap();
+        } else {
+            writeln!(
+                out,
+                "    | {{ name: \"{}\"; discriminant: {discriminant}; fields: {{",
+                variant.name
+            )
+            .unwrap();
+            write_fields(out, "          ", &variant.fields, types);
+            writeln!(out, "      }} }}{terminator}").unwrap();
+        }
+    }
+}
+
+fn write_explicit_enum(out: &mut String, type_name: &str, variants: &[EnumVariantDescriptionJson]) {
+    let variant_names: Vec<String> = variants
+        .iter()
+        .map(|variant| format!("\"{}\"", variant.name))
+        .collect();
+    let union = if variant_names.is_empty() {
+        "never".to_string()
+    } else {
+        variant_names.join(" | ")
+    };
+    writeln!(out, "export type {type_name} = {union};").unwrap();
+}
+
+pub(crate) fn write_event_definitions(
+    out: &mut String,
+    events: &[EventAbiJson],
+    types: &BTreeMap<String, TypeDescriptionJson>,
+) {
+    for event in events {
+        write_ts_docs(out, "", &event.docs);
+        writeln!(out, "export interface {} {{", event_interface_name(event)).unwrap();
+        writeln!(out, "    identifier: \"{}\";", event.identifier).unwrap();
+        for input in &event.inputs {
+            if input.indexed == Some(true) {
+                writeln!(out, "    /** Indexed, sent as topic. */").unwrap();
+            }
+            writeln!(
+                out,
+                "    {}: {};",
+                ts_property_name(&input.arg_name),
+                map_abi_type_to_ts_type(&input.type_name, types)
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+    }
+}
+
+fn event_interface_name(event: &EventAbiJson) -> String {
+    let name = ts_pascal_case_name(&event.identifier);
+    if name.ends_with("Event") {
+        name
+    } else {
+        format!("{name}Event")
+    }
+}
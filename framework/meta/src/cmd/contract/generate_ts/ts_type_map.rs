@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use crate::abi_json::{AbiTypeName, TypeDescriptionJson};
+
+/// Converts an ABI type name to the TypeScript type used in the generated bindings.
+///
+/// Numbers that fit into 53 bits are represented as `number`, everything larger as `bigint`.
+/// Addresses and token identifiers are strings (bech32 and ticker, respectively).
+pub fn map_abi_type_to_ts_type(
+    abi_type_name: &str,
+    types: &BTreeMap<String, TypeDescriptionJson>,
+) -> String {
+    ts_type(&AbiTypeName::parse(abi_type_name), types)
+}
+
+pub(crate) fn ts_type(
+    abi_type: &AbiTypeName,
+    types: &BTreeMap<String, TypeDescriptionJson>,
+) -> String {
+    match abi_type.name.as_str() {
+        "()" => "void".to_string(),
+        "u8" | "u16" | "u32" | "usize" | "i8" | "i16" | "i32" | "isize" => "number".to_string(),
+        "u64" | "i64" | "BigUint" | "BigInt" | "NonZeroBigUint" => "bigint".to_string(),
+        "bool" => "boolean".to_string(),
+        "bytes" => "Uint8Array".to_string(),
+        "utf-8 string" | "Address" | "H256" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+            "string".to_string()
+        },
+        "List" | "variadic" => list_ts_type(abi_type, types),
+        "Option" => format!("{} | null", param_ts_type(abi_type, types)),
+        "optional" => format!("{} | undefined", param_ts_type(abi_type, types)),
+        "multi" | "tuple" => tuple_ts_type(abi_type, types),
+        _ if abi_type.array_len().is_some() => list_ts_type(abi_type, types),
+        name if types.contains_key(name) => name.to_string(),
+        _ => "unknown".to_string(),
+    }
+}
+
+fn param_ts_type(abi_type: &AbiTypeName, types: &BTreeMap<String, TypeDescriptionJson>) -> String {
+    abi_type
+        .first_param()
+        .map(|param| ts_type(param, types))
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn list_ts_type(abi_type: &AbiTypeName, types: &BTreeMap<String, TypeDescriptionJson>) -> String {
+    if let Some(item_type) = abi_type.first_param() {
+        if item_type.name == "u8" {
+            return "Uint8Array".to_string();
+        }
+    }
+    format!("Array<{}>", param_ts_type(abi_type, types))
+}
+
+fn tuple_ts_type(abi_type: &AbiTypeName, types: &BTreeMap<String, TypeDescriptionJson>) -> String {
+    let items: Vec<String> = abi_type
+        .type_params
+        .iter()
+        .map(|param| ts_type(param, types))
+        .collect();
+    format!("[{}]", items.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::map_abi_type_to_ts_type;
+
+    #[test]
+    fn test_basic_types() {
+        let types = BTreeMap::new();
+        assert_eq!(map_abi_type_to_ts_type("u32", &types), "number");
+        assert_eq!(map_abi_type_to_ts_type("u64", &types), "bigint");
+        assert_eq!(map_abi_type_to_ts_type("BigUint", &types), "bigint");
+        assert_eq!(map_abi_type_to_ts_type("Address", &types), "string");
+        assert_eq!(map_abi_type_to_ts_type("array32<u8>", &types), "Uint8Array");
+        assert_eq!(map_abi_type_to_ts_type("Unknown", &types), "unknown");
+    }
+
+    #[test]
+    fn test_nested_types() {
+        let types = BTreeMap::new();
+        assert_eq!(
+            map_abi_type_to_ts_type("variadic<multi<Address,BigUint>>", &types),
+            "Array<[string, bigint]>"
+        );
+        assert_eq!(
+            map_abi_type_to_ts_type("Option<List<u64>>", &types),
+            "Array<bigint> | null"
+        );
+        assert_eq!(
+            map_abi_type_to_ts_type("optional<bool>", &types),
+            "boolean | undefined"
+        );
+    }
+}
@@ -3,12 +3,16 @@ use std::fs;
 use multiversx_sc::abi::ContractAbi;
 
 use crate::{
-    cli_args::BuildArgs, find_workspace::find_current_workspace,
-    print_util::print_workspace_target_dir, tools::check_tools_installed, CargoTomlContents,
+    cli_args::BuildArgs,
+    find_workspace::find_current_workspace,
+    print_util::{print_update_size_baseline, print_workspace_target_dir},
+    tools::check_tools_installed,
+    CargoTomlContents,
 };
 
 use super::{
-    sc_config::ScConfig, wasm_cargo_toml_data::WasmCargoTomlData,
+    sc_config::{ContractSizeReport, ScConfig, SizeBaseline},
+    wasm_cargo_toml_data::WasmCargoTomlData,
     wasm_cargo_toml_generate::generate_wasm_cargo_toml,
 };
 
@@ -87,11 +91,44 @@ impl MetaConfig {
         check_tools_installed(&mut build_args);
         adjust_target_dir_wasm(&mut build_args);
 
-        for contract_variant in &self.sc_config.contracts {
-            contract_variant.build_contract(&build_args, self.output_dir.as_str());
+        let size_baseline_path = self.size_baseline_path();
+        let size_baseline = size_baseline_path
+            .as_ref()
+            .map(SizeBaseline::load_from_file)
+            .unwrap_or_default();
+
+        let size_reports: Vec<ContractSizeReport> = self
+            .sc_config
+            .contracts
+            .iter()
+            .map(|contract_variant| {
+                contract_variant.build_contract(
+                    &build_args,
+                    self.output_dir.as_str(),
+                    &size_baseline,
+                )
+            })
+            .collect();
+
+        if build_args.update_size_baseline {
+            let size_baseline_path = size_baseline_path.expect(
+                "no size baseline configured, add `size-baseline` to the sc-config.toml settings",
+            );
+            update_size_baseline(size_baseline, &size_reports, &size_baseline_path);
+        }
+        if build_args.check_size {
+            check_size_reports(&size_reports, build_args.update_size_baseline);
         }
     }
 
+    /// The baseline path in the config is relative to the contract crate, the meta crate is one level below.
+    fn size_baseline_path(&self) -> Option<String> {
+        self.sc_config
+            .size_baseline
+            .as_ref()
+            .map(|path| format!("../{path}"))
+    }
+
     /// Cleans the wasm crates and all other outputs.
     pub fn clean(&self) {
         self.clean_contract_crates();
@@ -165,6 +202,36 @@ fn adjust_target_dir_wasm(build_args: &mut BuildArgs) {
     }
 }
 
+/// Records the sizes of the freshly built contracts as the new size baseline.
+fn update_size_baseline(
+    mut size_baseline: SizeBaseline,
+    size_reports: &[ContractSizeReport],
+    size_baseline_path: &str,
+) {
+    print_update_size_baseline(size_baseline_path);
+    for size_report in size_reports {
+        size_baseline.set(&size_report.contract, size_report.size);
+    }
+    size_baseline.save_to_file(size_baseline_path);
+}
+
+/// Fails the build if any contract exceeds its size limits, after all of them got built and reported.
+fn check_size_reports(size_reports: &[ContractSizeReport], ignore_growth: bool) {
+    let violations: Vec<String> = size_reports
+        .iter()
+        .flat_map(|size_report| {
+            let mut size_report = size_report.clone();
+            size_report.over_max_size_growth &= !ignore_growth;
+            size_report.violations()
+        })
+        .collect();
+    assert!(
+        violations.is_empty(),
+        "contract size budget exceeded:\n{}",
+        violations.join("\n")
+    );
+}
+
 /// This one is useful for some of the special unmanaged EI tests in the framework.
 /// Will do nothing for regular contracts.
 fn copy_to_wasm_unmanaged_ei() {
@@ -7,6 +7,7 @@ mod oc_validate;
 mod wasm_build;
 mod wasm_clean;
 mod wasm_crate_gen;
+mod wasm_size;
 mod wasm_update;
 
 pub use multi_contract_serde::{
@@ -16,3 +17,4 @@ pub use multi_contract_serde::{
 pub use oc_config::ContractVariant;
 pub use oc_global_config::ScConfig;
 pub use oc_settings::{ContractVariantProfile, ContractVariantSettings};
+pub use wasm_size::{ContractSizeReport, SizeBaseline};
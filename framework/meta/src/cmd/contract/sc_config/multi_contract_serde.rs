@@ -54,11 +54,26 @@ pub struct ContractVariantSerde {
 
     #[serde(default)]
     pub profile: Option<ContractVariantProfileSerde>,
+
+    /// Maximum size of the built wasm, in bytes. Accepts the same format as `stack-size`, e.g. "24k".
+    #[serde(default)]
+    #[serde(rename = "max-size")]
+    pub max_size: Option<String>,
+
+    /// Maximum allowed growth of the built wasm compared to the size baseline, in percent.
+    #[serde(default)]
+    #[serde(rename = "max-size-growth")]
+    pub max_size_growth: Option<u32>,
 }
 
 #[derive(Deserialize, Default, Debug)]
 pub struct MultiContractGeneralSettingsSerde {
     pub main: Option<String>,
+
+    /// Path to the file holding the reference wasm sizes, relative to the contract crate.
+    #[serde(default)]
+    #[serde(rename = "size-baseline")]
+    pub size_baseline: Option<String>,
 }
 
 #[derive(Deserialize, Default, Debug, Clone, PartialEq, Eq)]
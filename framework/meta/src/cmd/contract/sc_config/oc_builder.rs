@@ -7,7 +7,7 @@ use std::{
 
 use super::{
     oc_global_config::SC_CONFIG_FILE_NAMES,
-    oc_settings::{parse_allocator, parse_check_ei, parse_stack_size},
+    oc_settings::{parse_allocator, parse_check_ei, parse_max_size, parse_stack_size},
     ContractVariant, ContractVariantProfile, ContractVariantSerde, ContractVariantSettings,
     MultiContractConfigSerde, ScConfig,
 };
@@ -76,6 +76,8 @@ impl ContractVariantBuilder {
                     features: cms.features.clone(),
                     kill_legacy_callback: cms.kill_legacy_callback,
                     profile: ContractVariantProfile::from_serde(&cms.profile),
+                    max_size: parse_max_size(&cms.max_size),
+                    max_size_growth: cms.max_size_growth,
                 },
                 ..default
             },
@@ -189,6 +191,7 @@ fn build_contract_abi(builder: ContractVariantBuilder, original_abi: &ContractAb
         endpoints,
         promise_callbacks,
         events: original_abi.events.clone(),
+        storage: original_abi.storage.clone(),
         has_callback,
         type_descriptions: original_abi.type_descriptions.clone(),
         esdt_attributes: original_abi.esdt_attributes.clone(),
@@ -271,6 +274,7 @@ impl ScConfig {
         ScConfig {
             default_contract_config_name: config.settings.main.clone().unwrap_or_default(),
             contracts,
+            size_baseline: config.settings.size_baseline.clone(),
         }
     }
 
@@ -290,6 +294,7 @@ impl ScConfig {
                 wasm_crate_name,
                 abi: original_abi.clone(),
             }],
+            size_baseline: None,
         }
     }
 
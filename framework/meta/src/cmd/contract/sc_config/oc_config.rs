@@ -114,10 +114,18 @@ impl ContractVariant {
         format!("{}.wat", self.output_name_base(build_args))
     }
 
+    pub fn ts_output_name(&self) -> String {
+        format!("{}.ts", &self.contract_name)
+    }
+
     pub fn mxsc_file_output_name(&self, build_args: &BuildArgs) -> String {
         format!("{}.mxsc.json", self.output_name_base(build_args))
     }
 
+    pub fn size_report_output_name(&self, build_args: &BuildArgs) -> String {
+        format!("{}.size.json", self.output_name_base(build_args))
+    }
+
     pub fn imports_json_output_name(&self, build_args: &BuildArgs) -> String {
         format!("{}.imports.json", self.output_name_base(build_args))
     }
@@ -15,6 +15,9 @@ pub const SC_CONFIG_FILE_NAMES: &[&str] = &["sc-config.toml", "multicontract.tom
 pub struct ScConfig {
     pub default_contract_config_name: String,
     pub contracts: Vec<ContractVariant>,
+
+    /// Path to the wasm size baseline file, relative to the contract crate, if configured.
+    pub size_baseline: Option<String>,
 }
 
 impl ScConfig {
@@ -35,6 +35,12 @@ pub struct ContractVariantSettings {
     pub kill_legacy_callback: bool,
 
     pub profile: ContractVariantProfile,
+
+    /// The build fails if the wasm output is larger than this, in bytes.
+    pub max_size: Option<usize>,
+
+    /// The build fails if the wasm output grew by more than this percentage, relative to the size baseline.
+    pub max_size_growth: Option<u32>,
 }
 
 impl Default for ContractVariantSettings {
@@ -48,6 +54,8 @@ impl Default for ContractVariantSettings {
             features: Default::default(),
             kill_legacy_callback: false,
             profile: Default::default(),
+            max_size: None,
+            max_size_growth: None,
         }
     }
 }
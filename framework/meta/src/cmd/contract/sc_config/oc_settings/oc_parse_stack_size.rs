@@ -15,6 +15,11 @@ pub fn parse_stack_size(stack_size: &Option<String>) -> usize {
     }
 }
 
+/// Same format as the stack size.
+pub fn parse_max_size(max_size: &Option<String>) -> Option<usize> {
+    max_size.as_deref().map(parse_stack_size_expr)
+}
+
 fn parse_stack_size_expr(stack_size_str: &str) -> usize {
     if let Some(s) = stack_size_str.strip_suffix(STACK_SIZE_SUFFIX_KILO) {
         parse_stack_size_str(s) * STACK_SIZE_MULIPLIER_KILO
@@ -1,6 +1,6 @@
 use std::{ffi::OsStr, fs, process::Command};
 
-use super::ContractVariant;
+use super::{ContractSizeReport, ContractVariant, SizeBaseline};
 use crate::{
     abi_json::ContractAbiJson,
     cli_args::BuildArgs,
@@ -11,7 +11,12 @@ use crate::{
 };
 
 impl ContractVariant {
-    pub fn build_contract(&self, build_args: &BuildArgs, output_path: &str) {
+    pub fn build_contract(
+        &self,
+        build_args: &BuildArgs,
+        output_path: &str,
+        size_baseline: &SizeBaseline,
+    ) -> ContractSizeReport {
         let mut command = self.compose_build_command(build_args);
 
         print_build_command(self.wasm_output_name(build_args), &command);
@@ -25,6 +30,7 @@ impl ContractVariant {
         assert!(exit_status.success(), "contract build process failed");
 
         self.finalize_build(build_args, output_path);
+        self.write_size_report(build_args, output_path, size_baseline)
     }
 
     fn compose_build_command(&self, build_args: &BuildArgs) -> Command {
@@ -72,6 +78,7 @@ impl ContractVariant {
         self.run_wasm_opt(build_args, output_path);
         self.run_wasm2wat(build_args, output_path);
         self.extract_imports(build_args, output_path);
+        self.run_wasm_analysis(build_args, output_path);
         self.run_twiggy(build_args, output_path);
         self.pack_mxsc_file(build_args, output_path);
     }
@@ -102,6 +109,29 @@ impl ContractVariant {
         save_mxsc_file_json(&mxsc_file_json, output_mxsc_path);
     }
 
+    fn write_size_report(
+        &self,
+        build_args: &BuildArgs,
+        output_path: &str,
+        size_baseline: &SizeBaseline,
+    ) -> ContractSizeReport {
+        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
+        let size = fs::metadata(output_wasm_path)
+            .expect("failed to open compiled contract")
+            .len() as usize;
+        let size_report = ContractSizeReport::new(
+            &self.contract_name,
+            size,
+            &self.settings,
+            size_baseline.get(&self.contract_name),
+        );
+        let output_size_report_path =
+            format!("{output_path}/{}", self.size_report_output_name(build_args));
+        print_size_report(&output_size_report_path, &size_report);
+        size_report.save_to_file(output_size_report_path);
+        size_report
+    }
+
     fn run_wasm_opt(&self, build_args: &BuildArgs, output_path: &str) {
         if !build_args.wasm_opt {
             return;
@@ -141,6 +171,20 @@ impl ContractVariant {
     }
 }
 
+impl ContractVariant {
+    fn run_wasm_analysis(&self, build_args: &BuildArgs, output_path: &str) {
+        if !build_args.analyze {
+            return;
+        }
+
+        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
+        print_analyze_wasm(&output_wasm_path);
+        let analysis =
+            tools::analyze_wasm_file(&output_wasm_path, &self.all_exported_function_names());
+        print_wasm_analysis(&analysis);
+    }
+}
+
 fn write_imports_output(dest_path: &str, import_names: &[String]) {
     let json = serde_json::to_string_pretty(import_names).unwrap();
     fs::write(dest_path, json).expect("failed to write imports json file");
@@ -0,0 +1,127 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use super::ContractVariantSettings;
+
+/// Reference wasm sizes, by contract name, used to detect unexpected growth.
+///
+/// Saved as a JSON object, e.g. `{ "adder": 697 }`.
+#[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeBaseline(pub BTreeMap<String, usize>);
+
+impl SizeBaseline {
+    /// A missing file means an empty baseline, since it is created on the first update.
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Self {
+        match fs::read_to_string(path.as_ref()) {
+            Ok(s) => serde_json::from_str(&s).unwrap_or_else(|err| {
+                panic!(
+                    "failed to parse size baseline {}: {err}",
+                    path.as_ref().display()
+                )
+            }),
+            Err(_) => SizeBaseline::default(),
+        }
+    }
+
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) {
+        let mut json = serde_json::to_string_pretty(self).unwrap();
+        json.push('\n');
+        fs::write(path, json).expect("failed to write size baseline file");
+    }
+
+    pub fn get(&self, contract_name: &str) -> Option<usize> {
+        self.0.get(contract_name).cloned()
+    }
+
+    pub fn set(&mut self, contract_name: &str, size: usize) {
+        self.0.insert(contract_name.to_string(), size);
+    }
+}
+
+/// Machine-readable result of the size checks for one contract variant, written next to the `.mxsc.json`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractSizeReport {
+    pub contract: String,
+    pub size: usize,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_size: Option<usize>,
+
+    /// Relative to the baseline, negative if the contract got smaller.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub growth_percent: Option<f64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size_growth: Option<u32>,
+
+    pub over_max_size: bool,
+    pub over_max_size_growth: bool,
+}
+
+impl ContractSizeReport {
+    pub fn new(
+        contract_name: &str,
+        size: usize,
+        settings: &ContractVariantSettings,
+        baseline_size: Option<usize>,
+    ) -> Self {
+        let growth_percent = baseline_size
+            .filter(|baseline_size| *baseline_size > 0)
+            .map(|baseline_size| {
+                (size as f64 - baseline_size as f64) * 100.0 / baseline_size as f64
+            });
+        let over_max_size = settings.max_size.map_or(false, |max_size| size > max_size);
+        let over_max_size_growth = match (settings.max_size_growth, growth_percent) {
+            (Some(max_size_growth), Some(growth_percent)) => {
+                growth_percent > max_size_growth as f64
+            },
+            _ => false,
+        };
+
+        ContractSizeReport {
+            contract: contract_name.to_string(),
+            size,
+            max_size: settings.max_size,
+            baseline_size,
+            growth_percent,
+            max_size_growth: settings.max_size_growth,
+            over_max_size,
+            over_max_size_growth,
+        }
+    }
+
+    /// Human-readable descriptions of the exceeded limits.
+    pub fn violations(&self) -> Vec<String> {
+        let mut violations = Vec::new();
+        if self.over_max_size {
+            violations.push(format!(
+                "contract {} has {} bytes, over its budget of {} bytes",
+                self.contract,
+                self.size,
+                self.max_size.unwrap_or_default()
+            ));
+        }
+        if self.over_max_size_growth {
+            violations.push(format!(
+                "contract {} grew from {} to {} bytes ({:+.2}%), over the allowed {}%",
+                self.contract,
+                self.baseline_size.unwrap_or_default(),
+                self.size,
+                self.growth_percent.unwrap_or_default(),
+                self.max_size_growth.unwrap_or_default()
+            ));
+        }
+        violations
+    }
+
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) {
+        let mut json = serde_json::to_string_pretty(self).unwrap();
+        json.push('\n');
+        fs::write(path, json).expect("failed to write size report file");
+    }
+}
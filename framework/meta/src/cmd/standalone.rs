@@ -1,4 +1,6 @@
+pub mod abi_diff;
 mod all;
+pub mod ei_check;
 mod info;
 mod local_deps;
 mod print_util;
@@ -6,16 +8,20 @@ pub mod scen_test_gen;
 pub mod template;
 pub mod test;
 pub(crate) mod upgrade;
+pub mod verify;
 
 use crate::cli_args::{StandaloneCliAction, StandaloneCliArgs};
+use abi_diff::abi_diff;
 use all::call_all_meta;
 use clap::Parser;
+use ei_check::ei_check;
 use info::call_info;
 use local_deps::local_deps;
 use scen_test_gen::test_gen_tool;
 use template::{create_contract, print_template_names};
 use test::test;
 use upgrade::upgrade_sc;
+use verify::verify;
 
 /// Entry point in the program when calling it as a standalone tool.
 pub fn cli_main_standalone() {
@@ -39,6 +45,9 @@ pub fn cli_main_standalone() {
             test_gen_tool(args);
         },
         Some(StandaloneCliAction::Test(args)) => test(args),
+        Some(StandaloneCliAction::AbiDiff(args)) => abi_diff(args),
+        Some(StandaloneCliAction::Verify(args)) => verify(args),
+        Some(StandaloneCliAction::EiCheck(args)) => ei_check(args),
         None => {},
     }
 }
@@ -0,0 +1,35 @@
+mod abi_diff_endpoints;
+mod abi_diff_events;
+mod abi_diff_report;
+mod abi_diff_storage;
+mod abi_diff_types;
+
+pub use abi_diff_report::{AbiChange, AbiChangeSeverity, AbiDiffReport};
+
+use crate::{
+    abi_json::{load_abi_json_from_file, ContractAbiJson},
+    cli_args::AbiDiffArgs,
+};
+
+/// Compares two versions of a contract ABI and reports everything that could affect
+/// clients or stored data after an upgrade.
+pub fn compare_abis(old: &ContractAbiJson, new: &ContractAbiJson) -> AbiDiffReport {
+    let mut report = AbiDiffReport::default();
+    abi_diff_endpoints::compare_constructors(old, new, &mut report);
+    abi_diff_endpoints::compare_endpoints(old, new, &mut report);
+    abi_diff_events::compare_events(old, new, &mut report);
+    abi_diff_storage::compare_storage(old, new, &mut report);
+    abi_diff_types::compare_types(old, new, &mut report);
+    report
+}
+
+pub fn abi_diff(args: &AbiDiffArgs) {
+    let old = load_abi_json_from_file(&args.old);
+    let new = load_abi_json_from_file(&args.new);
+    let report = compare_abis(&old, &new);
+    report.print();
+
+    if report.has_breaking_changes() && !args.allow_breaking {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::abi_json::{
+    AbiTypeName, ContractAbiJson, EndpointAbiJson, EndpointMutabilityAbiJson, InputAbiJson,
+    OutputAbiJson,
+};
+
+use super::AbiDiffReport;
+
+pub(super) fn normalized_type_name(type_name: &str) -> String {
+    AbiTypeName::parse(type_name).to_string()
+}
+
+fn input_types(inputs: &[InputAbiJson]) -> Vec<String> {
+    inputs
+        .iter()
+        .map(|input| normalized_type_name(&input.type_name))
+        .collect()
+}
+
+fn output_types(outputs: &[OutputAbiJson]) -> Vec<String> {
+    outputs
+        .iter()
+        .map(|output| normalized_type_name(&output.type_name))
+        .collect()
+}
+
+/// Used to guess renames. Endpoints without arguments and results are too generic to tell.
+fn same_signature(old: &EndpointAbiJson, new: &EndpointAbiJson) -> bool {
+    (!old.inputs.is_empty() || !old.outputs.is_empty())
+        && input_types(&old.inputs) == input_types(&new.inputs)
+        && output_types(&old.outputs) == output_types(&new.outputs)
+}
+
+pub(super) fn compare_constructors(
+    old: &ContractAbiJson,
+    new: &ContractAbiJson,
+    report: &mut AbiDiffReport,
+) {
+    let (Some(old_constructor), Some(new_constructor)) = (&old.constructor, &new.constructor)
+    else {
+        return;
+    };
+
+    // the constructor is not called by clients, but the upgrade transaction needs the new arguments
+    let old_inputs = input_types(&old_constructor.inputs);
+    let new_inputs = input_types(&new_constructor.inputs);
+    if old_inputs != new_inputs {
+        report.warning(format!(
+            "constructor arguments changed from ({}) to ({})",
+            old_inputs.join(", "),
+            new_inputs.join(", ")
+        ));
+    }
+}
+
+pub(super) fn compare_endpoints(
+    old: &ContractAbiJson,
+    new: &ContractAbiJson,
+    report: &mut AbiDiffReport,
+) {
+    let added: Vec<&EndpointAbiJson> = new
+        .endpoints
+        .iter()
+        .filter(|endpoint| find_endpoint(old, &endpoint.name).is_none())
+        .collect();
+
+    for old_endpoint in &old.endpoints {
+        if let Some(new_endpoint) = find_endpoint(new, &old_endpoint.name) {
+            compare_endpoint(old_endpoint, new_endpoint, report);
+            continue;
+        }
+
+        let renamed_to = added
+            .iter()
+            .find(|new_endpoint| same_signature(old_endpoint, new_endpoint));
+        if let Some(new_endpoint) = renamed_to {
+            report.breaking(format!(
+                "endpoint '{}' removed, possibly renamed to '{}'",
+                old_endpoint.name, new_endpoint.name
+            ));
+        } else {
+            report.breaking(format!("endpoint '{}' removed", old_endpoint.name));
+        }
+    }
+
+    for new_endpoint in added {
+        report.info(format!("endpoint '{}' added", new_endpoint.name));
+    }
+}
+
+fn find_endpoint<'a>(abi: &'a ContractAbiJson, name: &str) -> Option<&'a EndpointAbiJson> {
+    abi.endpoints.iter().find(|endpoint| endpoint.name == name)
+}
+
+fn compare_endpoint(old: &EndpointAbiJson, new: &EndpointAbiJson, report: &mut AbiDiffReport) {
+    let name = &old.name;
+
+    let old_inputs = input_types(&old.inputs);
+    let new_inputs = input_types(&new.inputs);
+    if old_inputs != new_inputs {
+        report.breaking(format!(
+            "endpoint '{name}' arguments changed from ({}) to ({})",
+            old_inputs.join(", "),
+            new_inputs.join(", ")
+        ));
+    }
+
+    let old_outputs = output_types(&old.outputs);
+    let new_outputs = output_types(&new.outputs);
+    if old_outputs != new_outputs {
+        report.breaking(format!(
+            "endpoint '{name}' results changed from ({}) to ({})",
+            old_outputs.join(", "),
+            new_outputs.join(", ")
+        ));
+    }
+
+    compare_payability(name, &old.payable_in_tokens, &new.payable_in_tokens, report);
+    compare_restriction(name, "only_owner", old.only_owner, new.only_owner, report);
+    compare_restriction(name, "only_admin", old.only_admin, new.only_admin, report);
+    compare_role(name, &old.only_role, &new.only_role, report);
+    compare_mutability(name, &old.mutability, &new.mutability, report);
+}
+
+fn accepts_token(payable_in_tokens: &[String], token: &str) -> bool {
+    payable_in_tokens
+        .iter()
+        .any(|accepted| accepted == "*" || accepted == token)
+}
+
+fn compare_payability(
+    name: &str,
+    old_tokens: &[String],
+    new_tokens: &[String],
+    report: &mut AbiDiffReport,
+) {
+    let no_longer_accepted: Vec<&String> = old_tokens
+        .iter()
+        .filter(|token| !accepts_token(new_tokens, token))
+        .collect();
+    if !no_longer_accepted.is_empty() {
+        report.breaking(format!(
+            "endpoint '{name}' no longer accepts payment in {:?}",
+            no_longer_accepted
+        ));
+    }
+
+    let newly_accepted: Vec<&String> = new_tokens
+        .iter()
+        .filter(|token| !accepts_token(old_tokens, token))
+        .collect();
+    if !newly_accepted.is_empty() {
+        report.warning(format!(
+            "endpoint '{name}' now accepts payment in {:?}",
+            newly_accepted
+        ));
+    }
+}
+
+fn compare_restriction(
+    name: &str,
+    restriction: &str,
+    old: Option<bool>,
+    new: Option<bool>,
+    report: &mut AbiDiffReport,
+) {
+    match (old.unwrap_or_default(), new.unwrap_or_default()) {
+        (false, true) => report.breaking(format!("endpoint '{name}' became {restriction}")),
+        (true, false) => report.warning(format!("endpoint '{name}' is no longer {restriction}")),
+        _ => {},
+    }
+}
+
+fn compare_role(
+    name: &str,
+    old: &Option<String>,
+    new: &Option<String>,
+    report: &mut AbiDiffReport,
+) {
+    match (old, new) {
+        (None, Some(new_role)) => {
+            report.breaking(format!("endpoint '{name}' became only_role \"{new_role}\""))
+        },
+        (Some(old_role), Some(new_role)) if old_role != new_role => report.breaking(format!(
+            "endpoint '{name}' role changed from \"{old_role}\" to \"{new_role}\""
+        )),
+        (Some(old_role), None) => report.warning(format!(
+            "endpoint '{name}' is no longer only_role \"{old_role}\""
+        )),
+        _ => {},
+    }
+}
+
+fn mutability_name(mutability: &EndpointMutabilityAbiJson) -> &'static str {
+    match mutability {
+        EndpointMutabilityAbiJson::Mutable => "mutable",
+        EndpointMutabilityAbiJson::Readonly => "readonly",
+        EndpointMutabilityAbiJson::Pure => "pure",
+    }
+}
+
+fn compare_mutability(
+    name: &str,
+    old: &EndpointMutabilityAbiJson,
+    new: &EndpointMutabilityAbiJson,
+    report: &mut AbiDiffReport,
+) {
+    let old_name = mutability_name(old);
+    let new_name = mutability_name(new);
+    if old_name == new_name {
+        return;
+    }
+
+    // views can be queried off-chain, they stop working once they become mutable
+    if matches!(new, EndpointMutabilityAbiJson::Mutable) {
+        report.breaking(format!(
+            "endpoint '{name}' changed from {old_name} to {new_name}"
+        ));
+    } else {
+        report.info(format!(
+            "endpoint '{name}' changed from {old_name} to {new_name}"
+        ));
+    }
+}
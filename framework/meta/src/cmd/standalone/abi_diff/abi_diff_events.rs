@@ -0,0 +1,56 @@
+use crate::abi_json::{ContractAbiJson, EventAbiJson};
+
+use super::{abi_diff_endpoints::normalized_type_name, AbiDiffReport};
+
+fn event_signature(event: &EventAbiJson) -> Vec<String> {
+    event
+        .inputs
+        .iter()
+        .map(|input| {
+            let type_name = normalized_type_name(&input.type_name);
+            if input.indexed == Some(true) {
+                format!("indexed {type_name}")
+            } else {
+                type_name
+            }
+        })
+        .collect()
+}
+
+/// Indexers and other off-chain consumers decode events by identifier,
+/// so any change in the argument list breaks them.
+pub(super) fn compare_events(
+    old: &ContractAbiJson,
+    new: &ContractAbiJson,
+    report: &mut AbiDiffReport,
+) {
+    for old_event in &old.events {
+        let Some(new_event) = find_event(new, &old_event.identifier) else {
+            report.breaking(format!("event '{}' removed", old_event.identifier));
+            continue;
+        };
+
+        let old_signature = event_signature(old_event);
+        let new_signature = event_signature(new_event);
+        if old_signature != new_signature {
+            report.breaking(format!(
+                "event '{}' signature changed from ({}) to ({})",
+                old_event.identifier,
+                old_signature.join(", "),
+                new_signature.join(", ")
+            ));
+        }
+    }
+
+    for new_event in &new.events {
+        if find_event(old, &new_event.identifier).is_none() {
+            report.info(format!("event '{}' added", new_event.identifier));
+        }
+    }
+}
+
+fn find_event<'a>(abi: &'a ContractAbiJson, identifier: &str) -> Option<&'a EventAbiJson> {
+    abi.events
+        .iter()
+        .find(|event| event.identifier == identifier)
+}
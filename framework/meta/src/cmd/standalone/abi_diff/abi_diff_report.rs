@@ -0,0 +1,91 @@
+use colored::Colorize;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum AbiChangeSeverity {
+    /// Additions, which do not affect existing clients.
+    Info,
+
+    /// Changes that are backwards compatible, but should be reviewed, e.g. dropped access restrictions.
+    Warning,
+
+    /// Changes that break existing clients, indexers or previously stored data.
+    Breaking,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AbiChange {
+    pub severity: AbiChangeSeverity,
+    pub description: String,
+}
+
+#[derive(Default, Debug)]
+pub struct AbiDiffReport {
+    pub changes: Vec<AbiChange>,
+}
+
+impl AbiDiffReport {
+    pub fn info(&mut self, description: String) {
+        self.push(AbiChangeSeverity::Info, description);
+    }
+
+    pub fn warning(&mut self, description: String) {
+        self.push(AbiChangeSeverity::Warning, description);
+    }
+
+    pub fn breaking(&mut self, description: String) {
+        self.push(AbiChangeSeverity::Breaking, description);
+    }
+
+    fn push(&mut self, severity: AbiChangeSeverity, description: String) {
+        self.changes.push(AbiChange {
+            severity,
+            description,
+        });
+    }
+
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.severity == AbiChangeSeverity::Breaking)
+    }
+
+    pub fn iter_with_severity(
+        &self,
+        severity: AbiChangeSeverity,
+    ) -> impl Iterator<Item = &AbiChange> {
+        self.changes
+            .iter()
+            .filter(move |change| change.severity == severity)
+    }
+
+    pub fn print(&self) {
+        if self.changes.is_empty() {
+            println!("{}", "No ABI changes found.".green());
+            return;
+        }
+
+        for change in self.iter_with_severity(AbiChangeSeverity::Breaking) {
+            println!("{} {}", "BREAKING".red().bold(), change.description);
+        }
+        for change in self.iter_with_severity(AbiChangeSeverity::Warning) {
+            println!("{}  {}", "WARNING".yellow(), change.description);
+        }
+        for change in self.iter_with_severity(AbiChangeSeverity::Info) {
+            println!(
+                "{}     {}",
+                "INFO".truecolor(128, 128, 128),
+                change.description
+            );
+        }
+
+        let num_breaking = self.iter_with_severity(AbiChangeSeverity::Breaking).count();
+        if num_breaking > 0 {
+            println!(
+                "\n{}",
+                format!("Found {num_breaking} breaking change(s).").red()
+            );
+        } else {
+            println!("\n{}", "No breaking changes found.".green());
+        }
+    }
+}
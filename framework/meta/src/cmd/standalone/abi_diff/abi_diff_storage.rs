@@ -0,0 +1,79 @@
+use crate::abi_json::{ContractAbiJson, StorageAbiJson};
+
+use super::{abi_diff_endpoints::normalized_type_name, AbiDiffReport};
+
+/// Plain getters and setters use the same encoding as the `SingleValueMapper`,
+/// switching between them does not affect the stored data.
+fn storage_layout_kind(mapper: &str) -> &str {
+    match mapper {
+        "storage_get" | "storage_set" => "SingleValueMapper",
+        _ => mapper,
+    }
+}
+
+fn storage_layout(storage: &StorageAbiJson) -> String {
+    let key_arg_types: Vec<String> = storage
+        .key_args
+        .iter()
+        .map(|key_arg| normalized_type_name(&key_arg.type_name))
+        .collect();
+    let value_types: Vec<String> = storage
+        .value_types
+        .iter()
+        .map(|value_type| normalized_type_name(value_type))
+        .collect();
+    let layout = format!(
+        "{}<{}>",
+        storage_layout_kind(&storage.mapper),
+        value_types.join(", ")
+    );
+    if key_arg_types.is_empty() {
+        layout
+    } else {
+        format!("{layout} keyed by ({})", key_arg_types.join(", "))
+    }
+}
+
+/// The upgraded contract inherits the storage of the old one,
+/// so any change in how an existing key is encoded corrupts the data.
+pub(super) fn compare_storage(
+    old: &ContractAbiJson,
+    new: &ContractAbiJson,
+    report: &mut AbiDiffReport,
+) {
+    for old_storage in &old.storage {
+        let Some(new_storage) = find_storage(new, &old_storage.key) else {
+            if is_first_declaration(old, old_storage) {
+                report.warning(format!(
+                    "storage '{}' no longer declared, existing data is left behind",
+                    old_storage.key
+                ));
+            }
+            continue;
+        };
+
+        let old_layout = storage_layout(old_storage);
+        let new_layout = storage_layout(new_storage);
+        if old_layout != new_layout {
+            report.breaking(format!(
+                "storage '{}' layout changed from {} to {}",
+                old_storage.key, old_layout, new_layout
+            ));
+        }
+    }
+
+    for new_storage in &new.storage {
+        if find_storage(old, &new_storage.key).is_none() && is_first_declaration(new, new_storage) {
+            report.info(format!("storage '{}' added", new_storage.key));
+        }
+    }
+}
+
+fn find_storage<'a>(abi: &'a ContractAbiJson, key: &str) -> Option<&'a StorageAbiJson> {
+    abi.storage.iter().find(|storage| storage.key == key)
+}
+
+/// The same key can be declared several times, e.g. by a getter and a setter, only reported once.
+fn is_first_declaration(abi: &ContractAbiJson, storage: &StorageAbiJson) -> bool {
+    find_storage(abi, &storage.key).is_some_and(|first| std::ptr::eq(first, storage))
+}
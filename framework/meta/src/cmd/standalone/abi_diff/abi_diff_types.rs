@@ -0,0 +1,141 @@
+use crate::abi_json::{
+    ContractAbiJson, EnumVariantDescriptionJson, StructFieldDescriptionJson, TypeDescriptionJson,
+};
+
+use super::{abi_diff_endpoints::normalized_type_name, AbiDiffReport};
+
+fn field_signature(fields: &[StructFieldDescriptionJson]) -> Vec<String> {
+    fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{}: {}",
+                field.name,
+                normalized_type_name(&field.field_type)
+            )
+        })
+        .collect()
+}
+
+/// Type layouts determine how stored values and arguments get decoded.
+///
+/// Any change to existing fields breaks decoding of previously stored data,
+/// even appending a field, since the old encoding will be too short for the new type.
+/// The exception are fields appended with `#[codec(since = ...)]`, which older encodings can omit.
+pub(super) fn compare_types(
+    old: &ContractAbiJson,
+    new: &ContractAbiJson,
+    report: &mut AbiDiffReport,
+) {
+    for (type_name, old_type) in &old.types {
+        let Some(new_type) = new.types.get(type_name) else {
+            report.info(format!("type '{type_name}' no longer used"));
+            continue;
+        };
+
+        if old_type.content_type != new_type.content_type {
+            report.breaking(format!(
+                "type '{type_name}' changed from {} to {}",
+                old_type.content_type, new_type.content_type
+            ));
+            continue;
+        }
+
+        compare_struct_fields(type_name, old_type, new_type, report);
+        compare_enum_variants(type_name, old_type, new_type, report);
+    }
+
+    for type_name in new.types.keys() {
+        if !old.types.contains_key(type_name) {
+            report.info(format!("type '{type_name}' added"));
+        }
+    }
+}
+
+fn compare_struct_fields(
+    type_name: &str,
+    old_type: &TypeDescriptionJson,
+    new_type: &TypeDescriptionJson,
+    report: &mut AbiDiffReport,
+) {
+    let old_fields = field_signature(&old_type.fields);
+    let new_fields = field_signature(&new_type.fields);
+    if old_fields == new_fields {
+        return;
+    }
+
+    let appended_fields = &new_type.fields[old_fields.len().min(new_fields.len())..];
+    if new_fields.starts_with(&old_fields)
+        && appended_fields.iter().all(|field| field.since.is_some())
+    {
+        report.info(format!(
+            "type '{type_name}' gained versioned fields {{{}}}",
+            field_signature(appended_fields).join(", ")
+        ));
+    } else {
+        report.breaking(format!(
+            "type '{type_name}' layout changed from {{{}}} to {{{}}}",
+            old_fields.join(", "),
+            new_fields.join(", ")
+        ));
+    }
+}
+
+fn variant_discriminant(variant: &EnumVariantDescriptionJson, index: usize) -> usize {
+    variant.discriminant.unwrap_or(index)
+}
+
+fn compare_enum_variants(
+    type_name: &str,
+    old_type: &TypeDescriptionJson,
+    new_type: &TypeDescriptionJson,
+    report: &mut AbiDiffReport,
+) {
+    for (old_index, old_variant) in old_type.variants.iter().enumerate() {
+        let Some((new_index, new_variant)) = new_type
+            .variants
+            .iter()
+            .enumerate()
+            .find(|(_, variant)| variant.name == old_variant.name)
+        else {
+            report.breaking(format!(
+                "type '{type_name}' variant '{}' removed",
+                old_variant.name
+            ));
+            continue;
+        };
+
+        let old_discriminant = variant_discriminant(old_variant, old_index);
+        let new_discriminant = variant_discriminant(new_variant, new_index);
+        if old_discriminant != new_discriminant {
+            report.breaking(format!(
+                "type '{type_name}' variant '{}' discriminant changed from {old_discriminant} to {new_discriminant}",
+                old_variant.name
+            ));
+        }
+
+        let old_fields = field_signature(&old_variant.fields);
+        let new_fields = field_signature(&new_variant.fields);
+        if old_fields != new_fields {
+            report.breaking(format!(
+                "type '{type_name}' variant '{}' fields changed from {{{}}} to {{{}}}",
+                old_variant.name,
+                old_fields.join(", "),
+                new_fields.join(", ")
+            ));
+        }
+    }
+
+    for new_variant in &new_type.variants {
+        if !old_type
+            .variants
+            .iter()
+            .any(|variant| variant.name == new_variant.name)
+        {
+            report.info(format!(
+                "type '{type_name}' variant '{}' added",
+                new_variant.name
+            ));
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::print_util::{print_ei_check_report, print_ei_check_summary};
+use crate::{
+    cli_args::EiCheckArgs,
+    ei::{EICompatibilityReport, EIVersion},
+    folder_structure::RelevantDirectories,
+    tools::extract_wasm_imports,
+};
+
+pub fn ei_check(args: &EiCheckArgs) {
+    let path = if let Some(some_path) = &args.path {
+        some_path.as_str()
+    } else {
+        "./"
+    };
+    let target = match &args.ei {
+        Some(ei_name) => {
+            EIVersion::from_name(ei_name).unwrap_or_else(|| panic!("invalid EI version: {ei_name}"))
+        },
+        None => EIVersion::default(),
+    };
+
+    let reports = check_ei_workspace(path, args.ignore.as_slice(), target);
+    for (wasm_path, report) in &reports {
+        print_ei_check_report(wasm_path, report, target);
+    }
+
+    let num_failed = reports.iter().filter(|(_, report)| !report.is_ok()).count();
+    print_ei_check_summary(reports.len(), num_failed, target);
+    if num_failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Checks the imports of all `.wasm` files found in the output folders of the contract crates.
+pub fn check_ei_workspace(
+    path: impl AsRef<Path>,
+    ignore: &[String],
+    target: EIVersion,
+) -> Vec<(PathBuf, EICompatibilityReport)> {
+    let dirs = RelevantDirectories::find_all(path, ignore);
+    dirs.iter_contract_crates()
+        .flat_map(|contract_crate| find_output_wasm_files(&contract_crate.path))
+        .map(|wasm_path| {
+            let import_names = extract_wasm_imports(wasm_path.to_string_lossy().as_ref());
+            let report = EICompatibilityReport::new(&import_names, target);
+            (wasm_path, report)
+        })
+        .collect()
+}
+
+fn find_output_wasm_files(contract_crate_path: &Path) -> Vec<PathBuf> {
+    let Ok(read_dir) = fs::read_dir(contract_crate_path.join("output")) else {
+        return Vec::new();
+    };
+    let mut wasm_paths: Vec<PathBuf> = read_dir
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().map_or(false, |ext| ext == "wasm"))
+        .collect();
+    wasm_paths.sort();
+    wasm_paths
+}
@@ -1,6 +1,8 @@
 use colored::Colorize;
 use std::path::Path;
 
+use crate::ei::{EICompatibilityReport, EIVersion};
+
 pub fn print_all_count(num_contract_crates: usize) {
     println!(
         "\n{}",
@@ -24,3 +26,44 @@ pub fn print_all_command(meta_path: &Path, cargo_run_args: &[String]) {
         cargo_run_args.join(" "),
     );
 }
+
+pub fn print_ei_check_report(wasm_path: &Path, report: &EICompatibilityReport, target: EIVersion) {
+    let min_version = report.min_version.map_or("-", |ei| ei.name());
+    let status = if report.is_ok() {
+        "OK".green()
+    } else {
+        "FAILED".red()
+    };
+    println!(
+        "{status} {} (minimum EI version: {min_version})",
+        wasm_path.display()
+    );
+    for import_name in &report.outside_target {
+        println!(
+            "    {}",
+            format!(
+                "VM hook '{import_name}' is not available on EI version {}",
+                target.name()
+            )
+            .yellow()
+        );
+    }
+    for import_name in &report.non_vm_hooks {
+        println!(
+            "    {}",
+            format!("import '{import_name}' is not a VM hook").red()
+        );
+    }
+}
+
+pub fn print_ei_check_summary(num_checked: usize, num_failed: usize, target: EIVersion) {
+    let summary = format!(
+        "\nChecked {num_checked} contract binaries against EI version {}, {num_failed} failed.",
+        target.name()
+    );
+    if num_failed == 0 {
+        println!("{}", summary.green());
+    } else {
+        println!("{}", summary.red());
+    }
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::{
     cli_args::TemplateArgs,
     version_history::{validate_template_tag, LAST_TEMPLATE_VERSION},
@@ -12,10 +14,10 @@ use super::{
 pub fn create_contract(args: &TemplateArgs) {
     let version = get_repo_version(&args.tag);
     let version_tag: String = version.get_tag();
-    let repo_temp_download = RepoSource::download_from_github(version, std::env::temp_dir());
+    let repo_source = get_repo_source(version, &args.repo_path, &args.template_dir);
     let target = target_from_args(args);
 
-    let creator = ContractCreator::new(&repo_temp_download, args.template.clone(), target, false);
+    let creator = ContractCreator::new(&repo_source, args.template.clone(), target, false);
 
     creator.create_contract(version_tag);
 }
@@ -38,6 +40,31 @@ pub(crate) fn get_repo_version(args_tag: &Option<String>) -> RepoVersion {
     }
 }
 
+/// Local sources take precedence, the repository only gets downloaded if none were specified.
+pub(crate) fn get_repo_source(
+    version: RepoVersion,
+    args_repo_path: &Option<PathBuf>,
+    args_template_dir: &Option<PathBuf>,
+) -> RepoSource {
+    if let Some(template_dir) = args_template_dir {
+        assert!(
+            template_dir.is_dir(),
+            "template directory {} not found",
+            template_dir.display()
+        );
+        RepoSource::from_template_dir(template_dir)
+    } else if let Some(repo_path) = args_repo_path {
+        assert!(
+            repo_path.is_dir(),
+            "repository path {} not found",
+            repo_path.display()
+        );
+        RepoSource::from_local_path(repo_path)
+    } else {
+        RepoSource::download_from_github(version, std::env::temp_dir())
+    }
+}
+
 /// Coordinates the creation of a new contract from a template.
 pub struct ContractCreator<'a> {
     pub repo_source: &'a RepoSource,
@@ -5,9 +5,14 @@ use std::{
 
 use super::{RepoTempDownload, RepoVersion};
 
+const TEMPLATES_PATH_IN_REPO: &str = "contracts/examples";
+
 pub enum RepoSource {
     Downloaded(RepoTempDownload),
     LocalPath(PathBuf),
+
+    /// A directory containing only templates, not necessarily a framework repository.
+    TemplateDir(PathBuf),
 }
 
 impl RepoSource {
@@ -23,12 +28,25 @@ impl RepoSource {
         RepoSource::LocalPath(repo_local_path.as_ref().to_path_buf())
     }
 
+    pub fn from_template_dir(template_dir_path: impl AsRef<Path>) -> Self {
+        RepoSource::TemplateDir(template_dir_path.as_ref().to_path_buf())
+    }
+
     pub fn repo_path(&self) -> PathBuf {
         match self {
             RepoSource::Downloaded(repo_temp_download) => {
                 repo_temp_download.repository_temp_dir_path()
             },
             RepoSource::LocalPath(local_path) => local_path.clone(),
+            RepoSource::TemplateDir(template_dir_path) => template_dir_path.clone(),
+        }
+    }
+
+    /// The folder where templates are searched.
+    pub fn templates_path(&self) -> PathBuf {
+        match self {
+            RepoSource::TemplateDir(template_dir_path) => template_dir_path.clone(),
+            _ => self.repo_path().join(TEMPLATES_PATH_IN_REPO),
         }
     }
 }
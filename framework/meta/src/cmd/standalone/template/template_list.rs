@@ -1,11 +1,15 @@
 use crate::cli_args::TemplateListArgs;
 
-use super::{contract_creator::get_repo_version, template_source::template_sources, RepoSource};
+use super::{
+    contract_creator::{get_repo_source, get_repo_version},
+    template_source::template_sources,
+    RepoSource,
+};
 
 pub fn print_template_names(args: &TemplateListArgs) {
     let version = get_repo_version(&args.tag);
-    let repo_temp_download = RepoSource::download_from_github(version, std::env::temp_dir());
-    let template_names = template_names_from_repo(&repo_temp_download);
+    let repo_source = get_repo_source(version, &args.repo_path, &args.template_dir);
+    let template_names = template_names_from_repo(&repo_source);
     for template_name in template_names {
         println!("{template_name}");
     }
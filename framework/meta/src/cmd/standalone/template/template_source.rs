@@ -7,7 +7,6 @@ use crate::folder_structure::RelevantDirectories;
 
 use super::{copy_util::whitelisted_deep_copy, template_metadata::TemplateMetadata, RepoSource};
 
-const TEMPLATES_PATH_IN_REPO: &str = "contracts/examples";
 const TEMPLATE_TOML_FILE_NAME: &str = "mxsc-template.toml";
 
 pub struct TemplateSource<'a> {
@@ -28,7 +27,7 @@ impl<'a> TemplateSource<'a> {
 }
 
 pub fn template_sources(repo_temp_dir: &RepoSource) -> Vec<TemplateSource<'_>> {
-    let templates_path = repo_temp_dir.repo_path().join(TEMPLATES_PATH_IN_REPO);
+    let templates_path = repo_temp_dir.templates_path();
     let dirs = RelevantDirectories::find_all(templates_path, &[]);
     let mut sources = Vec::new();
     for dir in dirs.iter_contract_crates() {
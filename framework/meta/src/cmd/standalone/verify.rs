@@ -0,0 +1,136 @@
+mod verify_build;
+mod verify_expected;
+mod verify_toolchain;
+mod verify_wasm_compare;
+
+pub use verify_build::{build_all_variants, load_built_variants, BuiltVariant};
+pub use verify_expected::{expected_code_from_gateway, expected_code_from_mxsc, ExpectedCode};
+pub use verify_toolchain::toolchain_from_rustc;
+pub use verify_wasm_compare::{compare_wasm, wasm_sections, CodeComparison, WasmSectionRange};
+
+use colored::Colorize;
+use std::path::Path;
+
+use crate::{cargo_toml_contents::CargoTomlContents, cli_args::VerifyArgs};
+
+const FRAMEWORK_CRATE_NAME: &str = "multiversx-sc";
+
+/// The comparison of the expected code with each of the built variants that were considered.
+pub struct VerifyReport {
+    pub results: Vec<(String, CodeComparison)>,
+}
+
+impl VerifyReport {
+    /// Verification succeeds if any of the considered variants is a byte-level match.
+    pub fn is_verified(&self) -> bool {
+        self.results
+            .iter()
+            .any(|(_, comparison)| comparison.is_identical())
+    }
+
+    pub fn print(&self) {
+        for (file_name, comparison) in &self.results {
+            match comparison {
+                CodeComparison::Identical => {
+                    println!("{} {file_name}", "Match:".green().bold());
+                },
+                CodeComparison::Different {
+                    offset,
+                    section,
+                    expected_size,
+                    actual_size,
+                } => {
+                    println!(
+                        "{} {file_name}: first difference at byte {offset}, in section {section} (expected {expected_size} bytes, built {actual_size} bytes)",
+                        "Mismatch:".red().bold(),
+                    );
+                },
+            }
+        }
+    }
+}
+
+/// Compares the expected code against the built variants.
+///
+/// If the expected code comes from an `.mxsc.json` file and the build produced a file with the same name,
+/// only that one is compared. Otherwise all variants are tried.
+pub fn verify_code(expected: &ExpectedCode, built_variants: &[BuiltVariant]) -> VerifyReport {
+    let same_name: Vec<&BuiltVariant> = built_variants
+        .iter()
+        .filter(|variant| Some(&variant.file_name) == expected.mxsc_file_name.as_ref())
+        .collect();
+    let candidates = if same_name.is_empty() {
+        built_variants.iter().collect()
+    } else {
+        same_name
+    };
+
+    VerifyReport {
+        results: candidates
+            .into_iter()
+            .map(|variant| {
+                (
+                    variant.file_name.clone(),
+                    compare_wasm(&expected.code, &variant.code),
+                )
+            })
+            .collect(),
+    }
+}
+
+fn check_framework_version(contract_path: &Path, expected: &ExpectedCode) {
+    let Some(expected_version) = &expected.framework_version else {
+        return;
+    };
+    let cargo_toml_path = contract_path.join("Cargo.toml");
+    if !cargo_toml_path.exists() {
+        return;
+    }
+
+    let cargo_toml_contents = CargoTomlContents::load_from_file(cargo_toml_path);
+    let source_version = match cargo_toml_contents.dependency(FRAMEWORK_CRATE_NAME) {
+        Some(toml::Value::String(version)) => version.clone(),
+        Some(toml::Value::Table(table)) => match table.get("version") {
+            Some(toml::Value::String(version)) => version.clone(),
+            _ => return,
+        },
+        _ => return,
+    };
+    if source_version.trim_start_matches('=') != expected_version {
+        println!(
+            "{} code was built with framework version {expected_version}, source depends on {source_version}",
+            "Warning:".yellow().bold(),
+        );
+    }
+}
+
+pub fn verify(args: &VerifyArgs) {
+    let contract_path = Path::new(args.path.as_deref().unwrap_or("./"));
+
+    let expected = if let Some(mxsc_path) = &args.mxsc {
+        expected_code_from_mxsc(mxsc_path)
+    } else if let Some(address) = &args.address {
+        expected_code_from_gateway(&args.gateway, address)
+    } else {
+        panic!("either --mxsc or --address must be provided");
+    };
+    println!("Verifying against {}", expected.source);
+
+    check_framework_version(contract_path, &expected);
+
+    if !args.no_build {
+        let toolchain = args
+            .toolchain
+            .clone()
+            .or_else(|| expected.rustc.as_ref().map(toolchain_from_rustc));
+        build_all_variants(contract_path, toolchain.as_deref());
+    }
+
+    let built_variants = load_built_variants(&contract_path.join("output"));
+    let report = verify_code(&expected, &built_variants);
+    report.print();
+
+    if !report.is_verified() {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,66 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use super::verify_expected::load_mxsc_file_json;
+use crate::cmd::standalone::print_util::print_all_command;
+
+/// A contract variant, as produced by the fresh build.
+pub struct BuiltVariant {
+    /// The `.mxsc.json` file name.
+    pub file_name: String,
+    pub code: Vec<u8>,
+}
+
+/// Builds all contract variants from `sc-config.toml`, using the given toolchain.
+pub fn build_all_variants(contract_path: &Path, toolchain: Option<&str>) {
+    let meta_path = contract_path.join("meta");
+    assert!(
+        meta_path.exists(),
+        "Contract meta crate not found at {}",
+        meta_path.display()
+    );
+
+    let mut args = Vec::new();
+    if let Some(toolchain) = toolchain {
+        args.push(format!("+{toolchain}"));
+    }
+    args.extend(["run", "build", "--locked"].map(str::to_string));
+
+    print_all_command(meta_path.as_path(), &args);
+
+    let exit_status = Command::new("cargo")
+        .current_dir(&meta_path)
+        .args(&args)
+        .spawn()
+        .expect("failed to spawn cargo run process in meta crate")
+        .wait()
+        .expect("cargo run process in meta crate was not running");
+
+    assert!(exit_status.success(), "contract build failed");
+}
+
+/// Loads all `.mxsc.json` files from a contract output folder, sorted by name.
+pub fn load_built_variants(output_path: &Path) -> Vec<BuiltVariant> {
+    let read_dir = fs::read_dir(output_path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", output_path.display()));
+    let mut mxsc_paths: Vec<PathBuf> = read_dir
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.to_string_lossy().ends_with(".mxsc.json"))
+        .collect();
+    mxsc_paths.sort();
+
+    mxsc_paths
+        .into_iter()
+        .map(|path| {
+            let mxsc_file_json = load_mxsc_file_json(&path);
+            BuiltVariant {
+                file_name: path.file_name().unwrap().to_string_lossy().to_string(),
+                code: hex::decode(mxsc_file_json.code)
+                    .unwrap_or_else(|err| panic!("invalid hex code in {}: {err}", path.display())),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,76 @@
+use std::{fs, path::Path};
+
+use multiversx_sdk::{blockchain::CommunicationProxy, data::address::Address};
+
+use crate::{abi_json::RustcAbiJson, mxsc_file_json::MxscFileJson};
+
+/// The code a fresh build is expected to reproduce, together with whatever is known about how it was built.
+pub struct ExpectedCode {
+    /// Human-readable description of where the code came from.
+    pub source: String,
+
+    /// When verifying against an `.mxsc.json` file, only the output with the same file name is checked.
+    pub mxsc_file_name: Option<String>,
+
+    pub code: Vec<u8>,
+
+    /// Only available when the code comes from an `.mxsc.json` file.
+    pub rustc: Option<RustcAbiJson>,
+
+    /// Only available when the code comes from an `.mxsc.json` file.
+    pub framework_version: Option<String>,
+}
+
+pub fn load_mxsc_file_json(path: impl AsRef<Path>) -> MxscFileJson {
+    let path = path.as_ref();
+    let contents = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+    serde_json::from_str(&contents)
+        .unwrap_or_else(|err| panic!("failed to parse {}: {err}", path.display()))
+}
+
+fn decode_hex_code(hex_code: &str, source: &str) -> Vec<u8> {
+    hex::decode(hex_code).unwrap_or_else(|err| panic!("invalid hex code in {source}: {err}"))
+}
+
+pub fn expected_code_from_mxsc(path: impl AsRef<Path>) -> ExpectedCode {
+    let path = path.as_ref();
+    let mxsc_file_json = load_mxsc_file_json(path);
+    let source = path.display().to_string();
+    ExpectedCode {
+        code: decode_hex_code(&mxsc_file_json.code, &source),
+        source,
+        mxsc_file_name: path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string()),
+        rustc: Some(mxsc_file_json.build_info.rustc),
+        framework_version: Some(mxsc_file_json.build_info.framework.version),
+    }
+}
+
+/// Retrieves the code of a deployed contract from a gateway.
+pub fn expected_code_from_gateway(gateway: &str, address_bech32: &str) -> ExpectedCode {
+    let address = Address::from_bech32_string(address_bech32)
+        .unwrap_or_else(|err| panic!("invalid address {address_bech32}: {err}"));
+    let proxy = CommunicationProxy::new(gateway.to_string());
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("failed to start tokio runtime");
+    let account = runtime
+        .block_on(proxy.get_account(&address))
+        .unwrap_or_else(|err| panic!("failed to retrieve account {address_bech32}: {err}"));
+    assert!(
+        !account.code.is_empty(),
+        "account {address_bech32} has no code"
+    );
+
+    let source = format!("{address_bech32} at {gateway}");
+    ExpectedCode {
+        code: decode_hex_code(&account.code, &source),
+        source,
+        mxsc_file_name: None,
+        rustc: None,
+        framework_version: None,
+    }
+}
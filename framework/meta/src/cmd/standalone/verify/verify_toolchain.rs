@@ -0,0 +1,37 @@
+use chrono::NaiveDate;
+
+use crate::abi_json::RustcAbiJson;
+
+/// The rustup toolchain that produced the recorded compiler.
+///
+/// Nightly toolchains are named after their release date, which is usually the day after the commit date.
+pub fn toolchain_from_rustc(rustc: &RustcAbiJson) -> String {
+    match rustc.channel.as_str() {
+        "Nightly" => format!("nightly-{}", next_day(&rustc.commit_date)),
+        "Beta" => format!("beta-{}", next_day(&rustc.commit_date)),
+        _ => rustc.version.clone(),
+    }
+}
+
+/// Works on `YYYY-MM-DD` dates, returns the input unchanged if it cannot be parsed.
+fn next_day(date: &str) -> String {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.succ_opt())
+        .map_or_else(|| date.to_string(), |next| next.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_day() {
+        assert_eq!(next_day("2023-12-10"), "2023-12-11");
+        assert_eq!(next_day("2023-12-31"), "2024-01-01");
+        assert_eq!(next_day("2024-02-28"), "2024-02-29");
+        assert_eq!(next_day("2023-02-28"), "2023-03-01");
+        assert_eq!(next_day("2023-02-30"), "2023-02-30");
+        assert_eq!(next_day("unknown"), "unknown");
+    }
+}
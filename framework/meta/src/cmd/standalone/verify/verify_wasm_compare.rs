@@ -0,0 +1,105 @@
+use wasmparser::{Parser, Payload};
+
+/// The location of a section in the wasm binary.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmSectionRange {
+    pub name: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Outcome of comparing a freshly built contract with the expected bytecode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CodeComparison {
+    Identical,
+    Different {
+        /// Offset of the first differing byte.
+        offset: usize,
+
+        /// The section of the expected code that contains the first difference.
+        section: String,
+
+        expected_size: usize,
+        actual_size: usize,
+    },
+}
+
+impl CodeComparison {
+    pub fn is_identical(&self) -> bool {
+        matches!(self, CodeComparison::Identical)
+    }
+}
+
+fn section_name(payload: &Payload) -> Option<String> {
+    let name = match payload {
+        Payload::Version { .. } => "header".to_string(),
+        Payload::CustomSection(reader) => format!("custom \"{}\"", reader.name()),
+        Payload::TypeSection(_) => "type".to_string(),
+        Payload::ImportSection(_) => "import".to_string(),
+        Payload::FunctionSection(_) => "function".to_string(),
+        Payload::TableSection(_) => "table".to_string(),
+        Payload::MemorySection(_) => "memory".to_string(),
+        Payload::GlobalSection(_) => "global".to_string(),
+        Payload::ExportSection(_) => "export".to_string(),
+        Payload::StartSection { .. } => "start".to_string(),
+        Payload::ElementSection(_) => "element".to_string(),
+        Payload::CodeSectionStart { .. } => "code".to_string(),
+        Payload::DataSection(_) => "data".to_string(),
+        Payload::DataCountSection { .. } => "data count".to_string(),
+        Payload::UnknownSection { id, .. } => format!("unknown ({id})"),
+        _ => payload
+            .as_section()
+            .map(|(id, _)| format!("other ({id})"))?,
+    };
+    Some(name)
+}
+
+fn payload_end(payload: &Payload) -> Option<usize> {
+    match payload {
+        Payload::Version { range, .. } => Some(range.end),
+        _ => payload.as_section().map(|(_, range)| range.end),
+    }
+}
+
+/// Splits the wasm binary into its sections, without validating their contents.
+///
+/// Each section range includes its id and size. Stops at the first malformed section.
+pub fn wasm_sections(code: &[u8]) -> Vec<WasmSectionRange> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+    for payload in Parser::new(0).parse_all(code) {
+        let Ok(payload) = payload else {
+            break;
+        };
+        if let (Some(name), Some(end)) = (section_name(&payload), payload_end(&payload)) {
+            sections.push(WasmSectionRange { name, start, end });
+            start = end;
+        }
+    }
+    sections
+}
+
+/// Byte-level comparison, reporting the first difference in terms of wasm sections.
+pub fn compare_wasm(expected: &[u8], actual: &[u8]) -> CodeComparison {
+    if expected == actual {
+        return CodeComparison::Identical;
+    }
+
+    let offset = expected
+        .iter()
+        .zip(actual.iter())
+        .position(|(e, a)| e != a)
+        .unwrap_or_else(|| expected.len().min(actual.len()));
+    let section = wasm_sections(expected)
+        .into_iter()
+        .find(|section| section.start <= offset && offset < section.end)
+        .map(|section| section.name)
+        .unwrap_or_else(|| "end of code".to_string());
+
+    CodeComparison::Different {
+        offset,
+        section,
+        expected_size: expected.len(),
+        actual_size: actual.len(),
+    }
+}
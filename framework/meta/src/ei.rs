@@ -2,10 +2,12 @@ mod ei_1_0;
 mod ei_1_1;
 mod ei_1_2;
 mod ei_1_3;
+mod ei_compatibility;
 mod ei_version;
 
 pub use ei_1_0::EI_1_0_NAMES;
 pub use ei_1_1::EI_1_1_NAMES;
 pub use ei_1_2::EI_1_2_NAMES;
 pub use ei_1_3::EI_1_3_NAMES;
+pub use ei_compatibility::EICompatibilityReport;
 pub use ei_version::EIVersion;
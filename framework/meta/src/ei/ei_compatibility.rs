@@ -0,0 +1,42 @@
+use super::EIVersion;
+
+/// The result of checking the imports of a contract against the known EI versions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EICompatibilityReport {
+    /// The oldest EI version that provides all imported VM hooks.
+    ///
+    /// `None` if there are no imports at all.
+    pub min_version: Option<EIVersion>,
+
+    /// VM hooks that are imported, but are not available on the target EI version.
+    pub outside_target: Vec<String>,
+
+    /// Imports that are not VM hooks in any EI version.
+    ///
+    /// These are usually functions pulled in accidentally from `std` and would fail on deploy.
+    pub non_vm_hooks: Vec<String>,
+}
+
+impl EICompatibilityReport {
+    pub fn new(import_names: &[String], target: EIVersion) -> Self {
+        let mut report = EICompatibilityReport::default();
+        for import_name in import_names {
+            match EIVersion::first_containing_vm_hook(import_name) {
+                Some(ei) => {
+                    if report.min_version.map_or(true, |min| min < ei) {
+                        report.min_version = Some(ei);
+                    }
+                    if !target.contains_vm_hook(import_name) {
+                        report.outside_target.push(import_name.clone());
+                    }
+                },
+                None => report.non_vm_hooks.push(import_name.clone()),
+            }
+        }
+        report
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.outside_target.is_empty() && self.non_vm_hooks.is_empty()
+    }
+}
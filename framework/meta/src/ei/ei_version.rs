@@ -1,7 +1,7 @@
 /// The version of the SC environment interface (EI), it deals with the VM hooks available at a certain point in time.
 ///
 /// It is not tied to the version of the VM, hence the different numbering.
-#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum EIVersion {
     /// This is not necessarily the first version of the EI,
     /// but rather the oldest version when we started keeping track of the EI.
@@ -31,6 +31,14 @@ pub enum EIVersion {
 }
 
 impl EIVersion {
+    /// All known versions, from oldest to newest.
+    pub const ALL: &'static [EIVersion] = &[
+        EIVersion::V1_0,
+        EIVersion::V1_1,
+        EIVersion::V1_2,
+        EIVersion::V1_3,
+    ];
+
     pub fn from_name(name: &str) -> Option<Self> {
         match name {
             "1.0" => Some(EIVersion::V1_0),
@@ -62,4 +70,12 @@ impl EIVersion {
     pub fn contains_vm_hook(&self, vm_hook_names: &str) -> bool {
         self.vm_hook_names().contains(&vm_hook_names)
     }
+
+    /// The oldest version that contains the VM hook, if any.
+    pub fn first_containing_vm_hook(vm_hook_name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|ei| ei.contains_vm_hook(vm_hook_name))
+    }
 }
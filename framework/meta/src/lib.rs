@@ -1,4 +1,5 @@
 pub mod abi_json;
+pub mod abi_value;
 mod cargo_toml_contents;
 pub mod cli_args;
 pub mod cmd;
@@ -7,8 +8,9 @@ pub mod esdt_attr_file_json;
 pub mod folder_structure;
 mod mxsc_file_json;
 mod print_util;
+pub mod storage_decoder;
 mod tools;
-pub use tools::find_workspace;
+pub use tools::{find_workspace, wasm_analysis};
 pub mod version_history;
 
 #[macro_use]
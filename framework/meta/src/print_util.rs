@@ -2,6 +2,8 @@ use std::process::Command;
 
 use colored::Colorize;
 
+use crate::{cmd::contract::sc_config::ContractSizeReport, tools::WasmAnalysis};
+
 pub fn format_command(command: &Command) -> String {
     let mut result = String::new();
     for (key, opt_value) in command.get_envs() {
@@ -55,10 +57,45 @@ pub fn print_pack_mxsc_file(output_mxsc_path: &str) {
     println!("{}", format!("Packing {output_mxsc_path} ...").green(),);
 }
 
+pub fn print_generate_ts(ts_path: &str) {
+    println!(
+        "{}",
+        format!("Generating TypeScript bindings {ts_path} ...").green(),
+    );
+}
+
 pub fn print_contract_size(size: usize) {
     println!("{}", format!("Contract size: {size} bytes.").blue(),);
 }
 
+pub fn print_size_report(size_report_path: &str, size_report: &ContractSizeReport) {
+    println!(
+        "{}",
+        format!("Writing size report to {size_report_path} ...").green(),
+    );
+    if let Some(growth_percent) = size_report.growth_percent {
+        println!(
+            "{}",
+            format!(
+                "Size change from baseline: {:+.2}% ({} bytes before).",
+                growth_percent,
+                size_report.baseline_size.unwrap_or_default()
+            )
+            .blue(),
+        );
+    }
+    for violation in size_report.violations() {
+        println!("{}", format!("ERROR! {violation}.").red());
+    }
+}
+
+pub fn print_update_size_baseline(size_baseline_path: &str) {
+    println!(
+        "{}",
+        format!("Updating size baseline {size_baseline_path} ...").green(),
+    );
+}
+
 pub fn print_extract_imports(imports_path: &str) {
     println!(
         "{}",
@@ -96,3 +133,85 @@ pub fn print_workspace_target_dir(target_path_str: &str) {
         format!("Using workspace target directory: {target_path_str} ...").green()
     );
 }
+
+pub fn print_analyze_wasm(wasm_path: &str) {
+    print!("{}", format!("Analyzing {wasm_path} ...").green());
+}
+
+fn format_instruction_counts(counts: &std::collections::BTreeMap<String, usize>) -> String {
+    counts
+        .iter()
+        .map(|(name, count)| format!("{name} x{count}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn print_wasm_analysis(analysis: &WasmAnalysis) {
+    if !analysis.has_issues() {
+        println!("{}", " OK".green());
+        return;
+    }
+
+    println!();
+    if !analysis.float_instructions.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "WARNING! Float instructions: {}",
+                format_instruction_counts(&analysis.float_instructions)
+            )
+            .yellow()
+        );
+    }
+    if !analysis.bulk_memory_instructions.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "WARNING! Bulk memory instructions, not supported by the VM: {}",
+                format_instruction_counts(&analysis.bulk_memory_instructions)
+            )
+            .yellow()
+        );
+    }
+    if analysis.memory_grow_count > 0 {
+        println!(
+            "{}",
+            format!(
+                "WARNING! memory.grow used {} time(s), check the allocator",
+                analysis.memory_grow_count
+            )
+            .yellow()
+        );
+    }
+    for size in &analysis.large_data_segments {
+        println!(
+            "{}",
+            format!("WARNING! Large data segment: {size} bytes").yellow()
+        );
+    }
+    if !analysis.panic_fmt_markers.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "WARNING! Panic formatting code detected, found strings: {}",
+                analysis
+                    .panic_fmt_markers
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+            .yellow()
+        );
+    }
+    if !analysis.non_endpoint_exports.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "WARNING! Exported functions that are not endpoints: {}",
+                analysis.non_endpoint_exports.join(", ")
+            )
+            .yellow()
+        );
+    }
+}
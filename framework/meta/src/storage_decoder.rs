@@ -0,0 +1,200 @@
+mod decoded_storage;
+mod storage_mapper_layout;
+
+pub use decoded_storage::{DecodedStorage, DecodedStorageEntry, UndecodedStorageEntry};
+
+use storage_mapper_layout::{
+    storage_mapper_layout, StorageItemLayout, StorageSubKeyLayout, StorageValueLayout,
+};
+
+use crate::{
+    abi_json::{ContractAbiJson, StorageAbiJson},
+    abi_value::{AbiValue, AbiValueDecoder},
+};
+
+/// Storage keys reserved by the protocol, they do not belong to the contract.
+const RESERVED_KEY_PREFIX: &[u8] = b"ELROND";
+
+/// Turns raw contract storage into typed entries, based on the storage declarations in the ABI.
+///
+/// Reverses the key structure of the storage mappers (e.g. `VecMapper` `.len`/`.item`,
+/// `MapMapper` `.mapped`) and decodes both the key arguments and the values.
+pub struct StorageDecoder<'a> {
+    /// Longest keys first, so that keys that are prefixes of other keys do not shadow them.
+    storage: Vec<&'a StorageAbiJson>,
+    value_decoder: AbiValueDecoder<'a>,
+}
+
+/// Key arguments, item and sub-key layout, as matched from a raw key.
+struct MatchedKey<'a> {
+    key_args: Vec<AbiValue>,
+    sub_key: StorageSubKeyLayout,
+    item: Option<AbiValue>,
+    storage: &'a StorageAbiJson,
+}
+
+impl<'a> StorageDecoder<'a> {
+    pub fn new(abi: &'a ContractAbiJson) -> Self {
+        let mut storage: Vec<&StorageAbiJson> = Vec::new();
+        for declaration in &abi.storage {
+            // a key can be declared several times, e.g. by a getter and a setter
+            if storage
+                .iter()
+                .all(|existing| existing.key != declaration.key)
+            {
+                storage.push(declaration);
+            }
+        }
+        storage.sort_by_key(|declaration| std::cmp::Reverse(declaration.key.len()));
+
+        StorageDecoder {
+            storage,
+            value_decoder: AbiValueDecoder::new(&abi.types),
+        }
+    }
+
+    /// Decodes all entries, in the order of the raw keys.
+    pub fn decode_storage<'s, I>(&self, raw_storage: I) -> DecodedStorage
+    where
+        I: IntoIterator<Item = (&'s [u8], &'s [u8])>,
+    {
+        let mut raw_entries: Vec<(&[u8], &[u8])> = raw_storage
+            .into_iter()
+            .filter(|(key, _)| !key.starts_with(RESERVED_KEY_PREFIX))
+            .collect();
+        raw_entries.sort();
+
+        let mut result = DecodedStorage::default();
+        for (key, value) in raw_entries {
+            if let Some(entry) = self.decode_entry(key, value) {
+                result.entries.push(entry);
+            } else {
+                result.undecoded.push(UndecodedStorageEntry {
+                    raw_key: format!("0x{}", hex::encode(key)),
+                    raw_value: format!("0x{}", hex::encode(value)),
+                });
+            }
+        }
+        result
+    }
+
+    /// Decodes a single entry, `None` if the key does not match any declaration.
+    pub fn decode_entry(&self, key: &[u8], value: &[u8]) -> Option<DecodedStorageEntry> {
+        let matched = self
+            .storage
+            .iter()
+            .find_map(|storage| self.match_key(storage, key))?;
+
+        let raw_value = format!("0x{}", hex::encode(value));
+        let (decoded_value, value_error) = match self.decode_value(&matched, value) {
+            Ok(decoded) => (decoded, None),
+            Err(err) => (AbiValue::String(raw_value.clone()), Some(err)),
+        };
+
+        Some(DecodedStorageEntry {
+            storage_key: matched.storage.key.clone(),
+            mapper: matched.storage.mapper.clone(),
+            key_args: matched.key_args,
+            suffix: matched.sub_key.suffix.to_string(),
+            item: matched.item,
+            value: decoded_value,
+            value_error,
+            raw_key: format!("0x{}", hex::encode(key)),
+            raw_value,
+        })
+    }
+
+    fn match_key(&self, storage: &'a StorageAbiJson, key: &[u8]) -> Option<MatchedKey<'a>> {
+        let mut rest = key.strip_prefix(storage.key.as_bytes())?;
+
+        let mut key_args = Vec::new();
+        for key_arg in &storage.key_args {
+            let arg_value = self
+                .value_decoder
+                .decode_nested(&key_arg.type_name, &mut rest)
+                .ok()?;
+            key_args.push(arg_value);
+        }
+
+        storage_mapper_layout(&storage.mapper)?
+            .into_iter()
+            .find_map(|sub_key| {
+                let mut item_bytes = rest.strip_prefix(sub_key.suffix.as_bytes())?;
+                let item = self.decode_key_item(storage, &sub_key.item, &mut item_bytes)?;
+                if !item_bytes.is_empty() {
+                    return None;
+                }
+                Some((sub_key, item))
+            })
+            .map(|(sub_key, item)| MatchedKey {
+                key_args,
+                sub_key,
+                item,
+                storage,
+            })
+    }
+
+    /// Yields `Some(None)` when no item is expected, `None` if decoding fails.
+    fn decode_key_item(
+        &self,
+        storage: &StorageAbiJson,
+        item_layout: &StorageItemLayout,
+        input: &mut &[u8],
+    ) -> Option<Option<AbiValue>> {
+        let item_type = match item_layout {
+            StorageItemLayout::None => return Some(None),
+            StorageItemLayout::Index => "u32",
+            StorageItemLayout::ValueType(index) => storage.value_types.get(*index)?,
+        };
+        self.value_decoder
+            .decode_nested(item_type, input)
+            .ok()
+            .map(Some)
+    }
+
+    fn decode_value(&self, matched: &MatchedKey, value: &[u8]) -> Result<AbiValue, String> {
+        let value_type = |index: usize| {
+            matched
+                .storage
+                .value_types
+                .get(index)
+                .map(String::as_str)
+                .ok_or_else(|| format!("missing value type in {}", matched.storage.mapper))
+        };
+
+        match &matched.sub_key.value {
+            StorageValueLayout::Fixed(type_name) => self.value_decoder.decode_top(type_name, value),
+            StorageValueLayout::ValueType(index) => {
+                self.value_decoder.decode_top(value_type(*index)?, value)
+            },
+            StorageValueLayout::U32Fields(field_names) => self.decode_all_fields(
+                field_names.iter().map(|field_name| (*field_name, "u32")),
+                value,
+            ),
+            StorageValueLayout::LinkedListNode(index) => self.decode_all_fields(
+                [
+                    ("value", value_type(*index)?),
+                    ("node_id", "u32"),
+                    ("next_id", "u32"),
+                    ("prev_id", "u32"),
+                ],
+                value,
+            ),
+        }
+    }
+
+    fn decode_all_fields<'n>(
+        &self,
+        fields: impl IntoIterator<Item = (&'n str, &'n str)>,
+        value: &[u8],
+    ) -> Result<AbiValue, String> {
+        let mut input = value;
+        let result = self
+            .value_decoder
+            .decode_nested_fields(fields, &mut input)?;
+        if !input.is_empty() {
+            return Err(format!("{} leftover bytes", input.len()));
+        }
+        Ok(result)
+    }
+}
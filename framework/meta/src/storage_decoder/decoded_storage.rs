@@ -0,0 +1,76 @@
+use serde::Serialize;
+
+use crate::abi_value::AbiValue;
+
+/// A raw storage entry, matched to a storage declaration from the ABI.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedStorageEntry {
+    /// The base key, as declared in the contract.
+    pub storage_key: String,
+
+    pub mapper: String,
+
+    /// The values of the storage method arguments, in order.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub key_args: Vec<AbiValue>,
+
+    /// Mapper-specific key suffix, e.g. `.item` or `.mapped`. Empty for single values.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub suffix: String,
+
+    /// The index or item following the suffix, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<AbiValue>,
+
+    /// The decoded value, or the raw hex value if it could not be decoded.
+    pub value: AbiValue,
+
+    /// Explains why the value could not be decoded, if that is the case.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_error: Option<String>,
+
+    pub raw_key: String,
+
+    pub raw_value: String,
+}
+
+/// A raw storage entry that does not match any declaration in the ABI.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UndecodedStorageEntry {
+    pub raw_key: String,
+    pub raw_value: String,
+}
+
+/// The result of decoding the full storage of a contract.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedStorage {
+    pub entries: Vec<DecodedStorageEntry>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub undecoded: Vec<UndecodedStorageEntry>,
+}
+
+impl DecodedStorage {
+    /// All entries belonging to a storage declaration.
+    pub fn entries_for_key<'a>(
+        &'a self,
+        storage_key: &'a str,
+    ) -> impl Iterator<Item = &'a DecodedStorageEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.storage_key == storage_key)
+    }
+
+    pub fn to_json_string(&self) -> String {
+        let buf = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+        let mut ser = serde_json::Serializer::with_formatter(buf, formatter);
+        self.serialize(&mut ser).unwrap();
+        let mut serialized = String::from_utf8(ser.into_inner()).unwrap();
+        serialized.push('\n');
+        serialized
+    }
+}
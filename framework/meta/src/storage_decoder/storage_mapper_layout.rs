@@ -0,0 +1,139 @@
+/// What follows the suffix in a storage key.
+pub(super) enum StorageItemLayout {
+    None,
+    /// A `u32` index or node id.
+    Index,
+    /// An item of one of the declared value types.
+    ValueType(usize),
+}
+
+/// How the value stored under a key is encoded.
+pub(super) enum StorageValueLayout {
+    /// Fixed type, independent of the declaration.
+    Fixed(&'static str),
+    /// One of the declared value types.
+    ValueType(usize),
+    /// Internal mapper records, consisting of `u32` fields.
+    U32Fields(&'static [&'static str]),
+    /// `LinkedListMapper` node, containing a value of one of the declared types.
+    LinkedListNode(usize),
+}
+
+/// One of the keys a storage mapper writes, relative to its base key.
+pub(super) struct StorageSubKeyLayout {
+    pub suffix: &'static str,
+    pub item: StorageItemLayout,
+    pub value: StorageValueLayout,
+}
+
+impl StorageSubKeyLayout {
+    fn new(suffix: &'static str, item: StorageItemLayout, value: StorageValueLayout) -> Self {
+        StorageSubKeyLayout {
+            suffix,
+            item,
+            value,
+        }
+    }
+}
+
+const QUEUE_INFO_FIELDS: &[&str] = &["len", "front", "back", "new"];
+const QUEUE_NODE_FIELDS: &[&str] = &["previous", "next"];
+
+fn vec_mapper_layout() -> Vec<StorageSubKeyLayout> {
+    vec![
+        StorageSubKeyLayout::new(
+            ".len",
+            StorageItemLayout::None,
+            StorageValueLayout::Fixed("u32"),
+        ),
+        StorageSubKeyLayout::new(
+            ".item",
+            StorageItemLayout::Index,
+            StorageValueLayout::ValueType(0),
+        ),
+    ]
+}
+
+fn queue_mapper_layout() -> Vec<StorageSubKeyLayout> {
+    vec![
+        StorageSubKeyLayout::new(
+            ".info",
+            StorageItemLayout::None,
+            StorageValueLayout::U32Fields(QUEUE_INFO_FIELDS),
+        ),
+        StorageSubKeyLayout::new(
+            ".node_links",
+            StorageItemLayout::Index,
+            StorageValueLayout::U32Fields(QUEUE_NODE_FIELDS),
+        ),
+        StorageSubKeyLayout::new(
+            ".value",
+            StorageItemLayout::Index,
+            StorageValueLayout::ValueType(0),
+        ),
+    ]
+}
+
+fn set_mapper_layout() -> Vec<StorageSubKeyLayout> {
+    let mut layout = queue_mapper_layout();
+    layout.push(StorageSubKeyLayout::new(
+        ".node_id",
+        StorageItemLayout::ValueType(0),
+        StorageValueLayout::Fixed("u32"),
+    ));
+    layout
+}
+
+/// Mirrors the key structure of the storage mappers in `multiversx_sc::storage::mappers`.
+///
+/// Returns `None` for mappers with an unknown layout.
+pub(super) fn storage_mapper_layout(mapper: &str) -> Option<Vec<StorageSubKeyLayout>> {
+    match mapper {
+        "SingleValueMapper" | "storage_get" | "storage_set" => {
+            Some(vec![StorageSubKeyLayout::new(
+                "",
+                StorageItemLayout::None,
+                StorageValueLayout::ValueType(0),
+            )])
+        },
+        "VecMapper" => Some(vec_mapper_layout()),
+        "UnorderedSetMapper" => {
+            let mut layout = vec_mapper_layout();
+            layout.push(StorageSubKeyLayout::new(
+                ".index",
+                StorageItemLayout::ValueType(0),
+                StorageValueLayout::Fixed("u32"),
+            ));
+            Some(layout)
+        },
+        "QueueMapper" => Some(queue_mapper_layout()),
+        "SetMapper" => Some(set_mapper_layout()),
+        "MapMapper" => {
+            let mut layout = set_mapper_layout();
+            layout.push(StorageSubKeyLayout::new(
+                ".mapped",
+                StorageItemLayout::ValueType(0),
+                StorageValueLayout::ValueType(1),
+            ));
+            Some(layout)
+        },
+        "LinkedListMapper" => Some(vec![
+            StorageSubKeyLayout::new(
+                ".info",
+                StorageItemLayout::None,
+                StorageValueLayout::U32Fields(QUEUE_INFO_FIELDS),
+            ),
+            StorageSubKeyLayout::new(
+                ".node",
+                StorageItemLayout::Index,
+                StorageValueLayout::LinkedListNode(0),
+            ),
+        ]),
+        "WhitelistMapper" => Some(vec![StorageSubKeyLayout::new(
+            "",
+            StorageItemLayout::ValueType(0),
+            StorageValueLayout::Fixed("bool"),
+        )]),
+        _ => None,
+    }
+}
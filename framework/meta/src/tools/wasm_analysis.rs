@@ -0,0 +1,225 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+};
+use wasmparser::{BinaryReaderError, ExternalKind, Operator, Parser, Payload};
+
+/// Data segments larger than this are flagged, they usually come from large constants or lookup tables.
+pub const LARGE_DATA_SEGMENT_SIZE: usize = 16 * 1024;
+
+/// Strings that only end up in the binary when the panic formatting machinery from `core` is linked in.
+const PANIC_FMT_MARKERS: &[&[u8]] = &[
+    b"panicked at",
+    b"called `Option::unwrap()` on a `None` value",
+    b"called `Result::unwrap()` on an `Err` value",
+    b"index out of bounds: the len is",
+];
+
+/// The result of a static analysis pass over a contract binary.
+///
+/// Instruction counts are grouped by instruction name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WasmAnalysis {
+    /// `f32`/`f64` instructions, which are not deterministic and are rejected by the VM.
+    pub float_instructions: BTreeMap<String, usize>,
+
+    /// Bulk memory instructions, which the VM does not support.
+    pub bulk_memory_instructions: BTreeMap<String, usize>,
+
+    /// Number of `memory.grow` instructions, a sign that the allocator can leak.
+    pub memory_grow_count: usize,
+
+    /// Sizes of the data segments over [`LARGE_DATA_SEGMENT_SIZE`].
+    pub large_data_segments: Vec<usize>,
+
+    /// Panic message strings found in the data segments.
+    pub panic_fmt_markers: BTreeSet<String>,
+
+    /// Exported functions that do not correspond to any endpoint.
+    pub non_endpoint_exports: Vec<String>,
+}
+
+impl WasmAnalysis {
+    pub fn has_issues(&self) -> bool {
+        !self.float_instructions.is_empty()
+            || !self.bulk_memory_instructions.is_empty()
+            || self.memory_grow_count > 0
+            || !self.large_data_segments.is_empty()
+            || !self.panic_fmt_markers.is_empty()
+            || !self.non_endpoint_exports.is_empty()
+    }
+}
+
+/// Analyzes a .wasm file on the disk.
+///
+/// The `endpoint_names` are the only expected function exports.
+pub fn analyze_wasm_file(output_wasm_path: &str, endpoint_names: &[String]) -> WasmAnalysis {
+    let wasm_data =
+        fs::read(output_wasm_path).expect("error occured while analyzing .wasm: file not found");
+
+    analyze_wasm(&wasm_data, endpoint_names).expect("error occured while analyzing .wasm")
+}
+
+pub fn analyze_wasm(
+    wasm_data: &[u8],
+    endpoint_names: &[String],
+) -> Result<WasmAnalysis, BinaryReaderError> {
+    let mut analysis = WasmAnalysis::default();
+
+    let parser = Parser::new(0);
+    for payload in parser.parse_all(wasm_data) {
+        match payload? {
+            Payload::ExportSection(export_section) => {
+                for export in export_section {
+                    let export = export?;
+                    if export.kind == ExternalKind::Func
+                        && !endpoint_names.iter().any(|name| name == export.name)
+                    {
+                        analysis.non_endpoint_exports.push(export.name.to_string());
+                    }
+                }
+            },
+            Payload::DataSection(data_section) => {
+                for data in data_section {
+                    let data = data?;
+                    if data.data.len() > LARGE_DATA_SEGMENT_SIZE {
+                        analysis.large_data_segments.push(data.data.len());
+                    }
+                    for marker in PANIC_FMT_MARKERS {
+                        if contains_bytes(data.data, marker) {
+                            analysis
+                                .panic_fmt_markers
+                                .insert(String::from_utf8_lossy(marker).to_string());
+                        }
+                    }
+                }
+            },
+            Payload::CodeSectionEntry(body) => {
+                let mut operators = body.get_operators_reader()?;
+                while !operators.eof() {
+                    analyze_operator(&operators.read()?, &mut analysis);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    Ok(analysis)
+}
+
+fn analyze_operator(operator: &Operator, analysis: &mut WasmAnalysis) {
+    match operator {
+        Operator::MemoryGrow { .. } => analysis.memory_grow_count += 1,
+        Operator::MemoryCopy { .. }
+        | Operator::MemoryFill { .. }
+        | Operator::MemoryInit { .. }
+        | Operator::DataDrop { .. }
+        | Operator::TableCopy { .. }
+        | Operator::TableInit { .. }
+        | Operator::ElemDrop { .. } => {
+            *analysis
+                .bulk_memory_instructions
+                .entry(operator_name(operator))
+                .or_default() += 1;
+        },
+        // loads/stores, constants, arithmetic, comparisons and conversions to and from floats
+        Operator::F32Load { .. }
+        | Operator::F64Load { .. }
+        | Operator::F32Store { .. }
+        | Operator::F64Store { .. }
+        | Operator::F32Const { .. }
+        | Operator::F64Const { .. }
+        | Operator::F32Eq
+        | Operator::F32Ne
+        | Operator::F32Lt
+        | Operator::F32Gt
+        | Operator::F32Le
+        | Operator::F32Ge
+        | Operator::F64Eq
+        | Operator::F64Ne
+        | Operator::F64Lt
+        | Operator::F64Gt
+        | Operator::F64Le
+        | Operator::F64Ge
+        | Operator::F32Abs
+        | Operator::F32Neg
+        | Operator::F32Ceil
+        | Operator::F32Floor
+        | Operator::F32Trunc
+        | Operator::F32Nearest
+        | Operator::F32Sqrt
+        | Operator::F32Add
+        | Operator::F32Sub
+        | Operator::F32Mul
+        | Operator::F32Div
+        | Operator::F32Min
+        | Operator::F32Max
+        | Operator::F32Copysign
+        | Operator::F64Abs
+        | Operator::F64Neg
+        | Operator::F64Ceil
+        | Operator::F64Floor
+        | Operator::F64Trunc
+        | Operator::F64Nearest
+        | Operator::F64Sqrt
+        | Operator::F64Add
+        | Operator::F64Sub
+        | Operator::F64Mul
+        | Operator::F64Div
+        | Operator::F64Min
+        | Operator::F64Max
+        | Operator::F64Copysign
+        | Operator::I32TruncF32S
+        | Operator::I32TruncF32U
+        | Operator::I32TruncF64S
+        | Operator::I32TruncF64U
+        | Operator::I64TruncF32S
+        | Operator::I64TruncF32U
+        | Operator::I64TruncF64S
+        | Operator::I64TruncF64U
+        | Operator::F32ConvertI32S
+        | Operator::F32ConvertI32U
+        | Operator::F32ConvertI64S
+        | Operator::F32ConvertI64U
+        | Operator::F32DemoteF64
+        | Operator::F64ConvertI32S
+        | Operator::F64ConvertI32U
+        | Operator::F64ConvertI64S
+        | Operator::F64ConvertI64U
+        | Operator::F64PromoteF32
+        | Operator::I32ReinterpretF32
+        | Operator::I64ReinterpretF64
+        | Operator::F32ReinterpretI32
+        | Operator::F64ReinterpretI64
+        | Operator::I32TruncSatF32S
+        | Operator::I32TruncSatF32U
+        | Operator::I32TruncSatF64S
+        | Operator::I32TruncSatF64U
+        | Operator::I64TruncSatF32S
+        | Operator::I64TruncSatF32U
+        | Operator::I64TruncSatF64S
+        | Operator::I64TruncSatF64U => {
+            *analysis
+                .float_instructions
+                .entry(operator_name(operator))
+                .or_default() += 1;
+        },
+        _ => {},
+    }
+}
+
+/// The name of the operator variant, e.g. `F64Add`.
+fn operator_name(operator: &Operator) -> String {
+    let debug = format!("{operator:?}");
+    debug
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn contains_bytes(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
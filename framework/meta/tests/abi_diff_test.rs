@@ -0,0 +1,280 @@
+use multiversx_sc_meta::{
+    abi_json::deserialize_abi_from_json,
+    cmd::standalone::abi_diff::{compare_abis, AbiChangeSeverity, AbiDiffReport},
+};
+
+const OLD_ABI_JSON: &str = r#"{
+    "name": "vault",
+    "endpoints": [
+        {
+            "name": "deposit",
+            "mutability": "mutable",
+            "payableInTokens": ["EGLD"],
+            "inputs": [],
+            "outputs": []
+        },
+        {
+            "name": "getBalance",
+            "mutability": "readonly",
+            "inputs": [{ "name": "address", "type": "Address" }],
+            "outputs": [{ "type": "BigUint" }]
+        },
+        {
+            "name": "setFee",
+            "mutability": "mutable",
+            "inputs": [{ "name": "fee", "type": "u32" }],
+            "outputs": []
+        }
+    ],
+    "events": [
+        {
+            "identifier": "deposit",
+            "inputs": [
+                { "name": "caller", "type": "Address", "indexed": true },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        }
+    ],
+    "storage": [
+        {
+            "key": "fee",
+            "mapper": "SingleValueMapper",
+            "valueTypes": ["u64"]
+        },
+        {
+            "key": "balance",
+            "mapper": "storage_get",
+            "keyArgs": [{ "name": "address", "type": "Address" }],
+            "valueTypes": ["BigUint"]
+        },
+        {
+            "key": "depositors",
+            "mapper": "UnorderedSetMapper",
+            "valueTypes": ["Address"]
+        }
+    ],
+    "types": {
+        "Config": {
+            "type": "struct",
+            "fields": [
+                { "name": "fee", "type": "u32" }
+            ]
+        },
+        "Position": {
+            "type": "struct",
+            "fields": [
+                { "name": "amount", "type": "BigUint" }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Inactive", "discriminant": 0 },
+                { "name": "Active", "discriminant": 1 }
+            ]
+        }
+    }
+}"#;
+
+const NEW_ABI_JSON: &str = r#"{
+    "name": "vault",
+    "endpoints": [
+        {
+            "name": "deposit",
+            "mutability": "mutable",
+            "payableInTokens": ["*"],
+            "inputs": [],
+            "outputs": []
+        },
+        {
+            "name": "balanceOf",
+            "mutability": "readonly",
+            "inputs": [{ "name": "address", "type": "Address" }],
+            "outputs": [{ "type": "BigUint" }]
+        },
+        {
+            "name": "setFee",
+            "onlyOwner": true,
+            "onlyRole": "treasurer",
+            "mutability": "mutable",
+            "inputs": [{ "name": "fee", "type": "u64" }],
+            "outputs": []
+        }
+    ],
+    "events": [
+        {
+            "identifier": "deposit",
+            "inputs": [
+                { "name": "caller", "type": "Address" },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        }
+    ],
+    "storage": [
+        {
+            "key": "fee",
+            "mapper": "SingleValueMapper",
+            "valueTypes": ["BigUint"]
+        },
+        {
+            "key": "balance",
+            "mapper": "SingleValueMapper",
+            "keyArgs": [{ "name": "address", "type": "Address" }],
+            "valueTypes": ["BigUint"]
+        },
+        {
+            "key": "limit",
+            "mapper": "SingleValueMapper",
+            "valueTypes": ["BigUint"]
+        }
+    ],
+    "types": {
+        "Config": {
+            "type": "struct",
+            "fields": [
+                { "name": "fee", "type": "u32" },
+                { "name": "limit", "type": "BigUint" }
+            ]
+        },
+        "Position": {
+            "type": "struct",
+            "fields": [
+                { "name": "amount", "type": "BigUint" },
+                { "name": "locked", "type": "bool", "since": 2 }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Active", "discriminant": 0 },
+                { "name": "Inactive", "discriminant": 1 },
+                { "name": "Paused", "discriminant": 2 }
+            ]
+        }
+    }
+}"#;
+
+fn diff() -> AbiDiffReport {
+    let old = deserialize_abi_from_json(OLD_ABI_JSON).unwrap();
+    let new = deserialize_abi_from_json(NEW_ABI_JSON).unwrap();
+    compare_abis(&old, &new)
+}
+
+fn has_change(report: &AbiDiffReport, severity: AbiChangeSeverity, description: &str) -> bool {
+    report
+        .changes
+        .iter()
+        .any(|change| change.severity == severity && change.description == description)
+}
+
+#[test]
+fn abi_diff_same_abi_test() {
+    let abi = deserialize_abi_from_json(OLD_ABI_JSON).unwrap();
+    let report = compare_abis(&abi, &abi);
+    assert!(report.changes.is_empty());
+    assert!(!report.has_breaking_changes());
+}
+
+#[test]
+fn abi_diff_endpoints_test() {
+    let report = diff();
+    assert!(report.has_breaking_changes());
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Breaking,
+        "endpoint 'getBalance' removed, possibly renamed to 'balanceOf'"
+    ));
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Info,
+        "endpoint 'balanceOf' added"
+    ));
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Breaking,
+        "endpoint 'setFee' arguments changed from (u32) to (u64)"
+    ));
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Breaking,
+        "endpoint 'setFee' became only_owner"
+    ));
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Breaking,
+        "endpoint 'setFee' became only_role \"treasurer\""
+    ));
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Warning,
+        "endpoint 'deposit' now accepts payment in [\"*\"]"
+    ));
+}
+
+#[test]
+fn abi_diff_events_test() {
+    let report = diff();
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Breaking,
+        "event 'deposit' signature changed from (indexed Address, BigUint) to (Address, BigUint)"
+    ));
+}
+
+#[test]
+fn abi_diff_types_test() {
+    let report = diff();
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Breaking,
+        "type 'Config' layout changed from {fee: u32} to {fee: u32, limit: BigUint}"
+    ));
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Breaking,
+        "type 'Status' variant 'Inactive' discriminant changed from 0 to 1"
+    ));
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Info,
+        "type 'Status' variant 'Paused' added"
+    ));
+}
+
+#[test]
+fn abi_diff_versioned_fields_test() {
+    let report = diff();
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Info,
+        "type 'Position' gained versioned fields {locked: bool}"
+    ));
+    assert!(!report
+        .changes
+        .iter()
+        .any(|change| change.description.contains("'Position' layout")));
+}
+
+#[test]
+fn abi_diff_storage_test() {
+    let report = diff();
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Breaking,
+        "storage 'fee' layout changed from SingleValueMapper<u64> to SingleValueMapper<BigUint>"
+    ));
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Warning,
+        "storage 'depositors' no longer declared, existing data is left behind"
+    ));
+    assert!(has_change(
+        &report,
+        AbiChangeSeverity::Info,
+        "storage 'limit' added"
+    ));
+    assert!(!report
+        .changes
+        .iter()
+        .any(|change| change.description.contains("'balance'")));
+}
@@ -0,0 +1,108 @@
+use multiversx_sc_meta::{abi_json::deserialize_abi_from_json, abi_value::AbiValueDecoder};
+use serde_json::json;
+
+const ABI_JSON: &str = r#"{
+    "name": "decoder",
+    "types": {
+        "Payment": {
+            "type": "struct",
+            "fields": [
+                { "name": "token", "type": "TokenIdentifier" },
+                { "name": "nonce", "type": "u64" },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Inactive", "discriminant": 0 },
+                {
+                    "name": "Active",
+                    "discriminant": 1,
+                    "fields": [{ "name": "since", "type": "u64" }]
+                }
+            ]
+        }
+    }
+}"#;
+
+#[test]
+fn abi_value_decode_numbers_test() {
+    let abi = deserialize_abi_from_json(ABI_JSON).unwrap();
+    let decoder = AbiValueDecoder::new(&abi.types);
+    assert_eq!(decoder.decode_top("u32", &[]).unwrap(), json!(0));
+    assert_eq!(decoder.decode_top("u64", &[1, 0]).unwrap(), json!(256));
+    assert_eq!(decoder.decode_top("i16", &[0xff]).unwrap(), json!(-1));
+    assert!(decoder.decode_top("u8", &[1, 2]).is_err());
+    assert_eq!(
+        decoder.decode_top("BigUint", &[0x03, 0xe8]).unwrap(),
+        json!("1000")
+    );
+    assert_eq!(
+        decoder
+            .decode_nested("BigInt", &mut &[0, 0, 0, 1, 0xff][..])
+            .unwrap(),
+        json!("-1")
+    );
+}
+
+#[test]
+fn abi_value_decode_struct_test() {
+    let abi = deserialize_abi_from_json(ABI_JSON).unwrap();
+    let decoder = AbiValueDecoder::new(&abi.types);
+    let encoded = [
+        &[0, 0, 0, 4][..],
+        b"EGLD",
+        &[0, 0, 0, 0, 0, 0, 0, 5],
+        &[0, 0, 0, 1, 100],
+    ]
+    .concat();
+    assert_eq!(
+        decoder.decode_top("Payment", &encoded).unwrap(),
+        json!({ "token": "EGLD", "nonce": 5, "amount": "100" })
+    );
+    assert_eq!(
+        serde_json::to_string(&decoder.decode_top("Payment", &encoded).unwrap()).unwrap(),
+        r#"{"token":"EGLD","nonce":5,"amount":"100"}"#
+    );
+    assert!(decoder.decode_top("Payment", &encoded[1..]).is_err());
+}
+
+#[test]
+fn abi_value_decode_enum_test() {
+    let abi = deserialize_abi_from_json(ABI_JSON).unwrap();
+    let decoder = AbiValueDecoder::new(&abi.types);
+    assert_eq!(
+        decoder.decode_top("Status", &[]).unwrap(),
+        json!("Inactive")
+    );
+    assert_eq!(
+        decoder
+            .decode_top("Status", &[1, 0, 0, 0, 0, 0, 0, 0, 7])
+            .unwrap(),
+        json!({ "Active": { "since": 7 } })
+    );
+    assert!(decoder.decode_top("Status", &[2]).is_err());
+}
+
+#[test]
+fn abi_value_decode_generics_test() {
+    let abi = deserialize_abi_from_json(ABI_JSON).unwrap();
+    let decoder = AbiValueDecoder::new(&abi.types);
+    assert_eq!(decoder.decode_top("Option<u8>", &[]).unwrap(), json!(null));
+    assert_eq!(decoder.decode_top("Option<u8>", &[1, 5]).unwrap(), json!(5));
+    assert_eq!(
+        decoder.decode_top("List<u16>", &[0, 1, 0, 2]).unwrap(),
+        json!([1, 2])
+    );
+    assert_eq!(
+        decoder
+            .decode_top("tuple<u8,utf-8 string>", &[1, 0, 0, 0, 2, b'h', b'i'])
+            .unwrap(),
+        json!([1, "hi"])
+    );
+    assert_eq!(
+        decoder.decode_top("Address", &[0u8; 32]).unwrap(),
+        json!("erd1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq6gq4hu")
+    );
+}
@@ -0,0 +1,288 @@
+use multiversx_sc_meta::{
+    abi_json::{deserialize_abi_from_json, ContractAbiJson},
+    abi_value::{AbiValueDecoder, AbiValueEncoder},
+};
+use serde_json::{json, Value};
+
+const ABI_JSON: &str = r#"{
+    "name": "encoder",
+    "events": [
+        {
+            "identifier": "deposit",
+            "inputs": [
+                { "name": "caller", "type": "Address", "indexed": true },
+                { "name": "payments", "type": "variadic<multi<TokenIdentifier,BigUint>>", "indexed": true },
+                { "name": "payment", "type": "Payment" }
+            ]
+        }
+    ],
+    "types": {
+        "Payment": {
+            "type": "struct",
+            "fields": [
+                { "name": "token", "type": "TokenIdentifier" },
+                { "name": "nonce", "type": "u64" },
+                { "name": "amount", "type": "BigUint" },
+                { "name": "memo", "type": "Option<utf-8 string>", "since": 2 }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Inactive", "discriminant": 0 },
+                {
+                    "name": "Active",
+                    "discriminant": 1,
+                    "fields": [{ "name": "since", "type": "u64" }]
+                },
+                { "name": "Paused", "discriminant": 5 }
+            ]
+        }
+    }
+}"#;
+
+const ALICE_BECH32: &str = "erd1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8ssycr6th";
+
+fn abi() -> ContractAbiJson {
+    deserialize_abi_from_json(ABI_JSON).unwrap()
+}
+
+fn check_top_round_trip(type_name: &str, value: Value, expected_bytes: &[u8]) {
+    let abi = abi();
+    let encoded = AbiValueEncoder::new(&abi.types)
+        .encode_top(type_name, &value)
+        .unwrap();
+    assert_eq!(encoded, expected_bytes, "top-encoding {type_name}");
+    let decoded = AbiValueDecoder::new(&abi.types)
+        .decode_top(type_name, &encoded)
+        .unwrap();
+    assert_eq!(decoded, value, "top-decoding {type_name}");
+}
+
+fn check_nested_round_trip(type_name: &str, value: Value, expected_bytes: &[u8]) {
+    let abi = abi();
+    let mut encoded = Vec::new();
+    AbiValueEncoder::new(&abi.types)
+        .encode_nested(type_name, &value, &mut encoded)
+        .unwrap();
+    assert_eq!(encoded, expected_bytes, "nested-encoding {type_name}");
+    let decoded = AbiValueDecoder::new(&abi.types)
+        .decode_nested(type_name, &mut &encoded[..])
+        .unwrap();
+    assert_eq!(decoded, value, "nested-decoding {type_name}");
+}
+
+#[test]
+fn abi_value_encode_numbers_test() {
+    check_top_round_trip("u32", json!(0), &[]);
+    check_top_round_trip("u64", json!(256), &[1, 0]);
+    check_top_round_trip("i16", json!(-1), &[0xff]);
+    check_top_round_trip("i32", json!(128), &[0, 0x80]);
+    check_top_round_trip("i32", json!(-129), &[0xff, 0x7f]);
+    check_top_round_trip("BigUint", json!("1000"), &[0x03, 0xe8]);
+    check_top_round_trip("BigUint", json!("0"), &[]);
+    check_nested_round_trip("u16", json!(5), &[0, 5]);
+    check_nested_round_trip("i16", json!(-2), &[0xff, 0xfe]);
+    check_nested_round_trip("BigInt", json!("-1"), &[0, 0, 0, 1, 0xff]);
+
+    let abi = abi();
+    let encoder = AbiValueEncoder::new(&abi.types);
+    assert_eq!(encoder.encode_top("u64", &json!("300")).unwrap(), [1, 44]);
+    assert!(encoder.encode_top("u8", &json!(256)).is_err());
+    assert!(encoder.encode_top("u32", &json!(-1)).is_err());
+}
+
+#[test]
+fn abi_value_encode_buffers_test() {
+    check_top_round_trip("bool", json!(true), &[1]);
+    check_top_round_trip("bool", json!(false), &[]);
+    check_top_round_trip("TokenIdentifier", json!("EGLD"), b"EGLD");
+    check_top_round_trip("utf-8 string", json!("0xff00"), &[0xff, 0]);
+    check_top_round_trip("bytes", json!("0x0102"), &[1, 2]);
+    check_nested_round_trip("bytes", json!("0x0102"), &[0, 0, 0, 2, 1, 2]);
+    check_nested_round_trip("array2<u8>", json!("0x0102"), &[1, 2]);
+    check_nested_round_trip("tuple<u8,bool>", json!([3, true]), &[3, 1]);
+
+    let abi = abi();
+    let encoder = AbiValueEncoder::new(&abi.types);
+    assert!(encoder.encode_top("bytes", &json!("0102")).is_err());
+    assert!(encoder
+        .encode_nested("array2<u8>", &json!("0x010203"), &mut Vec::new())
+        .is_err());
+}
+
+#[test]
+fn abi_value_encode_address_test() {
+    let abi = abi();
+    let encoder = AbiValueEncoder::new(&abi.types);
+    let encoded = encoder.encode_top("Address", &json!(ALICE_BECH32)).unwrap();
+    assert_eq!(encoded.len(), 32);
+    assert_eq!(
+        AbiValueDecoder::new(&abi.types)
+            .decode_top("Address", &encoded)
+            .unwrap(),
+        json!(ALICE_BECH32)
+    );
+
+    let hex_address = format!("0x{}", hex::encode(&encoded));
+    assert_eq!(
+        encoder.encode_top("Address", &json!(hex_address)).unwrap(),
+        encoded
+    );
+    assert!(encoder
+        .encode_top("Address", &json!("erd1invalid"))
+        .is_err());
+}
+
+#[test]
+fn abi_value_encode_option_list_test() {
+    check_top_round_trip("Option<u8>", json!(null), &[]);
+    check_top_round_trip("Option<u8>", json!(7), &[1, 7]);
+    check_nested_round_trip("Option<u8>", json!(null), &[0]);
+    check_top_round_trip("List<u16>", json!([1, 2]), &[0, 1, 0, 2]);
+    check_nested_round_trip("List<u16>", json!([1, 2]), &[0, 0, 0, 2, 0, 1, 0, 2]);
+}
+
+#[test]
+fn abi_value_encode_struct_test() {
+    let payment_v1 = [
+        &[0, 0, 0, 4][..],
+        b"EGLD",
+        &[0, 0, 0, 0, 0, 0, 0, 5],
+        &[0, 0, 0, 1, 100],
+    ]
+    .concat();
+    check_top_round_trip(
+        "Payment",
+        json!({ "token": "EGLD", "nonce": 5, "amount": "100" }),
+        &payment_v1,
+    );
+
+    let payment_v2 = [&payment_v1[..], &[1, 0, 0, 0, 2], b"hi"].concat();
+    check_top_round_trip(
+        "Payment",
+        json!({ "token": "EGLD", "nonce": 5, "amount": "100", "memo": "hi" }),
+        &payment_v2,
+    );
+
+    let abi = abi();
+    let encoder = AbiValueEncoder::new(&abi.types);
+    // versioned fields can only be left out at top level
+    assert!(encoder
+        .encode_nested(
+            "Payment",
+            &json!({ "token": "EGLD", "nonce": 5, "amount": "100" }),
+            &mut Vec::new()
+        )
+        .is_err());
+    assert!(encoder
+        .encode_top("Payment", &json!({ "token": "EGLD", "nonce": 5 }))
+        .is_err());
+    assert!(encoder
+        .encode_top(
+            "Payment",
+            &json!({ "token": "EGLD", "nonce": 5, "amount": "100", "extra": 1 })
+        )
+        .is_err());
+}
+
+#[test]
+fn abi_value_encode_enum_test() {
+    check_top_round_trip("Status", json!("Inactive"), &[]);
+    check_top_round_trip("Status", json!("Paused"), &[5]);
+    check_top_round_trip(
+        "Status",
+        json!({ "Active": { "since": 7 } }),
+        &[1, 0, 0, 0, 0, 0, 0, 0, 7],
+    );
+    check_nested_round_trip("Status", json!("Inactive"), &[0]);
+    check_nested_round_trip("Status", json!("Paused"), &[5]);
+
+    let abi = abi();
+    let encoder = AbiValueEncoder::new(&abi.types);
+    assert!(encoder.encode_top("Status", &json!("Unknown")).is_err());
+    assert!(encoder.encode_top("Status", &json!("Active")).is_err());
+}
+
+#[test]
+fn abi_value_multi_test() {
+    let abi = abi();
+    let encoder = AbiValueEncoder::new(&abi.types);
+    let decoder = AbiValueDecoder::new(&abi.types);
+    let type_names = [
+        "u32",
+        "counted-variadic<u8>",
+        "optional<bool>",
+        "variadic<multi<TokenIdentifier,BigUint>>",
+    ];
+    let values = [
+        json!(3),
+        json!([1, 2]),
+        json!(true),
+        json!([["EGLD", "10"], ["TOKEN-123456", "0"]]),
+    ];
+
+    let args = encoder.encode_multi(type_names, &values).unwrap();
+    let expected_args: Vec<Vec<u8>> = vec![
+        vec![3],
+        vec![2],
+        vec![1],
+        vec![2],
+        vec![1],
+        b"EGLD".to_vec(),
+        vec![10],
+        b"TOKEN-123456".to_vec(),
+        vec![],
+    ];
+    assert_eq!(args, expected_args);
+
+    let arg_slices: Vec<&[u8]> = args.iter().map(Vec::as_slice).collect();
+    assert_eq!(
+        decoder.decode_multi(type_names, &arg_slices).unwrap(),
+        values
+    );
+
+    // missing optional, empty variadic
+    let short_values = [json!(3), json!([]), json!(null), json!([])];
+    let short_args = encoder.encode_multi(type_names, &short_values).unwrap();
+    assert_eq!(short_args, vec![vec![3], vec![]]);
+    let short_arg_slices: Vec<&[u8]> = short_args.iter().map(Vec::as_slice).collect();
+    assert_eq!(
+        decoder.decode_multi(type_names, &short_arg_slices).unwrap(),
+        short_values
+    );
+
+    assert!(decoder.decode_multi(["u32"], &[]).is_err());
+    assert!(decoder.decode_multi(["u32"], &[&[1], &[2]]).is_err());
+    assert!(encoder.encode_multi(["u32"], &[]).is_err());
+}
+
+#[test]
+fn abi_value_decode_event_test() {
+    let abi = abi();
+    let encoder = AbiValueEncoder::new(&abi.types);
+    let decoder = AbiValueDecoder::new(&abi.types);
+    let event = &abi.events[0];
+
+    let caller = encoder.encode_top("Address", &json!(ALICE_BECH32)).unwrap();
+    let data = encoder
+        .encode_top(
+            "Payment",
+            &json!({ "token": "EGLD", "nonce": 0, "amount": "5" }),
+        )
+        .unwrap();
+    let topics: [&[u8]; 4] = [b"deposit", &caller, b"EGLD", &[5]];
+    assert_eq!(
+        decoder.decode_event(event, &topics, &data).unwrap(),
+        json!({
+            "caller": ALICE_BECH32,
+            "payments": [["EGLD", "5"]],
+            "payment": { "token": "EGLD", "nonce": 0, "amount": "5" }
+        })
+    );
+
+    let wrong_identifier: [&[u8]; 2] = [b"withdraw", &caller];
+    assert!(decoder
+        .decode_event(event, &wrong_identifier, &data)
+        .is_err());
+}
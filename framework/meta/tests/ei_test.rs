@@ -1,4 +1,4 @@
-use multiversx_sc_meta::ei;
+use multiversx_sc_meta::{cmd::standalone::ei_check::check_ei_workspace, ei};
 
 use std::collections::HashSet;
 
@@ -109,3 +109,93 @@ fn test_added_names_ei_1_2() {
 fn test_added_names_ei_1_3() {
     test_added_names(ei::EI_1_2_NAMES, EI_1_3_ADDED_NAMES, ei::EI_1_3_NAMES);
 }
+
+fn import_names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_ei_compatibility_report() {
+    let imports = import_names(&["mBufferSetByteSlice", "managedVerifyBLS", "bigIntAdd"]);
+    let report = ei::EICompatibilityReport::new(&imports, ei::EIVersion::V1_2);
+    assert_eq!(report.min_version, Some(ei::EIVersion::V1_2));
+    assert!(report.is_ok());
+
+    let report = ei::EICompatibilityReport::new(&imports, ei::EIVersion::V1_1);
+    assert_eq!(report.outside_target, vec!["managedVerifyBLS".to_string()]);
+    assert!(report.non_vm_hooks.is_empty());
+    assert!(!report.is_ok());
+
+    let imports = import_names(&["managedCreateAsyncCall", "memcpy"]);
+    let report = ei::EICompatibilityReport::new(&imports, ei::EIVersion::V1_3);
+    assert_eq!(report.min_version, Some(ei::EIVersion::V1_3));
+    assert!(report.outside_target.is_empty());
+    assert_eq!(report.non_vm_hooks, vec!["memcpy".to_string()]);
+    assert!(!report.is_ok());
+
+    let report = ei::EICompatibilityReport::new(&[], ei::EIVersion::V1_0);
+    assert_eq!(report.min_version, None);
+    assert!(report.is_ok());
+}
+
+/// Wasm module that only imports functions from `env`, each of type `() -> ()`.
+fn wasm_with_imports(names: &[&str]) -> Vec<u8> {
+    let mut import_section = vec![names.len() as u8];
+    for name in names {
+        import_section.extend_from_slice(&[3, b'e', b'n', b'v', name.len() as u8]);
+        import_section.extend_from_slice(name.as_bytes());
+        import_section.extend_from_slice(&[0x00, 0x00]);
+    }
+
+    let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+    wasm.extend_from_slice(&[0x01, 0x04, 0x01, 0x60, 0x00, 0x00]);
+    wasm.push(0x02);
+    wasm.push(import_section.len() as u8);
+    wasm.extend(import_section);
+    wasm
+}
+
+#[test]
+fn test_ei_check_workspace() {
+    let workspace_path = std::env::temp_dir().join("sc-meta-ei-check-test");
+    let contract_path = workspace_path.join("adder");
+    let output_path = contract_path.join("output");
+    std::fs::create_dir_all(&output_path).unwrap();
+    std::fs::write(contract_path.join("multiversx.json"), "{}").unwrap();
+    std::fs::write(
+        contract_path.join("Cargo.toml"),
+        r#"[package]
+name = "adder"
+version = "0.0.0"
+
+[dependencies.multiversx-sc]
+version = "0.45.2"
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        output_path.join("adder.wasm"),
+        wasm_with_imports(&["bigIntAdd", "managedVerifyBLS"]),
+    )
+    .unwrap();
+    std::fs::write(
+        output_path.join("adder-full.wasm"),
+        wasm_with_imports(&["managedCreateAsyncCall", "memcpy"]),
+    )
+    .unwrap();
+
+    let reports = check_ei_workspace(&workspace_path, &[], ei::EIVersion::V1_2);
+    assert_eq!(reports.len(), 2);
+    assert!(reports[0].0.ends_with("adder-full.wasm"));
+    assert_eq!(reports[0].1.min_version, Some(ei::EIVersion::V1_3));
+    assert_eq!(
+        reports[0].1.outside_target,
+        vec!["managedCreateAsyncCall".to_string()]
+    );
+    assert_eq!(reports[0].1.non_vm_hooks, vec!["memcpy".to_string()]);
+    assert!(reports[1].0.ends_with("adder.wasm"));
+    assert_eq!(reports[1].1.min_version, Some(ei::EIVersion::V1_2));
+    assert!(reports[1].1.is_ok());
+
+    std::fs::remove_dir_all(&workspace_path).unwrap();
+}
@@ -1,5 +1,7 @@
 use multiversx_sc::abi::{ContractAbi, EndpointAbi};
-use multiversx_sc_meta::cmd::contract::sc_config::{MultiContractConfigSerde, ScConfig};
+use multiversx_sc_meta::cmd::contract::sc_config::{
+    ContractSizeReport, MultiContractConfigSerde, ScConfig, SizeBaseline,
+};
 
 fn get_serialized_toml() -> MultiContractConfigSerde {
     toml::from_str(
@@ -123,3 +125,65 @@ fn test_sc_config() {
         ["endpoint1", "endpoint2", "endpoint3", "endpoint4"]
     );
 }
+
+#[test]
+fn test_size_budget_config() {
+    let multi_contract: MultiContractConfigSerde = toml::from_str(
+        r#"
+        [settings]
+        main = "main-contract"
+        size-baseline = "size-baseline.json"
+
+        [contracts.main-contract]
+        max-size = "2k"
+        max-size-growth = 5
+
+        [contracts.view-contract]
+        name = "view"
+        external-view = true
+    "#,
+    )
+    .unwrap();
+    let sc_config = ScConfig::load_from_config(&multi_contract, &get_contract_abi());
+    assert_eq!(
+        sc_config.size_baseline,
+        Some("size-baseline.json".to_string())
+    );
+
+    let main_contract = sc_config.main_contract();
+    assert_eq!(main_contract.settings.max_size, Some(2048));
+    assert_eq!(main_contract.settings.max_size_growth, Some(5));
+
+    let view_contract = sc_config.find_contract("view");
+    assert_eq!(view_contract.settings.max_size, None);
+    assert_eq!(view_contract.settings.max_size_growth, None);
+
+    let mut size_baseline = SizeBaseline::default();
+    size_baseline.set("main-contract", 1000);
+
+    let within_budget = ContractSizeReport::new(
+        "main-contract",
+        1040,
+        &main_contract.settings,
+        size_baseline.get("main-contract"),
+    );
+    assert_eq!(within_budget.growth_percent, Some(4.0));
+    assert!(within_budget.violations().is_empty());
+
+    let too_much_growth = ContractSizeReport::new(
+        "main-contract",
+        1100,
+        &main_contract.settings,
+        size_baseline.get("main-contract"),
+    );
+    assert!(!too_much_growth.over_max_size);
+    assert!(too_much_growth.over_max_size_growth);
+
+    let over_budget = ContractSizeReport::new("main-contract", 3000, &main_contract.settings, None);
+    assert!(over_budget.over_max_size);
+    assert!(!over_budget.over_max_size_growth);
+    assert_eq!(
+        over_budget.violations(),
+        vec!["contract main-contract has 3000 bytes, over its budget of 2048 bytes".to_string()]
+    );
+}
@@ -28,6 +28,43 @@ fn test_template_list() {
     );
 }
 
+#[test]
+fn test_template_list_from_template_dir() {
+    let workspace_path = find_current_workspace().unwrap();
+    let repo_source = RepoSource::from_template_dir(workspace_path.join("contracts/examples"));
+    let mut template_names = template_names_from_repo(&repo_source);
+    template_names.sort();
+    assert_eq!(
+        template_names,
+        [
+            "adder".to_string(),
+            "crypto-zombies".to_string(),
+            "empty".to_string(),
+            "ping-pong-egld".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_template_from_template_dir() {
+    let workspace_path = find_current_workspace().unwrap();
+    let repo_source = RepoSource::from_template_dir(workspace_path.join("contracts/examples"));
+    let target = ContractCreatorTarget {
+        target_path: std::env::temp_dir().join("template-dir-test"),
+        new_name: "local-adder".to_string(),
+    };
+    prepare_target_dir(&target);
+
+    let creator = ContractCreator::new(&repo_source, "adder".to_string(), target.clone(), true);
+    creator.copy_template(LAST_TEMPLATE_VERSION);
+    creator.rename_template();
+
+    let contract_dir = target.contract_dir();
+    assert!(contract_dir.join("src/local_adder.rs").is_file());
+    let cargo_toml = fs::read_to_string(contract_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("name = \"local-adder\""));
+}
+
 #[test]
 #[cfg_attr(not(feature = "template-test-current"), ignore)]
 fn template_current_adder() {
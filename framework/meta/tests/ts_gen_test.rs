@@ -0,0 +1,133 @@
+use multiversx_sc_meta::{abi_json::deserialize_abi_from_json, cmd::contract::generate_ts};
+
+const ABI_JSON: &str = r#"{
+    "name": "my-vault",
+    "constructor": {
+        "inputs": [
+            { "name": "initial_value", "type": "BigUint" }
+        ],
+        "outputs": []
+    },
+    "endpoints": [
+        {
+            "docs": [" Deposits tokens."],
+            "name": "deposit",
+            "mutability": "mutable",
+            "payableInTokens": ["*"],
+            "inputs": [
+                { "name": "opt_receiver", "type": "optional<Address>", "multi_arg": true }
+            ],
+            "outputs": []
+        },
+        {
+            "name": "getStatus",
+            "mutability": "readonly",
+            "inputs": [],
+            "outputs": [
+                { "type": "Status" }
+            ]
+        },
+        {
+            "name": "setConfig",
+            "onlyOwner": true,
+            "mutability": "mutable",
+            "inputs": [
+                { "name": "config", "type": "Config" },
+                { "name": "extra", "type": "variadic<multi<TokenIdentifier,u64>>", "multi_arg": true }
+            ],
+            "outputs": []
+        }
+    ],
+    "events": [
+        {
+            "identifier": "deposit",
+            "inputs": [
+                { "name": "caller", "type": "Address", "indexed": true },
+                { "name": "amount", "type": "BigUint" }
+            ]
+        }
+    ],
+    "types": {
+        "Config": {
+            "type": "struct",
+            "fields": [
+                { "name": "fee", "type": "u32" },
+                { "name": "limit", "type": "Option<BigUint>" }
+            ]
+        },
+        "Status": {
+            "type": "enum",
+            "variants": [
+                { "name": "Inactive", "discriminant": 0 },
+                { "name": "Active", "discriminant": 1 }
+            ]
+        },
+        "Action": {
+            "type": "enum",
+            "variants": [
+                { "name": "Nothing", "discriminant": 0 },
+                {
+                    "name": "Transfer",
+                    "discriminant": 1,
+                    "fields": [
+                        { "name": "0", "type": "Address" }
+                    ]
+                }
+            ]
+        }
+    }
+}"#;
+
+fn generated() -> String {
+    let abi_json = deserialize_abi_from_json(ABI_JSON).unwrap();
+    generate_ts::generate_ts_bindings(&abi_json)
+}
+
+#[test]
+fn ts_gen_types_test() {
+    let ts = generated();
+    assert!(ts.contains(
+        "export interface Config {
+    fee: number;
+    limit: bigint | null;
+}"
+    ));
+    assert!(ts.contains(
+        "export enum Status {
+    Inactive = 0,
+    Active = 1,
+}"
+    ));
+    assert!(ts.contains("    | { name: \"Nothing\"; discriminant: 0 }"));
+    assert!(ts.contains("          \"0\": string;"));
+}
+
+#[test]
+fn ts_gen_events_test() {
+    let ts = generated();
+    assert!(ts.contains(
+        "export interface DepositEvent {
+    identifier: \"deposit\";
+    /** Indexed, sent as topic. */
+    caller: string;
+    amount: bigint;
+}"
+    ));
+}
+
+#[test]
+fn ts_gen_contract_class_test() {
+    let ts = generated();
+    assert!(ts.contains("export class MyVaultContract {"));
+    assert!(ts.contains("static readonly views: readonly string[] = [\"getStatus\"];"));
+    assert!(ts.contains("static deploy(initialValue: bigint): ContractCall<[bigint], void> {"));
+    assert!(
+        ts.contains("deposit(optReceiver?: string): ContractCall<[string | undefined], void> {")
+    );
+    assert!(ts.contains("payableInTokens: [\"*\"],"));
+    assert!(ts.contains("getStatus(): ContractCall<[], Status> {"));
+    assert!(ts.contains(
+        "setConfig(config: Config, extra: Array<[string, bigint]>): ContractCall<[Config, Array<[string, bigint]>], void> {"
+    ));
+    assert!(ts.contains("onlyOwner: true,"));
+}
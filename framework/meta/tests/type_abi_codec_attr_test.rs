@@ -0,0 +1,53 @@
+use multiversx_sc::{
+    abi::{TypeAbi, TypeContents, TypeDescriptionContainer, TypeDescriptionContainerImpl},
+    derive::TypeAbi,
+};
+use multiversx_sc_meta::abi_json::TypeDescriptionJson;
+
+#[derive(TypeAbi)]
+pub struct WithSkippedField {
+    pub amount: u32,
+    #[codec(skip, default = 5)]
+    pub cache: u64,
+}
+
+#[derive(TypeAbi)]
+pub enum Status {
+    #[codec(discriminant = 2)]
+    Paused,
+    #[codec(discriminant = 0)]
+    Inactive,
+    Active,
+}
+
+#[test]
+fn type_abi_skipped_fields_test() {
+    let mut container = TypeDescriptionContainerImpl::new();
+    WithSkippedField::provide_type_descriptions(&mut container);
+    let type_description = container.0.get("WithSkippedField").unwrap();
+
+    let json = serde_json::to_string(&TypeDescriptionJson::from(type_description)).unwrap();
+    assert_eq!(
+        json,
+        r#"{"type":"struct","fields":[{"name":"amount","type":"u32"}]}"#
+    );
+}
+
+#[test]
+fn type_abi_explicit_discriminants_test() {
+    let mut container = TypeDescriptionContainerImpl::new();
+    Status::provide_type_descriptions(&mut container);
+    let type_description = container.0.get("Status").unwrap();
+
+    let TypeContents::Enum(variants) = &type_description.contents else {
+        panic!("enum expected");
+    };
+    let discriminants: Vec<(&str, usize)> = variants
+        .iter()
+        .map(|variant| (variant.name.as_str(), variant.discriminant))
+        .collect();
+    assert_eq!(
+        discriminants,
+        vec![("Paused", 2), ("Inactive", 0), ("Active", 1)]
+    );
+}
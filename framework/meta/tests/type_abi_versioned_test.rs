@@ -0,0 +1,33 @@
+use multiversx_sc::{
+    abi::{TypeAbi, TypeContents, TypeDescriptionContainer, TypeDescriptionContainerImpl},
+    derive::TypeAbi,
+};
+use multiversx_sc_meta::abi_json::TypeDescriptionJson;
+
+#[derive(TypeAbi)]
+pub struct Position {
+    pub amount: u32,
+    #[codec(since = 2)]
+    pub locked: bool,
+    #[codec(since = 3, default = 100)]
+    pub multiplier: u64,
+}
+
+#[test]
+fn type_abi_versioned_fields_test() {
+    let mut container = TypeDescriptionContainerImpl::new();
+    Position::provide_type_descriptions(&mut container);
+    let type_description = container.0.get("Position").unwrap();
+
+    let TypeContents::Struct(fields) = &type_description.contents else {
+        panic!("struct expected");
+    };
+    let since: Vec<Option<u32>> = fields.iter().map(|field| field.since).collect();
+    assert_eq!(since, vec![None, Some(2), Some(3)]);
+
+    let json = serde_json::to_string(&TypeDescriptionJson::from(type_description)).unwrap();
+    assert_eq!(
+        json,
+        r#"{"type":"struct","fields":[{"name":"amount","type":"u32"},{"name":"locked","type":"bool","since":2},{"name":"multiplier","type":"u64","since":3}]}"#
+    );
+}
@@ -0,0 +1,190 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    thread,
+};
+
+use bech32::{ToBase32, Variant};
+use multiversx_sc_meta::cmd::standalone::verify::{
+    compare_wasm, expected_code_from_gateway, expected_code_from_mxsc, load_built_variants,
+    verify_code, wasm_sections, CodeComparison,
+};
+
+const WASM_HEADER: &[u8] = &[0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+
+/// A minimal module: an empty type section, a custom "name" section and some code bytes.
+fn sample_wasm(code_byte: u8) -> Vec<u8> {
+    let mut wasm = WASM_HEADER.to_vec();
+    wasm.extend_from_slice(&[0x01, 0x01, 0x00]);
+    wasm.extend_from_slice(&[0x0a, 0x04, 0x01, 0x02, 0x00, code_byte]);
+    wasm.extend_from_slice(&[0x00, 0x06, 0x04, b'n', b'a', b'm', b'e', 0x00]);
+    wasm
+}
+
+fn sample_mxsc_json(code: &[u8]) -> String {
+    format!(
+        r#"{{
+    "buildInfo": {{
+        "rustc": {{
+            "version": "1.76.0-nightly",
+            "commitHash": "a96d57bdb6d2bb6d233d1d6e0bf5ae79a0a2a3b2",
+            "commitDate": "2023-12-10",
+            "channel": "Nightly",
+            "short": "rustc 1.76.0-nightly (a96d57bdb 2023-12-10)"
+        }},
+        "contractCrate": {{
+            "name": "adder",
+            "version": "0.0.0"
+        }},
+        "framework": {{
+            "name": "multiversx-sc",
+            "version": "0.45.2"
+        }}
+    }},
+    "abi": {{
+        "name": "Adder",
+        "endpoints": []
+    }},
+    "size": {},
+    "code": "{}"
+}}"#,
+        code.len(),
+        hex::encode(code)
+    )
+}
+
+#[test]
+fn test_wasm_sections() {
+    let sections = wasm_sections(&sample_wasm(0x0b));
+    let names: Vec<&str> = sections.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["header", "type", "code", "custom \"name\""]);
+    assert_eq!(sections[2].start, 11);
+    assert_eq!(sections[2].end, 17);
+}
+
+#[test]
+fn test_compare_wasm() {
+    assert_eq!(
+        compare_wasm(&sample_wasm(0x0b), &sample_wasm(0x0b)),
+        CodeComparison::Identical
+    );
+    assert_eq!(
+        compare_wasm(&sample_wasm(0x0b), &sample_wasm(0x0c)),
+        CodeComparison::Different {
+            offset: 16,
+            section: "code".to_string(),
+            expected_size: 25,
+            actual_size: 25,
+        }
+    );
+
+    let mut longer = sample_wasm(0x0b);
+    longer.push(0x00);
+    assert_eq!(
+        compare_wasm(&longer, &sample_wasm(0x0b)),
+        CodeComparison::Different {
+            offset: 25,
+            section: "end of code".to_string(),
+            expected_size: 26,
+            actual_size: 25,
+        }
+    );
+}
+
+#[test]
+fn test_verify_mxsc_against_output() {
+    let dir = std::env::temp_dir().join("sc-meta-verify-test");
+    let output_dir = dir.join("output");
+    fs::create_dir_all(&output_dir).unwrap();
+    fs::write(
+        output_dir.join("adder.mxsc.json"),
+        sample_mxsc_json(&sample_wasm(0x0b)),
+    )
+    .unwrap();
+    fs::write(
+        output_dir.join("adder-view.mxsc.json"),
+        sample_mxsc_json(&sample_wasm(0x0c)),
+    )
+    .unwrap();
+
+    let published_path = dir.join("adder.mxsc.json");
+    fs::write(&published_path, sample_mxsc_json(&sample_wasm(0x0b))).unwrap();
+    let expected = expected_code_from_mxsc(&published_path);
+    assert_eq!(expected.framework_version.as_deref(), Some("0.45.2"));
+
+    let built_variants = load_built_variants(&output_dir);
+    assert_eq!(built_variants.len(), 2);
+
+    // only the variant with the same name is considered
+    let report = verify_code(&expected, &built_variants);
+    assert_eq!(report.results.len(), 1);
+    assert_eq!(report.results[0].0, "adder.mxsc.json");
+    assert!(report.is_verified());
+
+    // the published code only matches the other variant, but names take precedence
+    fs::write(&published_path, sample_mxsc_json(&sample_wasm(0x0c))).unwrap();
+    let expected = expected_code_from_mxsc(&published_path);
+    assert!(!verify_code(&expected, &built_variants).is_verified());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Serves a single account request, the way the gateway would.
+fn start_stub_gateway(address: String, code: Vec<u8>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        assert!(request_line.starts_with(&format!("GET /address/{address} ")));
+        let mut header_line = String::new();
+        while reader.read_line(&mut header_line).unwrap() > 2 {
+            header_line.clear();
+        }
+
+        let body = format!(
+            r#"{{"data":{{"account":{{"address":"{address}","nonce":0,"balance":"0","username":"","code":"{}"}}}},"error":"","code":"successful"}}"#,
+            hex::encode(code)
+        );
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+    });
+    url
+}
+
+#[test]
+fn test_verify_deployed_code() {
+    let address = bech32::encode("erd", [5u8; 32].to_base32(), Variant::Bech32).unwrap();
+    let gateway = start_stub_gateway(address.clone(), sample_wasm(0x0c));
+
+    let expected = expected_code_from_gateway(&gateway, &address);
+    assert_eq!(expected.code, sample_wasm(0x0c));
+    assert!(expected.mxsc_file_name.is_none());
+
+    let dir = std::env::temp_dir().join("sc-meta-verify-deployed-test");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("adder.mxsc.json"),
+        sample_mxsc_json(&sample_wasm(0x0b)),
+    )
+    .unwrap();
+    fs::write(
+        dir.join("adder-view.mxsc.json"),
+        sample_mxsc_json(&sample_wasm(0x0c)),
+    )
+    .unwrap();
+
+    // deployed code can come from any of the variants
+    let report = verify_code(&expected, &load_built_variants(&dir));
+    assert_eq!(report.results.len(), 2);
+    assert!(report.is_verified());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,141 @@
+use std::collections::BTreeSet;
+
+use multiversx_sc_meta::wasm_analysis::{analyze_wasm, LARGE_DATA_SEGMENT_SIZE};
+
+fn leb128(mut value: usize) -> Vec<u8> {
+    let mut result = Vec::new();
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            result.push(byte);
+            return result;
+        }
+        result.push(byte | 0x80);
+    }
+}
+
+fn section(id: u8, contents: &[u8]) -> Vec<u8> {
+    let mut result = vec![id];
+    result.extend(leb128(contents.len()));
+    result.extend_from_slice(contents);
+    result
+}
+
+fn vector(items: &[Vec<u8>]) -> Vec<u8> {
+    let mut result = leb128(items.len());
+    for item in items {
+        result.extend_from_slice(item);
+    }
+    result
+}
+
+fn export_func(name: &str, index: u8) -> Vec<u8> {
+    let mut result = leb128(name.len());
+    result.extend_from_slice(name.as_bytes());
+    result.extend_from_slice(&[0x00, index]);
+    result
+}
+
+fn function_body(instructions: &[u8]) -> Vec<u8> {
+    // no locals
+    let mut body = vec![0x00];
+    body.extend_from_slice(instructions);
+    body.push(0x0b);
+
+    let mut result = leb128(body.len());
+    result.extend(body);
+    result
+}
+
+fn active_data_segment(data: &[u8]) -> Vec<u8> {
+    // memory 0, offset `i32.const 0`
+    let mut result = vec![0x00, 0x41, 0x00, 0x0b];
+    result.extend(leb128(data.len()));
+    result.extend_from_slice(data);
+    result
+}
+
+/// Two functions of type `() -> ()`, both exported, and the given data segments.
+fn sample_module(
+    init_code: &[u8],
+    other_code: &[u8],
+    other_name: &str,
+    data_segments: &[&[u8]],
+) -> Vec<u8> {
+    let mut wasm = vec![0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00];
+    wasm.extend(section(1, &vector(&[vec![0x60, 0x00, 0x00]])));
+    wasm.extend(section(3, &vector(&[vec![0x00], vec![0x00]])));
+    wasm.extend(section(5, &vector(&[vec![0x00, 0x01]])));
+    wasm.extend(section(
+        7,
+        &vector(&[export_func("init", 0), export_func(other_name, 1)]),
+    ));
+    wasm.extend(section(
+        10,
+        &vector(&[function_body(init_code), function_body(other_code)]),
+    ));
+    let data_segments: Vec<Vec<u8>> = data_segments
+        .iter()
+        .map(|data| active_data_segment(data))
+        .collect();
+    wasm.extend(section(11, &vector(&data_segments)));
+    wasm
+}
+
+const I32_CONST_0: &[u8] = &[0x41, 0x00];
+const DROP: u8 = 0x1a;
+
+fn endpoint_names() -> Vec<String> {
+    vec!["init".to_string(), "add".to_string()]
+}
+
+#[test]
+fn test_wasm_analysis_clean() {
+    let wasm = sample_module(&[], &[0x41, 0x01, DROP], "add", &[b"some data"]);
+    let analysis = analyze_wasm(&wasm, &endpoint_names()).unwrap();
+    assert!(!analysis.has_issues());
+}
+
+#[test]
+fn test_wasm_analysis_issues() {
+    // f64.const 1.0, drop, f64.const 1.0, drop, i32.const 1, memory.grow, drop
+    let mut init_code = Vec::new();
+    for _ in 0..2 {
+        init_code.push(0x44);
+        init_code.extend_from_slice(&1.0f64.to_le_bytes());
+        init_code.push(DROP);
+    }
+    init_code.extend_from_slice(&[0x41, 0x01, 0x40, 0x00, DROP]);
+
+    // memory.fill
+    let mut other_code = Vec::new();
+    for _ in 0..3 {
+        other_code.extend_from_slice(I32_CONST_0);
+    }
+    other_code.extend_from_slice(&[0xfc, 0x0b, 0x00]);
+
+    let mut data = vec![0u8; LARGE_DATA_SEGMENT_SIZE];
+    data.extend_from_slice(b"panicked at src/lib.rs");
+
+    // the same marker in several segments is only reported once
+    let other_data = b"panicked at src/other.rs";
+
+    let wasm = sample_module(&init_code, &other_code, "helper", &[&data, other_data]);
+    let analysis = analyze_wasm(&wasm, &endpoint_names()).unwrap();
+    assert!(analysis.has_issues());
+    assert_eq!(analysis.float_instructions.len(), 1);
+    assert_eq!(analysis.float_instructions.get("F64Const"), Some(&2));
+    assert_eq!(analysis.bulk_memory_instructions.len(), 1);
+    assert_eq!(
+        analysis.bulk_memory_instructions.get("MemoryFill"),
+        Some(&1)
+    );
+    assert_eq!(analysis.memory_grow_count, 1);
+    assert_eq!(analysis.large_data_segments, vec![data.len()]);
+    assert_eq!(
+        analysis.panic_fmt_markers,
+        BTreeSet::from(["panicked at".to_string()])
+    );
+    assert_eq!(analysis.non_endpoint_exports, vec!["helper".to_string()]);
+}
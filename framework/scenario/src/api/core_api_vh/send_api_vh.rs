@@ -1,5 +1,8 @@
 use multiversx_chain_vm::mem_conv;
-use multiversx_sc::api::{const_handles, RawHandle, SendApi, SendApiImpl};
+use multiversx_sc::{
+    api::{const_handles, RawHandle, SendApi, SendApiImpl},
+    reentrancy_guard,
+};
 
 use crate::api::{VMHooksApi, VMHooksApiBackend};
 
@@ -67,6 +70,7 @@ impl<VHB: VMHooksApiBackend> SendApiImpl for VMHooksApi<VHB> {
         endpoint_name_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> ! {
+        reentrancy_guard::exit_before_async_call::<Self>();
         self.with_vm_hooks(|vh| {
             vh.managed_async_call(
                 to_handle,
@@ -80,4 +80,14 @@ impl<VHB: VMHooksApiBackend> StaticVarApiImpl for VMHooksApi<VHB> {
             use_raw_handle(data.static_vars_cell.borrow().call_value_multi_esdt_handle)
         })
     }
+
+    fn set_reentrancy_lock_held(&self, held: bool) {
+        self.with_static_data(|data| {
+            data.static_vars_cell.borrow_mut().reentrancy_lock_held = held;
+        })
+    }
+
+    fn get_reentrancy_lock_held(&self) -> bool {
+        self.with_static_data(|data| data.static_vars_cell.borrow().reentrancy_lock_held)
+    }
 }
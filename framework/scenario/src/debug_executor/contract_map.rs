@@ -9,6 +9,7 @@ use std::{
     sync::{Arc, Mutex, MutexGuard},
 };
 
+#[derive(Clone)]
 pub struct ContractMap {
     contract_objs: HashMap<Vec<u8>, ContractContainerRef>,
 }
@@ -81,6 +82,12 @@ impl ContractMapRef {
     }
 }
 
+impl From<ContractMap> for ContractMapRef {
+    fn from(contract_map: ContractMap) -> Self {
+        ContractMapRef(Arc::new(Mutex::new(contract_map)))
+    }
+}
+
 impl Executor for ContractMapRef {
     fn set_vm_hooks_ptr(
         &mut self,
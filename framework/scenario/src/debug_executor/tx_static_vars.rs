@@ -7,6 +7,7 @@ pub struct TxStaticVars {
     pub num_arguments: i32,
     pub call_value_egld_handle: RawHandle,
     pub call_value_multi_esdt_handle: RawHandle,
+    pub reentrancy_lock_held: bool,
 }
 
 impl Default for TxStaticVars {
@@ -17,6 +18,7 @@ impl Default for TxStaticVars {
             num_arguments: -1,
             call_value_egld_handle: const_handles::UNINITIALIZED_HANDLE,
             call_value_multi_esdt_handle: const_handles::UNINITIALIZED_HANDLE,
+            reentrancy_lock_held: false,
         }
     }
 }
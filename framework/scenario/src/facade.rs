@@ -1,7 +1,11 @@
 mod contract_info;
 mod debugger_backend;
 mod scenario_world;
+mod scenario_world_block;
+mod scenario_world_coverage;
+mod scenario_world_fork;
 mod scenario_world_runner;
+mod scenario_world_snapshot;
 mod scenario_world_steps;
 mod scenario_world_steps_deprecated;
 mod scenario_world_whitebox;
@@ -9,4 +13,6 @@ mod whitebox_contract;
 
 pub use contract_info::ContractInfo;
 pub use scenario_world::ScenarioWorld;
+pub use scenario_world_block::BlockProductionConfig;
+pub use scenario_world_snapshot::SnapshotId;
 pub use whitebox_contract::WhiteboxContract;
@@ -1,16 +1,35 @@
-use crate::scenario::{
-    self, model::*, run_trace::ScenarioTrace, run_vm::ScenarioVMRunner, ScenarioRunner,
+use crate::{
+    fork::GatewayFork,
+    scenario::{
+        self, model::*, run_coverage::ScenarioCoverage, run_trace::ScenarioTrace,
+        run_vm::ScenarioVMRunner, ScenarioRunner,
+    },
 };
-use std::path::Path;
+use std::{path::Path, sync::Arc};
+
+use super::{scenario_world_snapshot::WorldSnapshot, BlockProductionConfig};
 
 /// Coordinates the execution of scenario tests
 /// using the Rust implementation of the VM and direct contract execution.
 pub(crate) struct DebuggerBackend {
     pub vm_runner: ScenarioVMRunner,
     pub trace: Option<ScenarioTrace>,
+    pub gateway_fork: Option<Arc<GatewayFork>>,
+    /// Where the forked state goes in the trace, i.e. the trace length when forking, if tracing already.
+    pub fork_trace_len: Option<usize>,
+    pub coverage: Option<ScenarioCoverage>,
+    pub snapshots: Vec<WorldSnapshot>,
+    pub block_production: BlockProductionConfig,
 }
 
 impl DebuggerBackend {
+    /// Contract executions recorded in the coverage are attributed to this step, until changed.
+    pub fn set_coverage_step(&self, step_id: Option<&str>) {
+        if let Some(coverage) = &self.coverage {
+            coverage.set_current_step(step_id);
+        }
+    }
+
     pub fn for_each_runner_mut<F: FnMut(&mut dyn ScenarioRunner)>(&mut self, mut f: F) {
         f(&mut self.vm_runner);
         if let Some(trace) = &mut self.trace {
@@ -29,7 +48,9 @@ impl ScenarioRunner for DebuggerBackend {
     }
 
     fn run_sc_call_step(&mut self, step: &mut ScCallStep) {
+        self.set_coverage_step(Some(&step.id));
         self.vm_runner.run_sc_call_step(step);
+        self.set_coverage_step(None);
         step.expect = step.response.as_ref().map(TxResponse::to_expect);
         if let Some(trace) = &mut self.trace {
             trace.run_sc_call_step(step);
@@ -47,7 +68,9 @@ impl ScenarioRunner for DebuggerBackend {
     }
 
     fn run_sc_query_step(&mut self, step: &mut ScQueryStep) {
+        self.set_coverage_step(Some(&step.id));
         self.vm_runner.run_sc_query_step(step);
+        self.set_coverage_step(None);
         step.expect = step.response.as_ref().map(TxResponse::to_expect);
         if let Some(trace) = &mut self.trace {
             trace.run_sc_query_step(step);
@@ -55,7 +78,9 @@ impl ScenarioRunner for DebuggerBackend {
     }
 
     fn run_sc_deploy_step(&mut self, step: &mut ScDeployStep) {
+        self.set_coverage_step(Some(&step.id));
         self.vm_runner.run_sc_deploy_step(step);
+        self.set_coverage_step(None);
         step.expect = step.response.as_ref().map(TxResponse::to_expect);
         if let Some(trace) = &mut self.trace {
             trace.run_sc_deploy_step(step);
@@ -92,6 +117,10 @@ impl ScenarioRunner for DebuggerBackend {
 impl DebuggerBackend {
     pub(super) fn run_scenario_file(&mut self, steps_path: &Path) {
         let mut scenario = scenario::parse_scenario(steps_path);
+        let parent_scenario = self
+            .coverage
+            .as_ref()
+            .and_then(|coverage| coverage.set_current_scenario(Some(scenario_name(steps_path))));
 
         for step in &mut scenario.steps {
             match step {
@@ -126,5 +155,16 @@ impl DebuggerBackend {
                 },
             }
         }
+
+        if let Some(coverage) = &self.coverage {
+            coverage.set_current_scenario(parent_scenario);
+        }
     }
 }
+
+fn scenario_name(steps_path: &Path) -> String {
+    steps_path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
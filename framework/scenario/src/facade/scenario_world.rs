@@ -1,6 +1,10 @@
 use multiversx_chain_scenario_format::interpret_trait::InterpretableFrom;
 use multiversx_chain_vm::world_mock::BlockchainState;
-use multiversx_sc_meta::cmd::contract::sc_config::ContractVariant;
+use multiversx_sc_meta::{
+    abi_json::ContractAbiJson,
+    cmd::contract::sc_config::ContractVariant,
+    storage_decoder::{DecodedStorage, StorageDecoder},
+};
 
 use crate::{
     api::DebugApi,
@@ -11,13 +15,13 @@ use crate::{
     },
     scenario::{run_trace::ScenarioTrace, run_vm::ScenarioVMRunner},
     scenario_format::{interpret_trait::InterpreterContext, value_interpreter::interpret_string},
-    scenario_model::BytesValue,
+    scenario_model::{AddressKey, BytesValue, Step},
     vm_go_tool::run_vm_go_tool,
 };
 use multiversx_sc_meta::find_workspace::find_current_workspace;
 use std::path::{Path, PathBuf};
 
-use super::debugger_backend::DebuggerBackend;
+use super::{debugger_backend::DebuggerBackend, BlockProductionConfig};
 
 /// A facade for contracts tests.
 ///
@@ -48,6 +52,11 @@ impl ScenarioWorld {
             backend: Backend::Debugger(DebuggerBackend {
                 vm_runner: ScenarioVMRunner::new(),
                 trace: None,
+                gateway_fork: None,
+                fork_trace_len: None,
+                coverage: None,
+                snapshots: Vec::new(),
+                block_production: BlockProductionConfig::default(),
             }),
         }
     }
@@ -220,9 +229,39 @@ impl ScenarioWorld {
         );
     }
 
+    /// Decodes the current storage of a contract, based on the storage declarations in its ABI.
+    ///
+    /// Useful for inspecting the state, as an alternative to the raw output of `dump_state_step`.
+    pub fn decode_storage<A>(&self, address: A, abi: &ContractAbiJson) -> DecodedStorage
+    where
+        AddressKey: From<A>,
+    {
+        let address_key = AddressKey::from(address);
+        let account = self
+            .get_state()
+            .accounts
+            .get(&address_key.to_vm_address())
+            .unwrap_or_else(|| panic!("account {address_key} not found"));
+        StorageDecoder::new(abi).decode_storage(
+            account
+                .storage
+                .iter()
+                .map(|(key, value)| (key.as_slice(), value.as_slice())),
+        )
+    }
+
     /// Exports current scenario to a JSON file, as created.
+    ///
+    /// If forked from a gateway, the state fetched so far is added, so that the trace can be replayed offline.
     pub fn write_scenario_trace<P: AsRef<Path>>(&mut self, file_path: P) {
-        if let Some(trace) = &mut self.get_mut_debugger_backend().trace {
+        let forked_state_step = self.forked_state_step();
+        let debugger = self.get_mut_debugger_backend();
+        if let Some(trace) = &mut debugger.trace {
+            if let Some(forked_state_step) = forked_state_step {
+                let steps = &mut trace.scenario_trace.steps;
+                let fork_index = debugger.fork_trace_len.unwrap_or_default().min(steps.len());
+                steps.insert(fork_index, Step::SetState(forked_state_step));
+            }
             trace.write_scenario_trace(file_path);
         } else {
             panic!("scenario trace no initialized")
@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use multiversx_chain_vm::world_mock::{BlockInfo, BlockRandomSeed, BlockSeedPolicy};
+
+use crate::{scenario_model::SetStateStep, ScenarioWorld};
+
+/// Round duration on mainnet.
+const DEFAULT_ROUND_DURATION_SECONDS: u64 = 6;
+
+/// Number of rounds in an epoch on mainnet, i.e. one day.
+const DEFAULT_ROUNDS_PER_EPOCH: u64 = 14_400;
+
+/// How new blocks are produced by the block helpers of the scenario world.
+#[derive(Clone, Debug)]
+pub struct BlockProductionConfig {
+    pub round_duration_seconds: u64,
+    pub rounds_per_epoch: u64,
+}
+
+impl Default for BlockProductionConfig {
+    fn default() -> Self {
+        BlockProductionConfig {
+            round_duration_seconds: DEFAULT_ROUND_DURATION_SECONDS,
+            rounds_per_epoch: DEFAULT_ROUNDS_PER_EPOCH,
+        }
+    }
+}
+
+impl BlockProductionConfig {
+    fn epoch_of_round(&self, round: u64) -> u64 {
+        round / self.rounds_per_epoch
+    }
+}
+
+impl ScenarioWorld {
+    pub fn set_round_duration(&mut self, seconds: u64) -> &mut Self {
+        assert!(seconds > 0, "round duration cannot be zero");
+        self.get_mut_debugger_backend()
+            .block_production
+            .round_duration_seconds = seconds;
+        self
+    }
+
+    pub fn set_rounds_per_epoch(&mut self, rounds: u64) -> &mut Self {
+        assert!(rounds > 0, "epoch length cannot be zero");
+        self.get_mut_debugger_backend()
+            .block_production
+            .rounds_per_epoch = rounds;
+        self
+    }
+
+    /// Produces the given number of blocks, one per round.
+    pub fn mine_blocks(&mut self, num_blocks: u64) -> &mut Self {
+        let round_duration = self.block_production().round_duration_seconds;
+        self.produce_blocks(num_blocks, num_blocks * round_duration, None)
+    }
+
+    /// Moves the block timestamp forward by the given duration,
+    /// producing one block for each round that fits in it, but at least one.
+    pub fn advance_time(&mut self, duration: Duration) -> &mut Self {
+        let seconds = duration.as_secs();
+        let num_rounds = (seconds / self.block_production().round_duration_seconds).max(1);
+        self.produce_blocks(num_rounds, seconds, None)
+    }
+
+    /// Produces blocks until the first round of the given epoch.
+    pub fn jump_to_epoch(&mut self, epoch: u64) -> &mut Self {
+        let config = self.block_production();
+        let current = &self.get_state().current_block_info;
+        assert!(
+            epoch > current.block_epoch,
+            "cannot jump to epoch {epoch}, current epoch is {}",
+            current.block_epoch
+        );
+        let num_rounds = (epoch * config.rounds_per_epoch)
+            .saturating_sub(current.block_round)
+            .max(1);
+        self.produce_blocks(
+            num_rounds,
+            num_rounds * config.round_duration_seconds,
+            Some(epoch),
+        )
+    }
+
+    /// Decides the random seeds of the blocks produced from now on.
+    ///
+    /// The current block is also re-seeded, so the policy already applies to the next transaction.
+    /// From then on, each transaction after the first one in a block also gets a new seed,
+    /// so consecutive transactions do not see the same random sequence.
+    pub fn set_block_seed_policy(&mut self, policy: BlockSeedPolicy) -> &mut Self {
+        let state = self.get_mut_state();
+        state.block_seed_policy = policy;
+        state.block_seed_per_tx = true;
+        state.last_tx_block_random_seed = None;
+        let state = self.get_state();
+        let previous_seed = state.previous_block_info.block_random_seed.clone();
+        let current_nonce = state.current_block_info.block_nonce;
+        let seed = self.next_block_random_seed(&previous_seed, current_nonce);
+        let step = SetStateStep::new()
+            .comment(&self.block_seed_comment())
+            .block_random_seed(&seed[..]);
+        self.set_state_step(step)
+    }
+
+    /// Called before each transaction. Re-seeds the current block via a `setState` step,
+    /// if a seed policy was set and a transaction already ran with the current seed.
+    pub(crate) fn reseed_block_before_tx(&mut self) {
+        let state = self.get_state();
+        if !state.block_seed_per_tx {
+            return;
+        }
+
+        let current = &state.current_block_info;
+        if state.last_tx_block_random_seed.as_ref() == Some(&current.block_random_seed) {
+            let current_seed = current.block_random_seed.clone();
+            let current_nonce = current.block_nonce;
+            let seed = self
+                .get_mut_state()
+                .block_seed_policy
+                .next_tx_seed(&current_seed, current_nonce);
+            if seed != *current_seed {
+                let step = SetStateStep::new()
+                    .comment(&self.block_seed_comment())
+                    .block_random_seed(&seed[..]);
+                self.set_state_step(step);
+            }
+        }
+
+        let state = self.get_mut_state();
+        state.last_tx_block_random_seed = Some(state.current_block_info.block_random_seed.clone());
+    }
+
+    fn next_block_random_seed(
+        &mut self,
+        parent_seed: &BlockRandomSeed,
+        block_nonce: u64,
+    ) -> BlockRandomSeed {
+        self.get_mut_state()
+            .block_seed_policy
+            .next_seed(parent_seed, block_nonce)
+    }
+
+    /// Explains the random seeds in the traced `setState` steps.
+    fn block_seed_comment(&self) -> String {
+        format!("block random seed: {}", self.get_state().block_seed_policy)
+    }
+
+    fn block_production(&self) -> BlockProductionConfig {
+        self.get_debugger_backend().block_production.clone()
+    }
+
+    /// Sets both the previous and the current block info, via a `setState` step, so it is also traced.
+    fn produce_blocks(
+        &mut self,
+        num_rounds: u64,
+        seconds: u64,
+        epoch_override: Option<u64>,
+    ) -> &mut Self {
+        let config = self.block_production();
+        let last = self.get_state().current_block_info.clone();
+
+        let current_round = last.block_round + num_rounds;
+        let current_epoch = epoch_override
+            .unwrap_or_else(|| config.epoch_of_round(current_round).max(last.block_epoch));
+
+        let previous = if num_rounds == 1 {
+            last.clone()
+        } else {
+            let nonce = last.block_nonce + num_rounds - 1;
+            BlockInfo {
+                block_timestamp: (last.block_timestamp + seconds)
+                    .saturating_sub(config.round_duration_seconds)
+                    .max(last.block_timestamp),
+                block_nonce: nonce,
+                block_round: current_round - 1,
+                block_epoch: config
+                    .epoch_of_round(current_round - 1)
+                    .clamp(last.block_epoch, current_epoch),
+                block_random_seed: Box::new(
+                    self.next_block_random_seed(&last.block_random_seed, nonce),
+                ),
+            }
+        };
+        let current_nonce = last.block_nonce + num_rounds;
+        let current = BlockInfo {
+            block_timestamp: last.block_timestamp + seconds,
+            block_nonce: current_nonce,
+            block_round: current_round,
+            block_epoch: current_epoch,
+            block_random_seed: Box::new(
+                self.next_block_random_seed(&previous.block_random_seed, current_nonce),
+            ),
+        };
+
+        let step = SetStateStep::new()
+            .comment(&self.block_seed_comment())
+            .prev_block_timestamp(previous.block_timestamp)
+            .prev_block_nonce(previous.block_nonce)
+            .prev_block_round(previous.block_round)
+            .prev_block_epoch(previous.block_epoch)
+            .prev_block_random_seed(&previous.block_random_seed[..])
+            .block_timestamp(current.block_timestamp)
+            .block_nonce(current.block_nonce)
+            .block_round(current.block_round)
+            .block_epoch(current.block_epoch)
+            .block_random_seed(&current.block_random_seed[..]);
+        self.set_state_step(step)
+    }
+}
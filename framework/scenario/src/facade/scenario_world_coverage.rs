@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use multiversx_sc::contract_base::ContractAbiProvider;
+
+use crate::{
+    scenario::run_coverage::ScenarioCoverage, scenario_format::value_interpreter::interpret_string,
+    ScenarioWorld,
+};
+
+impl ScenarioWorld {
+    /// Records the endpoints hit by all subsequent steps in the given coverage.
+    ///
+    /// The same coverage can be shared by several worlds.
+    pub fn start_coverage(&mut self, coverage: &ScenarioCoverage) -> &mut Self {
+        let debugger = self.get_mut_debugger_backend();
+        debugger
+            .vm_runner
+            .blockchain_mock
+            .vm
+            .set_execution_observer(Some(Arc::new(coverage.clone())));
+        debugger.coverage = Some(coverage.clone());
+        self
+    }
+
+    /// Tracks the endpoints of the contract with the given code expression,
+    /// the same one used in `register_contract`.
+    ///
+    /// Source lines for lcov are searched for in the `src` folder of the current directory.
+    pub fn register_coverage_contract<Abi: ContractAbiProvider>(
+        &mut self,
+        expression: &str,
+    ) -> &mut Self {
+        let code = interpret_string(expression, &self.interpreter_context());
+        let src_dir = self.current_dir.join("src");
+        self.get_debugger_backend()
+            .coverage
+            .as_ref()
+            .expect("coverage not started, call `start_coverage` first")
+            .add_contract(&Abi::abi(), code, src_dir);
+        self
+    }
+}
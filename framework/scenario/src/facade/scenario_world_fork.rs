@@ -0,0 +1,145 @@
+use std::{path::Path, sync::Arc};
+
+use multiversx_chain_scenario_format::interpret_trait::IntoRaw;
+use multiversx_chain_vm::{types::VMAddress, world_mock::AccountData};
+use multiversx_sc::contract_base::CallableContractBuilder;
+use num_traits::Zero;
+
+use crate::{
+    fork::GatewayFork,
+    scenario_model::{
+        Account, AddressKey, BigUintValue, BytesKey, BytesValue, Scenario, SetStateStep, Step,
+        U64Value,
+    },
+    ScenarioWorld,
+};
+
+impl ScenarioWorld {
+    /// Accounts missing from the local state will be fetched through the gateway, when first needed.
+    /// Their storage values and ESDT balances are also fetched individually, when first needed.
+    ///
+    /// Local state always takes precedence, so forked accounts can be overridden in `setState` steps.
+    /// Nothing gets fetched for the overridden accounts.
+    pub fn fork_from_gateway(&mut self, gateway_fork: GatewayFork) -> &mut Self {
+        let gateway_fork = Arc::new(gateway_fork);
+        self.get_mut_state().set_fork_source(gateway_fork.clone());
+        let debugger = self.get_mut_debugger_backend();
+        debugger.fork_trace_len = debugger
+            .trace
+            .as_ref()
+            .map(|trace| trace.scenario_trace.steps.len());
+        debugger.gateway_fork = Some(gateway_fork);
+        self
+    }
+
+    fn get_gateway_fork(&self) -> &GatewayFork {
+        self.get_debugger_backend()
+            .gateway_fork
+            .as_deref()
+            .expect("world not forked, call `fork_from_gateway` first")
+    }
+
+    /// Links the code deployed at a forked address to a contract implementation.
+    pub fn register_forked_contract<A, B>(&mut self, address: A, contract_builder: B)
+    where
+        AddressKey: From<A>,
+        B: CallableContractBuilder,
+    {
+        let address_key = AddressKey::from(address);
+        let code = self
+            .get_state()
+            .fetch_forked_account(&address_key.to_vm_address())
+            .and_then(|account| account.contract_path)
+            .unwrap_or_else(|| panic!("no contract found at forked address {address_key}"));
+        self.register_contract(&format!("0x{}", hex::encode(code)), contract_builder);
+    }
+
+    /// Everything fetched so far from the forked chain, as it was there, in a `setState` step.
+    pub(crate) fn forked_state_step(&self) -> Option<SetStateStep> {
+        let fetched_state = self.get_state().fork.as_ref()?.fetched_state();
+        let mut set_state_step =
+            SetStateStep::new().comment(&self.get_gateway_fork().description());
+        for (address, account_data) in &fetched_state.accounts {
+            if let Some(account_data) = account_data {
+                set_state_step
+                    .accounts
+                    .insert(address_key(address), forked_account(account_data));
+            }
+        }
+        for ((address, key), value) in &fetched_state.storage {
+            if let Some(account) = set_state_step.accounts.get_mut(&address_key(address)) {
+                if !value.is_empty() {
+                    account.storage.insert(
+                        BytesKey::from(format!("0x{}", hex::encode(key)).as_str()),
+                        BytesValue::from(value.as_slice()),
+                    );
+                }
+            }
+        }
+        for ((address, token_identifier, nonce), balance) in &fetched_state.esdt_balances {
+            if let Some(account) = set_state_step.accounts.get_mut(&address_key(address)) {
+                if !balance.is_zero() {
+                    let token_expr = format!("str:{}", String::from_utf8_lossy(token_identifier));
+                    *account = std::mem::take(account).esdt_nft_balance(
+                        token_expr.as_str(),
+                        *nonce,
+                        balance,
+                        None::<&str>,
+                    );
+                }
+            }
+        }
+        Some(set_state_step)
+    }
+
+    /// Saves everything fetched so far as a `setState` scenario, for replaying the test offline.
+    ///
+    /// Contract code is saved as is, contracts need to be registered with its hex expression when replaying.
+    pub fn write_forked_state<P: AsRef<Path>>(&self, file_path: P) {
+        let set_state_step = self
+            .forked_state_step()
+            .expect("world not forked, call `fork_from_gateway` first");
+        let scenario = Scenario {
+            name: None,
+            comment: set_state_step.comment.clone(),
+            check_gas: None,
+            steps: vec![Step::SetState(set_state_step)],
+        };
+        scenario.into_raw().save_to_file(file_path);
+    }
+}
+
+fn address_key(address: &VMAddress) -> AddressKey {
+    AddressKey::from(&multiversx_sc::types::Address::from(*address.as_array()))
+}
+
+fn forked_account(account_data: &AccountData) -> Account {
+    let mut account = Account::new()
+        .nonce(U64Value::from(account_data.nonce))
+        .balance(BigUintValue::from(&account_data.egld_balance));
+    if !account_data.username.is_empty() {
+        account.username = Some(BytesValue::from(account_data.username.as_slice()));
+    }
+    if let Some(code) = &account_data.contract_path {
+        account = account.code(BytesValue::from(code.as_slice()));
+    }
+    if let Some(owner) = &account_data.contract_owner {
+        account = account.owner(&multiversx_sc::types::Address::from(*owner.as_array()));
+    }
+    if !account_data.developer_rewards.is_zero() {
+        account.developer_rewards = Some(BigUintValue::from(&account_data.developer_rewards));
+    }
+    for (token_identifier, esdt_data) in account_data.esdt.iter() {
+        let roles = esdt_data
+            .roles
+            .get()
+            .into_iter()
+            .map(|role| String::from_utf8_lossy(&role).into_owned())
+            .collect::<Vec<_>>();
+        if !roles.is_empty() {
+            let token_expr = format!("str:{}", String::from_utf8_lossy(token_identifier));
+            account = account.esdt_roles(token_expr.as_str(), roles);
+        }
+    }
+    account
+}
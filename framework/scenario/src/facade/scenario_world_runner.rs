@@ -31,23 +31,47 @@ impl ScenarioRunner for ScenarioWorld {
     }
 
     fn run_sc_call_step(&mut self, step: &mut ScCallStep) {
+        self.reseed_block_before_tx();
+        self.get_debugger_backend()
+            .set_coverage_step(Some(&step.id));
         self.for_each_runner_mut(|runner| runner.run_sc_call_step(step));
+        self.get_debugger_backend().set_coverage_step(None);
     }
 
     fn run_multi_sc_call_step(&mut self, steps: &mut [ScCallStep]) {
+        if self.get_state().block_seed_per_tx {
+            // each transaction needs its own seed, traced before it
+            for step in steps {
+                self.run_sc_call_step(step);
+            }
+            return;
+        }
         self.for_each_runner_mut(|runner| runner.run_multi_sc_call_step(steps));
     }
 
     fn run_multi_sc_deploy_step(&mut self, steps: &mut [ScDeployStep]) {
+        if self.get_state().block_seed_per_tx {
+            for step in steps {
+                self.run_sc_deploy_step(step);
+            }
+            return;
+        }
         self.for_each_runner_mut(|runner| runner.run_multi_sc_deploy_step(steps));
     }
 
     fn run_sc_query_step(&mut self, step: &mut ScQueryStep) {
+        self.get_debugger_backend()
+            .set_coverage_step(Some(&step.id));
         self.for_each_runner_mut(|runner| runner.run_sc_query_step(step));
+        self.get_debugger_backend().set_coverage_step(None);
     }
 
     fn run_sc_deploy_step(&mut self, step: &mut ScDeployStep) {
+        self.reseed_block_before_tx();
+        self.get_debugger_backend()
+            .set_coverage_step(Some(&step.id));
         self.for_each_runner_mut(|runner| runner.run_sc_deploy_step(step));
+        self.get_debugger_backend().set_coverage_step(None);
     }
 
     fn run_transfer_step(&mut self, step: &TransferStep) {
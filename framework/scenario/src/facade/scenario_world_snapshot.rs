@@ -0,0 +1,71 @@
+use multiversx_chain_vm::world_mock::BlockchainState;
+
+use crate::ScenarioWorld;
+
+use super::{debugger_backend::DebuggerBackend, scenario_world::Backend};
+
+/// Identifies a saved state of a [`ScenarioWorld`].
+///
+/// Only valid for the world that produced it, or for worlds forked from it afterwards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SnapshotId(usize);
+
+#[derive(Clone)]
+pub(crate) struct WorldSnapshot {
+    state: BlockchainState,
+    trace_len: Option<usize>,
+}
+
+impl ScenarioWorld {
+    /// Saves the current blockchain state, so it can later be restored with `revert_to`.
+    ///
+    /// Snapshots are not consumed when reverting, so several tests can branch from the same one.
+    pub fn snapshot(&mut self) -> SnapshotId {
+        let debugger = self.get_mut_debugger_backend();
+        let snapshot = WorldSnapshot {
+            state: debugger.vm_runner.blockchain_mock.state.clone(),
+            trace_len: debugger
+                .trace
+                .as_ref()
+                .map(|trace| trace.scenario_trace.steps.len()),
+        };
+        debugger.snapshots.push(snapshot);
+        SnapshotId(debugger.snapshots.len() - 1)
+    }
+
+    /// Restores the blockchain state saved by `snapshot`.
+    ///
+    /// The steps traced after the snapshot are also dropped, so the trace stays replayable.
+    pub fn revert_to(&mut self, snapshot_id: SnapshotId) -> &mut Self {
+        let debugger = self.get_mut_debugger_backend();
+        let snapshot = debugger
+            .snapshots
+            .get(snapshot_id.0)
+            .unwrap_or_else(|| panic!("unknown snapshot: {snapshot_id:?}"))
+            .clone();
+        *debugger.vm_runner.blockchain_mock.state = snapshot.state;
+        if let (Some(trace), Some(trace_len)) = (&mut debugger.trace, snapshot.trace_len) {
+            trace.scenario_trace.steps.truncate(trace_len);
+        }
+        self
+    }
+
+    /// Creates an independent copy of the world, including its registered contracts and snapshots.
+    ///
+    /// Changes to either world are not visible in the other. Coverage, if started, is shared.
+    pub fn fork(&self) -> ScenarioWorld {
+        let debugger = self.get_debugger_backend();
+        ScenarioWorld {
+            current_dir: self.current_dir.clone(),
+            backend: Backend::Debugger(DebuggerBackend {
+                vm_runner: debugger.vm_runner.fork(),
+                trace: debugger.trace.clone(),
+                gateway_fork: debugger.gateway_fork.clone(),
+                fork_trace_len: debugger.fork_trace_len,
+                coverage: debugger.coverage.clone(),
+                snapshots: debugger.snapshots.clone(),
+                block_production: debugger.block_production.clone(),
+            }),
+        }
+    }
+}
@@ -85,6 +85,7 @@ impl ScenarioWorld {
             sc_call_step.tx.function = TxFunctionName::WHITEBOX_CALL.to_string();
         }
 
+        self.reseed_block_before_tx();
         let contract_obj = (whitebox_contract.contract_obj_builder)();
         let debugger_backend = self.get_mut_debugger_backend();
         let tx_result =
@@ -126,6 +127,7 @@ impl ScenarioWorld {
         F: FnOnce(ContractObj),
         C: FnOnce(TxResult),
     {
+        self.reseed_block_before_tx();
         let contract_obj = (whitebox_contract.contract_obj_builder)();
         let debugger_backend = self.get_mut_debugger_backend();
         let (_, tx_result) = debugger_backend
@@ -0,0 +1,5 @@
+//! Running tests against state forked from a real network.
+
+mod gateway_fork;
+
+pub use gateway_fork::GatewayFork;
@@ -0,0 +1,194 @@
+use std::{fmt, future::Future};
+
+use multiversx_chain_vm::{
+    types::VMAddress,
+    world_mock::{AccountData, BlockchainForkSource},
+};
+use multiversx_sdk::{blockchain::CommunicationProxy, data::address::Address};
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+/// Fetches state from a gateway, when the VM first needs it.
+///
+/// Accounts are retrieved without their storage and ESDT balances,
+/// which are then retrieved individually, key by key and token by token, as they get accessed.
+/// Everything fetched is kept in the blockchain state, so the network is queried at most once for each of them.
+pub struct GatewayFork {
+    gateway_url: String,
+    proxy: CommunicationProxy,
+}
+
+impl GatewayFork {
+    pub fn new(gateway_url: &str) -> Self {
+        GatewayFork {
+            gateway_url: gateway_url.to_string(),
+            proxy: CommunicationProxy::new(gateway_url.to_string()),
+        }
+    }
+
+    /// Pins the forked state to a block nonce, so that test results do not depend on when they are run.
+    pub fn at_block_nonce(mut self, block_nonce: u64) -> Self {
+        self.proxy = self.proxy.with_block_nonce(block_nonce);
+        self
+    }
+
+    pub fn gateway_url(&self) -> &str {
+        &self.gateway_url
+    }
+
+    pub fn block_nonce(&self) -> Option<u64> {
+        self.proxy.block_nonce()
+    }
+
+    /// Describes where the state comes from.
+    pub fn description(&self) -> String {
+        match self.block_nonce() {
+            Some(block_nonce) => format!(
+                "state forked from {} at block nonce {block_nonce}",
+                self.gateway_url
+            ),
+            None => format!("state forked from {}", self.gateway_url),
+        }
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output
+    where
+        F: Send,
+        F::Output: Send,
+    {
+        // the VM is synchronous, but it might itself be called from an async context,
+        // so each request gets its own thread and runtime
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .unwrap()
+                    .block_on(future)
+            })
+            .join()
+            .unwrap_or_else(|err| std::panic::resume_unwind(err))
+        })
+    }
+}
+
+fn sdk_address(address: &VMAddress) -> Address {
+    Address::from_bytes(*address.as_array())
+}
+
+fn parse_biguint(value: &str) -> BigUint {
+    if value.is_empty() {
+        return BigUint::zero();
+    }
+    value
+        .parse()
+        .unwrap_or_else(|err| panic!("invalid number {value}: {err}"))
+}
+
+impl BlockchainForkSource for GatewayFork {
+    fn fetch_account(&self, address: &VMAddress) -> Option<AccountData> {
+        let sdk_address = sdk_address(address);
+        let addr = sdk_address.to_string();
+        let (account, esdt_roles) = self.block_on(async {
+            let account = self
+                .proxy
+                .get_account(&sdk_address)
+                .await
+                .unwrap_or_else(|err| panic!("failed to retrieve account {addr}: {err}"));
+            let esdt_roles = self
+                .proxy
+                .get_account_esdt_roles(&sdk_address)
+                .await
+                .unwrap_or_else(|err| {
+                    panic!("failed to retrieve ESDT roles for address {addr}: {err}")
+                });
+            (account, esdt_roles)
+        });
+
+        let egld_balance = parse_biguint(&account.balance);
+        let has_data = account
+            .root_hash
+            .as_ref()
+            .map_or(false, |root_hash| !root_hash.is_empty());
+        if account.nonce == 0
+            && egld_balance.is_zero()
+            && account.code.is_empty()
+            && !has_data
+            && esdt_roles.is_empty()
+        {
+            return None;
+        }
+
+        let mut account_data = AccountData::new_empty(address.clone());
+        account_data.nonce = account.nonce;
+        account_data.egld_balance = egld_balance;
+        account_data.username = account.username.into_bytes();
+        if !account.code.is_empty() {
+            account_data.contract_path = Some(
+                hex::decode(&account.code)
+                    .unwrap_or_else(|err| panic!("invalid code for address {addr}: {err}")),
+            );
+        }
+        account_data.contract_owner =
+            account
+                .owner_address
+                .filter(|owner| !owner.is_empty())
+                .map(|owner| {
+                    let owner = Address::from_bech32_string(&owner)
+                        .unwrap_or_else(|err| panic!("invalid owner {owner}: {err}"));
+                    VMAddress::from(owner.to_bytes())
+                });
+        if let Some(developer_reward) = &account.developer_reward {
+            account_data.developer_rewards = parse_biguint(developer_reward);
+        }
+        for (token_identifier, roles) in esdt_roles {
+            account_data.esdt.set_roles(
+                token_identifier.into_bytes(),
+                roles.into_iter().map(String::into_bytes).collect(),
+            );
+        }
+        Some(account_data)
+    }
+
+    fn fetch_storage_value(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
+        let sdk_address = sdk_address(address);
+        let addr = sdk_address.to_string();
+        let key_hex = hex::encode(key);
+        let value_hex = self
+            .block_on(self.proxy.get_account_storage_value(&sdk_address, &key_hex))
+            .unwrap_or_else(|err| {
+                panic!("failed to retrieve storage key {key_hex} for address {addr}: {err}")
+            });
+        hex::decode(&value_hex)
+            .unwrap_or_else(|err| panic!("invalid storage value {value_hex}: {err}"))
+    }
+
+    fn fetch_esdt_balance(
+        &self,
+        address: &VMAddress,
+        token_identifier: &[u8],
+        nonce: u64,
+    ) -> BigUint {
+        let sdk_address = sdk_address(address);
+        let addr = sdk_address.to_string();
+        let token_identifier = String::from_utf8_lossy(token_identifier);
+        let esdt_balance = self
+            .block_on(
+                self.proxy
+                    .get_account_esdt_token(&sdk_address, &token_identifier, nonce),
+            )
+            .unwrap_or_else(|err| {
+                panic!("failed to retrieve ESDT {token_identifier} for address {addr}: {err}")
+            });
+        parse_biguint(&esdt_balance.balance)
+    }
+}
+
+impl fmt::Debug for GatewayFork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GatewayFork")
+            .field("gateway_url", &self.gateway_url)
+            .field("block_nonce", &self.block_nonce())
+            .finish()
+    }
+}
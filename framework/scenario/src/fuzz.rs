@@ -0,0 +1,9 @@
+mod contract_fuzzer;
+mod fuzz_arg_gen;
+mod fuzz_call;
+mod fuzz_rng;
+
+pub use contract_fuzzer::{ContractFuzzer, FuzzFailure};
+pub use fuzz_arg_gen::AbiArgGenerator;
+pub use fuzz_call::{FuzzCall, FuzzPayment};
+pub use fuzz_rng::FuzzRng;
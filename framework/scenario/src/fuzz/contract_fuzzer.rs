@@ -0,0 +1,439 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use multiversx_chain_vm::world_mock::{AccountData, BlockchainState};
+use multiversx_sc::{
+    abi::{ContractAbi, EndpointAbi, EndpointMutabilityAbi},
+    types::Address,
+};
+
+use super::{AbiArgGenerator, FuzzCall, FuzzPayment, FuzzRng};
+use crate::{
+    scenario::{model::*, ScenarioRunner},
+    scenario_format::serde_raw::ValueSubTree,
+    ScenarioWorld,
+};
+
+/// Accepts any ESDT, in the ABI `payableInTokens` list.
+const ANY_TOKEN: &str = "*";
+
+const EGLD_TOKEN: &str = "EGLD";
+
+const FILE_PREFIX: &str = "file:";
+
+type Invariant = Box<dyn Fn(&mut ScenarioWorld) -> bool>;
+
+/// Runs random sequences of well-typed endpoint calls against a contract, in the debugger,
+/// checking invariants after every call.
+///
+/// Each run starts from a fresh world, produced by the world function and the setup.
+/// Failing sequences are shrunk to a minimal one, which can be saved as a `.scen.json` reproduction.
+/// Replaying the reproduction fails on its last step, a `checkState` of the last state in which the invariant held.
+pub struct ContractFuzzer {
+    new_world: Box<dyn Fn() -> ScenarioWorld>,
+    setup: Box<dyn Fn(&mut ScenarioWorld)>,
+    abi: ContractAbi,
+    contract: AddressValue,
+    callers: Vec<AddressValue>,
+    egld_payment: Option<u64>,
+    esdt_payments: Vec<(String, u64)>,
+    excluded_endpoints: Vec<String>,
+    invariants: Vec<(String, Invariant)>,
+    seed: u64,
+    num_runs: usize,
+    calls_per_run: usize,
+    repro_path: Option<PathBuf>,
+}
+
+/// An invariant violation, with the shrunk call sequence that causes it.
+#[derive(Debug)]
+pub struct FuzzFailure {
+    pub invariant: String,
+    pub seed: u64,
+    pub calls: Vec<FuzzCall>,
+    pub repro_path: Option<PathBuf>,
+}
+
+impl Display for FuzzFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "invariant `{}` violated after {} call(s) (seed {}):",
+            self.invariant,
+            self.calls.len(),
+            self.seed
+        )?;
+        for (index, call) in self.calls.iter().enumerate() {
+            writeln!(f, "  {}. {call}", index + 1)?;
+        }
+        if let Some(repro_path) = &self.repro_path {
+            write!(f, "reproduction written to {}", repro_path.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl ContractFuzzer {
+    /// The world function should only create the world and register contracts,
+    /// all steps go in the setup, so that they also end up in the reproduction.
+    pub fn new<W, A>(new_world: W, abi: ContractAbi, contract: A) -> Self
+    where
+        W: Fn() -> ScenarioWorld + 'static,
+        AddressValue: From<A>,
+    {
+        ContractFuzzer {
+            new_world: Box::new(new_world),
+            setup: Box::new(|_| {}),
+            abi,
+            contract: AddressValue::from(contract),
+            callers: Vec::new(),
+            egld_payment: None,
+            esdt_payments: Vec::new(),
+            excluded_endpoints: Vec::new(),
+            invariants: Vec::new(),
+            seed: 0,
+            num_runs: 10,
+            calls_per_run: 10,
+            repro_path: None,
+        }
+    }
+
+    /// Steps that bring the world to its initial state: accounts, deploy, etc.
+    pub fn setup<S>(mut self, setup: S) -> Self
+    where
+        S: Fn(&mut ScenarioWorld) + 'static,
+    {
+        self.setup = Box::new(setup);
+        self
+    }
+
+    /// Adds an account that can be used as caller. Its address may also appear in arguments.
+    pub fn caller<A>(mut self, address: A) -> Self
+    where
+        AddressValue: From<A>,
+    {
+        self.callers.push(AddressValue::from(address));
+        self
+    }
+
+    /// Payable endpoints will randomly receive up to this amount of EGLD.
+    pub fn egld_payment(mut self, max_amount: u64) -> Self {
+        self.egld_payment = Some(max_amount);
+        self
+    }
+
+    /// Endpoints payable in this token will randomly receive up to this amount.
+    pub fn esdt_payment(mut self, token_id: &str, max_amount: u64) -> Self {
+        self.esdt_payments.push((token_id.to_string(), max_amount));
+        self
+    }
+
+    pub fn exclude_endpoint(mut self, endpoint_name: &str) -> Self {
+        self.excluded_endpoints.push(endpoint_name.to_string());
+        self
+    }
+
+    /// Checked after setup and after every call. Should return `false` when violated.
+    pub fn invariant<F>(mut self, name: &str, invariant: F) -> Self
+    where
+        F: Fn(&mut ScenarioWorld) -> bool + 'static,
+    {
+        self.invariants
+            .push((name.to_string(), Box::new(invariant)));
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn runs(mut self, num_runs: usize) -> Self {
+        self.num_runs = num_runs;
+        self
+    }
+
+    pub fn calls_per_run(mut self, calls_per_run: usize) -> Self {
+        self.calls_per_run = calls_per_run;
+        self
+    }
+
+    /// Where to save the minimal failing sequence, as a scenario trace.
+    ///
+    /// Relative paths are resolved from the temp directory.
+    /// Code paths in the file are made absolute, so it can be replayed from anywhere.
+    pub fn repro_path<P: AsRef<Path>>(mut self, repro_path: P) -> Self {
+        self.repro_path = Some(repro_path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Runs all sequences, crashes with the shrunk sequence if an invariant is violated.
+    pub fn run(&self) {
+        if let Err(failure) = self.try_run() {
+            panic!("{failure}");
+        }
+    }
+
+    pub fn try_run(&self) -> Result<(), FuzzFailure> {
+        assert!(!self.callers.is_empty(), "no callers configured");
+
+        let mut rng = FuzzRng::new(self.seed);
+        for _ in 0..self.num_runs {
+            let calls = self.generate_calls(&mut rng);
+            if let Some((invariant, num_calls)) = self.find_violation(&calls) {
+                let calls = self.shrink(calls[..num_calls].to_vec(), &invariant);
+                let repro_path = self
+                    .repro_path
+                    .as_ref()
+                    .map(|repro_path| self.write_repro(&calls, &invariant, repro_path));
+                return Err(FuzzFailure {
+                    invariant,
+                    seed: self.seed,
+                    calls,
+                    repro_path,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn fuzzed_endpoints(&self) -> Vec<&EndpointAbi> {
+        self.abi
+            .endpoints
+            .iter()
+            .filter(|endpoint| matches!(endpoint.mutability, EndpointMutabilityAbi::Mutable))
+            .filter(|endpoint| !self.excluded_endpoints.contains(&endpoint.name))
+            .collect()
+    }
+
+    fn generate_calls(&self, rng: &mut FuzzRng) -> Vec<FuzzCall> {
+        let endpoints = self.fuzzed_endpoints();
+        let mut addresses: Vec<Address> = self.callers.iter().map(|c| c.to_address()).collect();
+        addresses.push(self.contract.to_address());
+        let token_ids: Vec<String> = self
+            .esdt_payments
+            .iter()
+            .map(|(token_id, _)| token_id.clone())
+            .collect();
+        let arg_generator =
+            AbiArgGenerator::new(&self.abi.type_descriptions, &addresses, &token_ids);
+
+        let mut calls = Vec::new();
+        for _ in 0..self.calls_per_run {
+            let Some(endpoint) = rng.choose(&endpoints) else {
+                break;
+            };
+            calls.push(FuzzCall {
+                caller: rng.choose(&self.callers).unwrap().clone(),
+                endpoint: endpoint.name.clone(),
+                arguments: arg_generator.generate_inputs(&endpoint.inputs, rng),
+                payment: self.generate_payment(endpoint, rng),
+            });
+        }
+        calls
+    }
+
+    fn generate_payment(&self, endpoint: &EndpointAbi, rng: &mut FuzzRng) -> FuzzPayment {
+        let accepts = |token_id: &str| {
+            endpoint
+                .payable_in_tokens
+                .iter()
+                .any(|payable| payable == token_id || payable == ANY_TOKEN)
+        };
+
+        let mut options = Vec::new();
+        if let Some(max_amount) = self.egld_payment {
+            if accepts(EGLD_TOKEN) {
+                options.push(FuzzPayment::Egld(rng.up_to(max_amount)));
+            }
+        }
+        for (token_id, max_amount) in &self.esdt_payments {
+            if accepts(token_id) {
+                options.push(FuzzPayment::Esdt {
+                    token_id: token_id.clone(),
+                    amount: rng.up_to(*max_amount),
+                });
+            }
+        }
+
+        if options.is_empty() || rng.one_in(4) {
+            return FuzzPayment::None;
+        }
+        rng.choose(&options).unwrap().clone()
+    }
+
+    fn check_invariants(&self, world: &mut ScenarioWorld) -> Option<String> {
+        self.invariants
+            .iter()
+            .find(|(_, invariant)| !invariant(world))
+            .map(|(name, _)| name.clone())
+    }
+
+    /// Yields the violated invariant and the number of calls it took to violate it.
+    fn find_violation(&self, calls: &[FuzzCall]) -> Option<(String, usize)> {
+        let mut world = (self.new_world)();
+        (self.setup)(&mut world);
+        if let Some(invariant) = self.check_invariants(&mut world) {
+            return Some((invariant, 0));
+        }
+
+        for (index, call) in calls.iter().enumerate() {
+            world.sc_call(call.to_step(&self.contract));
+            if let Some(invariant) = self.check_invariants(&mut world) {
+                return Some((invariant, index + 1));
+            }
+        }
+        None
+    }
+
+    /// Removes calls and payments for as long as the same invariant is still violated.
+    fn shrink(&self, mut calls: Vec<FuzzCall>, invariant: &str) -> Vec<FuzzCall> {
+        loop {
+            let mut candidates = Vec::new();
+            for index in 0..calls.len() {
+                let mut candidate = calls.clone();
+                candidate.remove(index);
+                candidates.push(candidate);
+            }
+            for (index, call) in calls.iter().enumerate() {
+                if call.payment != FuzzPayment::None {
+                    let mut candidate = calls.clone();
+                    candidate[index].payment = FuzzPayment::None;
+                    candidates.push(candidate);
+                }
+            }
+
+            let smaller = candidates.into_iter().find_map(|candidate| {
+                match self.find_violation(&candidate) {
+                    Some((violated, num_calls)) if violated == invariant => {
+                        Some(candidate[..num_calls].to_vec())
+                    },
+                    _ => None,
+                }
+            });
+            match smaller {
+                Some(smaller) => calls = smaller,
+                None => return calls,
+            }
+        }
+    }
+
+    /// Returns the path of the written file.
+    ///
+    /// The call that violates the invariant is followed by a `checkState` of the state right before it,
+    /// which is the last state in which the invariant held, so replaying the file fails there.
+    /// Only balances and storage are checked, a violating call that changes nothing else replays without failing,
+    /// same as an invariant violated by the setup alone.
+    fn write_repro(&self, calls: &[FuzzCall], invariant: &str, repro_path: &Path) -> PathBuf {
+        let mut world = (self.new_world)();
+        let repro_path = std::env::temp_dir().join(repro_path);
+        world.start_trace();
+        (self.setup)(&mut world);
+        let mut invariant_held_step = None;
+        for (index, call) in calls.iter().enumerate() {
+            let mut step = call.to_step(&self.contract);
+            if index == calls.len() - 1 {
+                step.comment = Some(format!("violates invariant `{invariant}`"));
+                invariant_held_step = Some(state_check_step(
+                    world.get_state(),
+                    format!("last state in which invariant `{invariant}` held"),
+                ));
+            }
+            world.sc_call(step);
+        }
+
+        let current_dir = world.current_dir().clone();
+        let trace = world
+            .get_mut_debugger_backend()
+            .trace
+            .as_mut()
+            .expect("scenario trace not initialized");
+        if let Some(invariant_held_step) = &invariant_held_step {
+            // only recorded, running it would fail
+            trace.run_check_state_step(invariant_held_step);
+        }
+        for step in &mut trace.scenario_trace.steps {
+            match step {
+                Step::SetState(set_state_step) => {
+                    for account in set_state_step.accounts.values_mut() {
+                        if let Some(code) = &mut account.code {
+                            absolute_code_path(code, &current_dir);
+                        }
+                    }
+                },
+                Step::ScDeploy(sc_deploy_step) => {
+                    absolute_code_path(&mut sc_deploy_step.tx.contract_code, &current_dir);
+                },
+                _ => {},
+            }
+        }
+        world.write_scenario_trace(&repro_path);
+        repro_path
+    }
+}
+
+/// Balances and storage of all accounts, other accounts may appear afterwards.
+fn state_check_step(state: &BlockchainState, comment: String) -> CheckStateStep {
+    let mut check_state_step = CheckStateStep::new();
+    check_state_step.comment = Some(comment);
+    check_state_step.accounts.other_accounts_allowed = true;
+    for (address, account_data) in &state.accounts {
+        let address = Address::from(*address.as_array());
+        check_state_step
+            .accounts
+            .accounts
+            .insert(AddressKey::from(&address), account_check(account_data));
+    }
+    check_state_step
+}
+
+fn account_check(account_data: &AccountData) -> CheckAccount {
+    let mut account_check =
+        CheckAccount::new().balance(BigUintValue::from(&account_data.egld_balance));
+    let mut storages = BTreeMap::new();
+    for (key, value) in &account_data.storage {
+        if !value.is_empty() {
+            storages.insert(
+                BytesKey::from(format!("0x{}", hex::encode(key)).as_str()),
+                CheckValue::Equal(BytesValue::from(value.as_slice())),
+            );
+        }
+    }
+    account_check.storage = CheckStorage::Equal(CheckStorageDetails {
+        storages,
+        other_storages_allowed: false,
+    });
+
+    let mut esdt_checks = BTreeMap::new();
+    for (token_identifier, esdt_data) in account_data.esdt.iter() {
+        let instances = esdt_data.instances.get_instances();
+        if let Some(instance) = instances.get(&0) {
+            if instances.len() == 1 {
+                let token_expr = format!("str:{}", String::from_utf8_lossy(token_identifier));
+                esdt_checks.insert(
+                    BytesKey::from(token_expr.as_str()),
+                    CheckEsdt::Short(BigUintValue::from(&instance.balance)),
+                );
+            }
+        }
+    }
+    if !esdt_checks.is_empty() {
+        account_check.esdt = CheckEsdtMap::Equal(CheckEsdtMapContents {
+            contents: esdt_checks,
+            other_esdts_allowed: true,
+        });
+    }
+    account_check
+}
+
+fn absolute_code_path(code: &mut BytesValue, current_dir: &Path) {
+    if let ValueSubTree::Str(expr) = &code.original {
+        if let Some(relative_path) = expr.strip_prefix(FILE_PREFIX) {
+            let absolute_path = current_dir.join(relative_path);
+            code.original = ValueSubTree::Str(format!("{FILE_PREFIX}{}", absolute_path.display()));
+        }
+    }
+}
@@ -0,0 +1,378 @@
+use multiversx_sc::{
+    abi::{InputAbi, StructFieldDescription, TypeContents, TypeDescriptionContainerImpl},
+    types::Address,
+};
+use multiversx_sc_meta::abi_json::AbiTypeName;
+
+use super::FuzzRng;
+
+/// Beyond this depth, options are always empty and lists have no items, to keep recursive types finite.
+const MAX_DEPTH: usize = 4;
+
+/// Maximum number of items generated for lists and variadic arguments.
+const MAX_ITEMS: u64 = 3;
+
+/// Maximum length of generated byte buffers and strings.
+const MAX_BUFFER_LEN: u64 = 16;
+
+/// Generates random, but well-typed, endpoint arguments, based only on the ABI.
+///
+/// Numbers are biased towards edge cases (zero, one, maximum).
+/// Addresses and token identifiers are mostly picked from the known ones,
+/// so that calls have a chance of getting past the basic checks.
+pub struct AbiArgGenerator<'a> {
+    types: &'a TypeDescriptionContainerImpl,
+    addresses: &'a [Address],
+    token_ids: &'a [String],
+}
+
+impl<'a> AbiArgGenerator<'a> {
+    pub fn new(
+        types: &'a TypeDescriptionContainerImpl,
+        addresses: &'a [Address],
+        token_ids: &'a [String],
+    ) -> Self {
+        AbiArgGenerator {
+            types,
+            addresses,
+            token_ids,
+        }
+    }
+
+    /// Generates top-encoded arguments for all endpoint inputs, multi-values flattened.
+    pub fn generate_inputs(&self, inputs: &[InputAbi], rng: &mut FuzzRng) -> Vec<Vec<u8>> {
+        let mut result = Vec::new();
+        for input in inputs {
+            self.generate_multi(&AbiTypeName::parse(&input.type_name), rng, &mut result);
+        }
+        result
+    }
+
+    fn generate_multi(&self, abi_type: &AbiTypeName, rng: &mut FuzzRng, result: &mut Vec<Vec<u8>>) {
+        match abi_type.name.as_str() {
+            "variadic" => {
+                for _ in 0..rng.up_to(MAX_ITEMS) {
+                    self.generate_multi_params(abi_type, rng, result);
+                }
+            },
+            "counted-variadic" => {
+                let count = rng.up_to(MAX_ITEMS);
+                result.push(top_encode_unsigned(count));
+                for _ in 0..count {
+                    self.generate_multi_params(abi_type, rng, result);
+                }
+            },
+            "optional" => {
+                if rng.one_in(2) {
+                    self.generate_multi_params(abi_type, rng, result);
+                }
+            },
+            "multi" => self.generate_multi_params(abi_type, rng, result),
+            _ => result.push(self.generate_top(abi_type, rng, 0)),
+        }
+    }
+
+    fn generate_multi_params(
+        &self,
+        abi_type: &AbiTypeName,
+        rng: &mut FuzzRng,
+        result: &mut Vec<Vec<u8>>,
+    ) {
+        for type_param in &abi_type.type_params {
+            self.generate_multi(type_param, rng, result);
+        }
+    }
+
+    /// Generates a top-encoded value of the given type.
+    pub fn generate_top(&self, abi_type: &AbiTypeName, rng: &mut FuzzRng, depth: usize) -> Vec<u8> {
+        match abi_type.name.as_str() {
+            "u8" | "u16" | "u32" | "usize" | "u64" => {
+                top_encode_unsigned(random_unsigned(rng, int_len(&abi_type.name)))
+            },
+            "i8" | "i16" | "i32" | "isize" | "i64" => {
+                top_encode_signed(random_signed(rng, int_len(&abi_type.name)))
+            },
+            "bool" => top_encode_unsigned(rng.below(2)),
+            "BigUint" => top_encode_unsigned(random_unsigned(rng, 8)),
+            "NonZeroBigUint" => top_encode_unsigned(random_unsigned(rng, 8).max(1)),
+            "BigInt" => top_encode_signed(random_signed(rng, 8)),
+            "bytes" | "utf-8 string" => random_ascii(rng),
+            "TokenIdentifier" => self.random_token_id(rng),
+            "EgldOrEsdtTokenIdentifier" => self.random_egld_or_token_id(rng),
+            "Option" => {
+                if depth >= MAX_DEPTH || rng.one_in(2) {
+                    Vec::new()
+                } else {
+                    let mut result = vec![1];
+                    self.generate_nested_param(abi_type, rng, depth, &mut result);
+                    result
+                }
+            },
+            "List" | "vec" => {
+                let mut result = Vec::new();
+                for _ in 0..self.random_len(rng, depth) {
+                    self.generate_nested_param(abi_type, rng, depth, &mut result);
+                }
+                result
+            },
+            _ => {
+                let mut result = Vec::new();
+                self.generate_nested(abi_type, rng, depth, &mut result);
+                if self.is_fieldless_first_variant(abi_type, &result) {
+                    result.clear();
+                }
+                result
+            },
+        }
+    }
+
+    /// Generates a nested-encoded value of the given type, appending it to the output.
+    pub fn generate_nested(
+        &self,
+        abi_type: &AbiTypeName,
+        rng: &mut FuzzRng,
+        depth: usize,
+        output: &mut Vec<u8>,
+    ) {
+        match abi_type.name.as_str() {
+            "u8" | "u16" | "u32" | "usize" | "u64" => {
+                let len = int_len(&abi_type.name);
+                let value = random_unsigned(rng, len);
+                output.extend_from_slice(&value.to_be_bytes()[8 - len..]);
+            },
+            "i8" | "i16" | "i32" | "isize" | "i64" => {
+                let len = int_len(&abi_type.name);
+                let value = random_signed(rng, len);
+                output.extend_from_slice(&value.to_be_bytes()[8 - len..]);
+            },
+            "bool" => output.push(rng.below(2) as u8),
+            "Address" => output.extend_from_slice(&self.random_address(rng)),
+            "H256" => output.extend_from_slice(&rng.bytes(32)),
+            "CodeMetadata" => output.extend_from_slice(&rng.bytes(2)),
+            "Option" => {
+                if depth >= MAX_DEPTH || rng.one_in(2) {
+                    output.push(0);
+                } else {
+                    output.push(1);
+                    self.generate_nested_param(abi_type, rng, depth, output);
+                }
+            },
+            "List" | "vec" => {
+                let len = self.random_len(rng, depth);
+                output.extend_from_slice(&(len as u32).to_be_bytes());
+                for _ in 0..len {
+                    self.generate_nested_param(abi_type, rng, depth, output);
+                }
+            },
+            "tuple" => {
+                for type_param in &abi_type.type_params {
+                    self.generate_nested(type_param, rng, depth + 1, output);
+                }
+            },
+            "BigUint"
+            | "NonZeroBigUint"
+            | "BigInt"
+            | "bytes"
+            | "utf-8 string"
+            | "TokenIdentifier"
+            | "EgldOrEsdtTokenIdentifier" => {
+                let top_encoded = self.generate_top(abi_type, rng, depth);
+                output.extend_from_slice(&(top_encoded.len() as u32).to_be_bytes());
+                output.extend_from_slice(&top_encoded);
+            },
+            _ => {
+                if let Some(len) = abi_type.array_len() {
+                    for _ in 0..len {
+                        self.generate_nested_param(abi_type, rng, depth, output);
+                    }
+                } else {
+                    self.generate_nested_described(&abi_type.to_string(), rng, depth, output);
+                }
+            },
+        }
+    }
+
+    fn generate_nested_param(
+        &self,
+        abi_type: &AbiTypeName,
+        rng: &mut FuzzRng,
+        depth: usize,
+        output: &mut Vec<u8>,
+    ) {
+        if let Some(type_param) = abi_type.first_param() {
+            self.generate_nested(type_param, rng, depth + 1, output);
+        }
+    }
+
+    /// Structs and enums from the ABI type descriptions. Unknown types are left empty.
+    fn generate_nested_described(
+        &self,
+        type_name: &str,
+        rng: &mut FuzzRng,
+        depth: usize,
+        output: &mut Vec<u8>,
+    ) {
+        let Some(type_description) = self.types.0.get(type_name) else {
+            return;
+        };
+        match &type_description.contents {
+            TypeContents::Struct(fields) => {
+                self.generate_nested_fields(fields, rng, depth, output);
+            },
+            TypeContents::Enum(variants) => {
+                if let Some(variant) = rng.choose(variants) {
+                    output.push(variant.discriminant as u8);
+                    self.generate_nested_fields(&variant.fields, rng, depth, output);
+                }
+            },
+            TypeContents::ExplicitEnum(variants) => {
+                if let Some(variant) = rng.choose(variants) {
+                    output.extend_from_slice(&(variant.name.len() as u32).to_be_bytes());
+                    output.extend_from_slice(variant.name.as_bytes());
+                }
+            },
+            TypeContents::NotSpecified => {},
+        }
+    }
+
+    fn generate_nested_fields(
+        &self,
+        fields: &[StructFieldDescription],
+        rng: &mut FuzzRng,
+        depth: usize,
+        output: &mut Vec<u8>,
+    ) {
+        for field in fields {
+            self.generate_nested(
+                &AbiTypeName::parse(&field.field_type),
+                rng,
+                depth + 1,
+                output,
+            );
+        }
+    }
+
+    /// Enums top-encode their first variant as empty bytes, if it has no fields.
+    fn is_fieldless_first_variant(&self, abi_type: &AbiTypeName, nested: &[u8]) -> bool {
+        if nested != [0] {
+            return false;
+        }
+        matches!(
+            self.types
+                .0
+                .get(&abi_type.to_string())
+                .map(|td| &td.contents),
+            Some(TypeContents::Enum(_))
+        )
+    }
+
+    fn random_len(&self, rng: &mut FuzzRng, depth: usize) -> u64 {
+        if depth >= MAX_DEPTH {
+            0
+        } else {
+            rng.up_to(MAX_ITEMS)
+        }
+    }
+
+    fn random_address(&self, rng: &mut FuzzRng) -> Vec<u8> {
+        match rng.choose(self.addresses) {
+            Some(address) if !rng.one_in(4) => address.as_bytes().to_vec(),
+            _ => rng.bytes(32),
+        }
+    }
+
+    fn random_token_id(&self, rng: &mut FuzzRng) -> Vec<u8> {
+        match rng.choose(self.token_ids) {
+            Some(token_id) if !rng.one_in(4) => token_id.as_bytes().to_vec(),
+            _ => b"FUZZ-123456".to_vec(),
+        }
+    }
+
+    fn random_egld_or_token_id(&self, rng: &mut FuzzRng) -> Vec<u8> {
+        if rng.one_in(3) {
+            b"EGLD".to_vec()
+        } else {
+            self.random_token_id(rng)
+        }
+    }
+}
+
+fn int_len(type_name: &str) -> usize {
+    match type_name {
+        "u8" | "i8" => 1,
+        "u16" | "i16" => 2,
+        "u32" | "i32" | "usize" | "isize" => 4,
+        _ => 8,
+    }
+}
+
+fn random_unsigned(rng: &mut FuzzRng, len: usize) -> u64 {
+    let max = if len >= 8 {
+        u64::MAX
+    } else {
+        (1u64 << (len * 8)) - 1
+    };
+    match rng.below(5) {
+        0 => 0,
+        1 => 1,
+        2 => max,
+        3 => rng.up_to(1000.min(max)),
+        _ => rng.up_to(max),
+    }
+}
+
+fn random_signed(rng: &mut FuzzRng, len: usize) -> i64 {
+    let bits = len * 8;
+    let max = if bits >= 64 {
+        i64::MAX
+    } else {
+        (1i64 << (bits - 1)) - 1
+    };
+    let min = -max - 1;
+    match rng.below(6) {
+        0 => 0,
+        1 => 1,
+        2 => -1,
+        3 => max,
+        4 => min,
+        _ => {
+            let value = rng.up_to(max as u64) as i64;
+            if rng.one_in(2) {
+                -value
+            } else {
+                value
+            }
+        },
+    }
+}
+
+fn random_ascii(rng: &mut FuzzRng) -> Vec<u8> {
+    (0..rng.up_to(MAX_BUFFER_LEN))
+        .map(|_| b'a' + rng.below(26) as u8)
+        .collect()
+}
+
+/// Minimal big-endian representation, zero is empty.
+fn top_encode_unsigned(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let first_non_zero = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    bytes[first_non_zero..].to_vec()
+}
+
+/// Minimal two's complement big-endian representation, zero is empty.
+fn top_encode_signed(value: i64) -> Vec<u8> {
+    if value == 0 {
+        return Vec::new();
+    }
+    let bytes = value.to_be_bytes();
+    let mut start = 0;
+    while start < bytes.len() - 1 {
+        let redundant = (bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0);
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    bytes[start..].to_vec()
+}
@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+use crate::scenario_model::{AddressValue, ScCallStep};
+
+/// What a fuzz call transfers along with it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FuzzPayment {
+    None,
+    Egld(u64),
+    Esdt { token_id: String, amount: u64 },
+}
+
+/// A single generated endpoint call.
+#[derive(Clone, Debug)]
+pub struct FuzzCall {
+    pub caller: AddressValue,
+    pub endpoint: String,
+    /// Top-encoded arguments.
+    pub arguments: Vec<Vec<u8>>,
+    pub payment: FuzzPayment,
+}
+
+impl FuzzCall {
+    /// The call as a scenario step. Failed transactions are allowed, so no expect section is added.
+    pub fn to_step(&self, contract: &AddressValue) -> ScCallStep {
+        let mut step = ScCallStep::new()
+            .from(&self.caller)
+            .to(contract)
+            .function(&self.endpoint)
+            .no_expect();
+        for argument in &self.arguments {
+            step = step.argument(argument.as_slice());
+        }
+        match &self.payment {
+            FuzzPayment::None => step,
+            FuzzPayment::Egld(amount) => step.egld_value(*amount),
+            FuzzPayment::Esdt { token_id, amount } => {
+                step.esdt_transfer(format!("str:{token_id}").as_str(), 0u64, *amount)
+            },
+        }
+    }
+}
+
+impl Display for FuzzCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} from {}", self.endpoint, self.caller)?;
+        let arguments: Vec<String> = self
+            .arguments
+            .iter()
+            .map(|argument| format!("0x{}", hex::encode(argument)))
+            .collect();
+        write!(f, " ({})", arguments.join(", "))?;
+        match &self.payment {
+            FuzzPayment::None => Ok(()),
+            FuzzPayment::Egld(amount) => write!(f, " paying {amount} EGLD"),
+            FuzzPayment::Esdt { token_id, amount } => write!(f, " paying {amount} {token_id}"),
+        }
+    }
+}
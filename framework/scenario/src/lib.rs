@@ -6,9 +6,12 @@ pub mod bech32;
 pub mod debug_executor;
 pub mod display_util;
 mod facade;
+pub mod fork;
+pub mod fuzz;
 pub mod managed_test_util;
 pub mod scenario;
 mod scenario_macros;
+pub mod scenario_value;
 pub mod standalone;
 pub mod test_wallets;
 mod vm_go_tool;
@@ -25,7 +28,7 @@ pub mod whitebox_legacy;
 pub use whitebox_legacy as testing_framework;
 
 pub use api::DebugApi;
-pub use multiversx_chain_vm;
+pub use multiversx_chain_vm::{self, world_mock::BlockSeedPolicy};
 
 /// Re-exporting for convenience.
 pub use num_bigint;
@@ -44,7 +47,9 @@ pub use crate::scenario as mandos_system;
 // Re-exporting the whole mandos crate for easier use in tests.
 pub use multiversx_chain_scenario_format as scenario_format;
 
-pub use facade::{ContractInfo, ScenarioWorld, WhiteboxContract};
+pub use facade::{
+    BlockProductionConfig, ContractInfo, ScenarioWorld, SnapshotId, WhiteboxContract,
+};
 
 use std::path::Path;
 
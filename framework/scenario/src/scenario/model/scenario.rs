@@ -5,7 +5,7 @@ use crate::scenario_format::{
 
 use super::Step;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Scenario {
     pub name: Option<String>,
     pub comment: Option<String>,
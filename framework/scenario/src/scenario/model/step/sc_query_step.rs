@@ -4,7 +4,8 @@ use num_traits::Zero;
 use crate::{
     api::StaticApi,
     multiversx_sc::{
-        codec::{CodecFrom, TopEncodeMulti},
+        abi::TypeAbi,
+        codec::{CodecFrom, TopEncode, TopEncodeMulti},
         types::ContractCall,
     },
     scenario::model::{AddressValue, BytesValue, TxExpect, TxQuery},
@@ -108,6 +109,13 @@ impl ScQueryStep {
         self
     }
 
+    /// Expects status "Ok" and the given single result, written in the structured scenario value syntax.
+    ///
+    /// Unlike the typed call, the expected value cannot be type-checked against the endpoint result.
+    pub fn expect_value<T: TopEncode + TypeAbi>(self, expected_value: &T) -> Self {
+        self.expect(TxExpect::ok().result_value(expected_value))
+    }
+
     /// Explicitly states that no tx expect section should be added and no checks should be performed.
     ///
     /// Note: by default a basic `TxExpect::ok()` is added, which checks that status is 0 and nothing else.
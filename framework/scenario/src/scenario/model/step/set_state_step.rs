@@ -20,6 +20,11 @@ impl SetStateStep {
         Self::default()
     }
 
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.comment = Some(comment.to_string());
+        self
+    }
+
     pub fn put_account<A>(mut self, address_expr: A, account: Account) -> Self
     where
         AddressKey: From<A>,
@@ -2,7 +2,10 @@ use std::marker::PhantomData;
 
 use multiversx_sc::codec::PanicErrorHandler;
 
-use crate::multiversx_sc::codec::{CodecFrom, TopEncodeMulti};
+use crate::multiversx_sc::{
+    abi::TypeAbi,
+    codec::{CodecFrom, TopEncode, TopEncodeMulti},
+};
 
 use crate::{
     scenario::model::{AddressValue, U64Value},
@@ -114,6 +117,16 @@ impl<OriginalResult> TypedScCall<OriginalResult> {
         self.expect(format_expect(expected_value))
     }
 
+    /// Same as `expect_value`, but the expected value is written in the structured scenario value syntax,
+    /// so that it stays readable in generated scenarios.
+    pub fn expect_structured_value<ExpectedResult>(self, expected_value: ExpectedResult) -> Self
+    where
+        OriginalResult: TopEncodeMulti,
+        ExpectedResult: CodecFrom<OriginalResult> + TopEncode + TypeAbi,
+    {
+        self.expect(TxExpect::ok().result_value(&expected_value))
+    }
+
     /// Unwraps the response, if available.
     pub fn response(&self) -> &TxResponse {
         self.sc_call_step.response()
@@ -1,11 +1,13 @@
 use super::TxResponse;
 use crate::{
+    multiversx_sc::{abi::TypeAbi, codec::TopEncode},
     scenario::model::{BytesValue, CheckLogs, CheckValue, CheckValueList, U64Value},
     scenario_format::{
         interpret_trait::{InterpretableFrom, InterpreterContext, IntoRaw},
         serde_raw::TxExpectRaw,
     },
     scenario_model::Checkable,
+    scenario_value::to_scenario_value,
 };
 use multiversx_chain_vm::tx_mock::result_values_to_string;
 
@@ -84,6 +86,23 @@ impl TxExpect {
         self
     }
 
+    /// Adds an expected result, written in the structured scenario value syntax.
+    ///
+    /// Same check as `result`, but struct fields and numbers stay readable in generated scenarios.
+    pub fn result_value<T: TopEncode + TypeAbi>(mut self, value: &T) -> Self {
+        let mut check_results = match self.out {
+            CheckValue::Star => Vec::new(),
+            CheckValue::Equal(check_results) => check_results,
+        };
+        check_results.push(CheckValue::Equal(BytesValue::interpret_from(
+            to_scenario_value(value),
+            &InterpreterContext::default(),
+        )));
+        self.out = CheckValue::Equal(check_results);
+        self.build_from_response = false;
+        self
+    }
+
     pub fn additional_error_message<A>(mut self, message: A) -> Self
     where
         A: AsRef<str>,
@@ -1,5 +1,5 @@
 use crate::multiversx_sc::types::Address;
-use multiversx_chain_vm::tx_mock::TxResult;
+use multiversx_chain_vm::tx_mock::{ReentrantCall, TxResult};
 use multiversx_sdk::{
     data::transaction::{ApiLogs, ApiSmartContractResult, Events, TransactionOnNetwork},
     utils::base64_decode,
@@ -35,6 +35,9 @@ pub struct TxResponse {
     pub api_scrs: Vec<ApiSmartContractResult>,
     /// The api logs of the transaction.
     pub api_logs: Option<ApiLogs>,
+    /// Calls into contracts that were already executing further up the call stack.
+    /// Only recorded by the debugger.
+    pub reentrant_calls: Vec<ReentrantCall>,
 }
 
 impl TxResponse {
@@ -46,6 +49,7 @@ impl TxResponse {
                 status: tx_result.result_status,
                 message: tx_result.result_message,
             },
+            reentrant_calls: tx_result.reentrant_calls,
             ..Default::default()
         }
     }
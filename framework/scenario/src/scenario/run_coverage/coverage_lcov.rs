@@ -0,0 +1,107 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::{ContractCoverage, CoverageReport};
+
+/// Endpoint function found in the sources: line number, Rust method name, hit count.
+type SourceFunction = (usize, String, usize);
+
+pub(super) fn write_lcov(report: &CoverageReport) -> String {
+    let mut result = String::new();
+    for contract in &report.contracts {
+        for (source_path, functions) in locate_endpoints(contract) {
+            write_lcov_record(&mut result, &source_path, &functions);
+        }
+    }
+    result
+}
+
+fn write_lcov_record(result: &mut String, source_path: &Path, functions: &[SourceFunction]) {
+    writeln!(result, "TN:").unwrap();
+    writeln!(result, "SF:{}", source_path.display()).unwrap();
+    for (line, name, _) in functions {
+        writeln!(result, "FN:{line},{name}").unwrap();
+    }
+    for (_, name, hit_count) in functions {
+        writeln!(result, "FNDA:{hit_count},{name}").unwrap();
+    }
+    let num_hit = functions.iter().filter(|(_, _, hits)| *hits > 0).count();
+    writeln!(result, "FNF:{}", functions.len()).unwrap();
+    writeln!(result, "FNH:{num_hit}").unwrap();
+    for (line, _, hit_count) in functions {
+        writeln!(result, "DA:{line},{hit_count}").unwrap();
+    }
+    writeln!(result, "LF:{}", functions.len()).unwrap();
+    writeln!(result, "LH:{num_hit}").unwrap();
+    writeln!(result, "end_of_record").unwrap();
+}
+
+/// Finds the declaration of each endpoint method in the contract sources.
+///
+/// Endpoints that cannot be found are left out.
+fn locate_endpoints(contract: &ContractCoverage) -> BTreeMap<PathBuf, Vec<SourceFunction>> {
+    let mut source_files = Vec::new();
+    collect_rust_files(&contract.src_dir, &mut source_files);
+    source_files.sort();
+
+    let sources: Vec<(PathBuf, String)> = source_files
+        .into_iter()
+        .filter_map(|path| fs::read_to_string(&path).ok().map(|text| (path, text)))
+        .collect();
+
+    let mut result: BTreeMap<PathBuf, Vec<SourceFunction>> = BTreeMap::new();
+    for endpoint in &contract.endpoints {
+        let location = sources.iter().find_map(|(path, text)| {
+            find_fn_line(text, &endpoint.rust_method_name).map(|line| (path, line))
+        });
+        if let Some((path, line)) = location {
+            result.entry(path.clone()).or_default().push((
+                line,
+                endpoint.rust_method_name.clone(),
+                endpoint.hit_count,
+            ));
+        }
+    }
+    for functions in result.values_mut() {
+        functions.sort();
+    }
+    result
+}
+
+fn collect_rust_files(dir: &Path, result: &mut Vec<PathBuf>) {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_rust_files(&path, result);
+        } else if path.extension().map_or(false, |ext| ext == "rs") {
+            result.push(path);
+        }
+    }
+}
+
+/// 1-based line number of the first `fn <name>` declaration.
+fn find_fn_line(text: &str, fn_name: &str) -> Option<usize> {
+    let pattern = format!("fn {fn_name}");
+    text.lines()
+        .position(|line| {
+            line.match_indices(&pattern).any(|(index, _)| {
+                let rest = &line[index + pattern.len()..];
+                let preceded_ok =
+                    index == 0 || !is_ident_char(line[..index].chars().last().unwrap());
+                let followed_ok = !rest.starts_with(is_ident_char);
+                preceded_ok && followed_ok
+            })
+        })
+        .map(|index| index + 1)
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
@@ -0,0 +1,97 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::coverage_lcov::write_lcov;
+
+/// Which endpoints of the tracked contracts were hit, and by which scenarios.
+#[derive(Clone, Debug)]
+pub struct CoverageReport {
+    pub contracts: Vec<ContractCoverage>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ContractCoverage {
+    pub name: String,
+    pub src_dir: PathBuf,
+    pub endpoints: Vec<EndpointCoverage>,
+}
+
+#[derive(Clone, Debug)]
+pub struct EndpointCoverage {
+    pub name: String,
+    pub rust_method_name: String,
+    pub hit_count: usize,
+
+    /// Scenario names, together with the step ids, where available.
+    pub scenarios: BTreeSet<String>,
+}
+
+impl CoverageReport {
+    /// Pairs of contract name and endpoint name, for all endpoints that were never executed,
+    /// neither called directly by a step, nor by another contract.
+    pub fn never_exercised(&self) -> Vec<(String, String)> {
+        self.contracts
+            .iter()
+            .flat_map(|contract| {
+                contract
+                    .endpoints
+                    .iter()
+                    .filter(|endpoint| endpoint.hit_count == 0)
+                    .map(|endpoint| (contract.name.clone(), endpoint.name.clone()))
+            })
+            .collect()
+    }
+
+    /// Human-readable per-endpoint summary, listing the endpoints never exercised first.
+    pub fn to_text(&self) -> String {
+        let mut result = String::new();
+        for contract in &self.contracts {
+            let num_hit = contract
+                .endpoints
+                .iter()
+                .filter(|endpoint| endpoint.hit_count > 0)
+                .count();
+            result.push_str(&format!(
+                "{}: {num_hit}/{} endpoints exercised\n",
+                contract.name,
+                contract.endpoints.len()
+            ));
+            for endpoint in &contract.endpoints {
+                if endpoint.hit_count == 0 {
+                    result.push_str(&format!("  never exercised: {}\n", endpoint.name));
+                }
+            }
+            for endpoint in &contract.endpoints {
+                if endpoint.hit_count > 0 {
+                    result.push_str(&format!(
+                        "  {} x{}: {}\n",
+                        endpoint.name,
+                        endpoint.hit_count,
+                        endpoint
+                            .scenarios
+                            .iter()
+                            .cloned()
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
+            }
+        }
+        result
+    }
+
+    pub fn write_text<P: AsRef<Path>>(&self, file_path: P) {
+        fs::write(file_path, self.to_text()).expect("failed to write coverage report");
+    }
+
+    /// Writes an lcov tracefile, restricted to the source files of the tracked contracts.
+    ///
+    /// Coverage is at endpoint level: each endpoint method counts as a function,
+    /// and its declaration line is the only line reported.
+    pub fn write_lcov<P: AsRef<Path>>(&self, file_path: P) {
+        fs::write(file_path, write_lcov(self)).expect("failed to write lcov file");
+    }
+}
@@ -0,0 +1,6 @@
+mod coverage_lcov;
+mod coverage_report;
+mod scenario_coverage;
+
+pub use coverage_report::*;
+pub use scenario_coverage::*;
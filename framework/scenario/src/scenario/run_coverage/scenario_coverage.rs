@@ -0,0 +1,157 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use multiversx_chain_vm::{tx_execution::ExecutionObserver, tx_mock::TxInput};
+use multiversx_sc::abi::ContractAbi;
+
+use super::{ContractCoverage, CoverageReport, EndpointCoverage};
+
+/// Used as scenario name for steps that do not come from a scenario file.
+const UNNAMED_SCENARIO: &str = "<unnamed>";
+
+/// The endpoint name used when deploying a contract.
+const INIT_ENDPOINT_NAME: &str = "init";
+
+/// Collects which contract endpoints are hit by scenario steps.
+///
+/// Hits are recorded by the VM, so endpoints reached through sync calls, async calls
+/// and promises from other contracts count too.
+///
+/// It is a shared handle, so the same coverage can be attached to several worlds,
+/// for instance one per scenario file, and reported on once at the end.
+#[derive(Clone, Default)]
+pub struct ScenarioCoverage {
+    data: Arc<Mutex<CoverageData>>,
+}
+
+#[derive(Default)]
+struct CoverageData {
+    contracts: Vec<CoveredContract>,
+    current_scenario: Option<String>,
+    current_step: Option<String>,
+    hits: BTreeMap<(String, String), EndpointHits>,
+}
+
+struct CoveredContract {
+    name: String,
+    code: Vec<u8>,
+    src_dir: PathBuf,
+    /// Pairs of endpoint name and Rust method name.
+    endpoints: Vec<(String, String)>,
+}
+
+#[derive(Default)]
+struct EndpointHits {
+    count: usize,
+    scenarios: BTreeSet<String>,
+}
+
+impl ScenarioCoverage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Endpoints of contracts with this code will be tracked.
+    ///
+    /// The source directory is only used when producing lcov output.
+    pub fn add_contract(&self, abi: &ContractAbi, code: Vec<u8>, src_dir: PathBuf) {
+        let mut data = self.data.lock().unwrap();
+        if data.contracts.iter().any(|contract| contract.code == code) {
+            return;
+        }
+
+        let endpoints = abi
+            .constructors
+            .iter()
+            .map(|constructor| {
+                (
+                    INIT_ENDPOINT_NAME.to_string(),
+                    constructor.rust_method_name.clone(),
+                )
+            })
+            .chain(
+                abi.endpoints
+                    .iter()
+                    .map(|endpoint| (endpoint.name.clone(), endpoint.rust_method_name.clone())),
+            )
+            .collect();
+        data.contracts.push(CoveredContract {
+            name: abi.name.clone(),
+            code,
+            src_dir,
+            endpoints,
+        });
+    }
+
+    /// Steps are attributed to this scenario, until it is changed.
+    ///
+    /// Returns the previous one, so that it can be restored when running nested scenarios.
+    pub fn set_current_scenario(&self, scenario: Option<String>) -> Option<String> {
+        std::mem::replace(&mut self.data.lock().unwrap().current_scenario, scenario)
+    }
+
+    /// Hits are attributed to this step of the current scenario, until it is changed.
+    pub(crate) fn set_current_step(&self, step_id: Option<&str>) {
+        self.data.lock().unwrap().current_step = step_id
+            .filter(|step_id| !step_id.is_empty())
+            .map(str::to_string);
+    }
+
+    fn record_hit(&self, code: &[u8], endpoint_name: &str) {
+        let mut data = self.data.lock().unwrap();
+        let Some(contract) = data.contracts.iter().find(|contract| contract.code == code) else {
+            return;
+        };
+        let key = (contract.name.clone(), endpoint_name.to_string());
+
+        let scenario = data
+            .current_scenario
+            .clone()
+            .or_else(|| std::thread::current().name().map(str::to_string))
+            .unwrap_or_else(|| UNNAMED_SCENARIO.to_string());
+        let scenario = match &data.current_step {
+            Some(step_id) => format!("{scenario} ({step_id})"),
+            None => scenario,
+        };
+
+        let hits = data.hits.entry(key).or_default();
+        hits.count += 1;
+        hits.scenarios.insert(scenario);
+    }
+
+    /// Summarizes the hits so far, for each tracked contract and endpoint.
+    pub fn report(&self) -> CoverageReport {
+        let data = self.data.lock().unwrap();
+        let contracts = data
+            .contracts
+            .iter()
+            .map(|contract| ContractCoverage {
+                name: contract.name.clone(),
+                src_dir: contract.src_dir.clone(),
+                endpoints: contract
+                    .endpoints
+                    .iter()
+                    .map(|(name, rust_method_name)| {
+                        let hits = data.hits.get(&(contract.name.clone(), name.clone()));
+                        EndpointCoverage {
+                            name: name.clone(),
+                            rust_method_name: rust_method_name.clone(),
+                            hit_count: hits.map_or(0, |hits| hits.count),
+                            scenarios: hits.map(|hits| hits.scenarios.clone()).unwrap_or_default(),
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+        CoverageReport { contracts }
+    }
+}
+
+impl ExecutionObserver for ScenarioCoverage {
+    fn on_contract_execution(&self, contract_code: &[u8], tx_input: &TxInput) {
+        self.record_hit(contract_code, tx_input.func_name.as_str());
+    }
+}
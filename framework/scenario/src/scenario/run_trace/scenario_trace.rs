@@ -10,7 +10,7 @@ use crate::{
 };
 use std::{collections::HashMap, path::Path};
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct ScenarioTrace {
     pub scenario_trace: Scenario,
     pub addr_to_pretty_string_map: HashMap<Address, String>,
@@ -13,6 +13,9 @@ use super::ScenarioVMRunner;
 
 impl ScenarioVMRunner {
     pub fn perform_check_state(&mut self, check_state_step: &CheckStateStep) {
+        if self.blockchain_mock.state.fork.is_some() {
+            load_forked_values(&mut self.blockchain_mock.state, &check_state_step.accounts);
+        }
         execute(&self.blockchain_mock.state, &check_state_step.accounts);
     }
 
@@ -21,6 +24,39 @@ impl ScenarioVMRunner {
     }
 }
 
+/// Values from a forked chain are only copied locally when first needed, checks also need them.
+fn load_forked_values(state: &mut BlockchainState, accounts: &CheckAccounts) {
+    for (expected_address, expected_account) in accounts.accounts.iter() {
+        let vm_address = expected_address.to_vm_address();
+        state.load_forked_account_if_missing(&vm_address);
+        if let CheckStorage::Equal(eq) = &expected_account.storage {
+            for expected_key in eq.storages.keys() {
+                state.load_forked_storage_value_if_missing(&vm_address, &expected_key.value);
+            }
+        }
+        if let CheckEsdtMap::Equal(contents) = &expected_account.esdt {
+            for (key, expected_value) in contents.contents.iter() {
+                match expected_value {
+                    CheckEsdt::Short(_) => {
+                        state.load_forked_esdt_balance_if_missing(&vm_address, &key.value, 0);
+                    },
+                    CheckEsdt::Full(expected_esdt) => {
+                        if let CheckEsdtInstances::Equal(instances) = &expected_esdt.instances {
+                            for instance in instances {
+                                state.load_forked_esdt_balance_if_missing(
+                                    &vm_address,
+                                    &key.value,
+                                    instance.nonce.value,
+                                );
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
 fn execute(state: &BlockchainState, accounts: &CheckAccounts) {
     for (expected_address, expected_account) in accounts.accounts.iter() {
         if let Some(account) = state.accounts.get(&expected_address.to_vm_address()) {
@@ -21,6 +21,27 @@ impl ScenarioVMRunner {
             blockchain_mock,
         }
     }
+
+    /// Independent copy, with its own contract map and a copy of the blockchain state.
+    ///
+    /// The execution observer, if any, is shared with the original.
+    pub fn fork(&self) -> Self {
+        let contract_map_ref = ContractMapRef::from(self.contract_map_ref.lock().clone());
+        let mut blockchain_mock = BlockchainMock::new(Box::new(contract_map_ref.clone()));
+        *blockchain_mock.state = self.blockchain_mock.state.clone();
+        blockchain_mock.vm.set_execution_observer(
+            self.blockchain_mock
+                .vm
+                .execution_observer
+                .lock()
+                .unwrap()
+                .clone(),
+        );
+        ScenarioVMRunner {
+            contract_map_ref,
+            blockchain_mock,
+        }
+    }
 }
 
 impl ScenarioRunner for ScenarioVMRunner {
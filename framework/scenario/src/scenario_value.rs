@@ -0,0 +1,41 @@
+mod scenario_value_renderer;
+mod scenario_value_serde;
+
+pub use scenario_value_renderer::ScenarioValueRenderer;
+pub use scenario_value_serde::ScenarioValue;
+
+use multiversx_sc::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeDescriptionContainerImpl},
+    codec::{top_encode_to_vec_u8_or_panic, DecodeError, TopDecode, TopEncode},
+};
+use multiversx_sc_meta::abi_json::AbiTypeName;
+
+use crate::scenario_format::{
+    interpret_trait::InterpreterContext, serde_raw::ValueSubTree,
+    value_interpreter::interpret_subtree,
+};
+
+/// Renders a value in the structured scenario value syntax, e.g. for expected results.
+///
+/// Struct fields become the entries of a JSON object, numbers and text get their type prefixes.
+/// Values that cannot be rendered based on their ABI, e.g. because of a custom encoding,
+/// are rendered as hex, so the result always interprets back to the same bytes.
+pub fn to_scenario_value<T: TopEncode + TypeAbi>(value: &T) -> ValueSubTree {
+    let bytes = top_encode_to_vec_u8_or_panic(value);
+
+    let mut types = TypeDescriptionContainerImpl::new();
+    T::provide_type_descriptions(&mut types);
+    let renderer = ScenarioValueRenderer::new(&types);
+    if let Ok(rendered) = renderer.render_top(&AbiTypeName::parse(&T::type_name()), &bytes) {
+        if interpret_subtree(&rendered, &InterpreterContext::default()) == bytes {
+            return rendered;
+        }
+    }
+
+    ValueSubTree::Str(format!("0x{}", hex::encode(bytes)))
+}
+
+/// Parses a value back from the scenario value syntax, i.e. interprets and top-decodes it.
+pub fn from_scenario_value<T: TopDecode>(value: &ValueSubTree) -> Result<T, DecodeError> {
+    T::top_decode(interpret_subtree(value, &InterpreterContext::default()))
+}
@@ -0,0 +1,340 @@
+use std::collections::BTreeMap;
+
+use multiversx_sc::abi::{
+    EnumVariantDescription, StructFieldDescription, TypeContents, TypeDescriptionContainerImpl,
+};
+use multiversx_sc_meta::{
+    abi_json::AbiTypeName,
+    abi_value::{
+        address_bech32, buffer_text, check_top_int_len, fixed_int_len, hex_repr,
+        required_first_param, take, take_len, take_length_prefixed,
+    },
+};
+use num_bigint::{BigInt, BigUint};
+
+use crate::scenario_format::{
+    interpret_trait::InterpreterContext,
+    reconstruct_trait::ReconstructorContext,
+    serde_raw::ValueSubTree,
+    value_interpreter::{interpret_string, reconstruct, ExprReconstructorHint},
+};
+
+/// Renders encoded values in the structured scenario value syntax, based on the ABI type descriptions.
+///
+/// Numbers get the prefix of their fixed width (`u32:`, `i64:`, `biguint:`) when nested,
+/// text gets the `str:` prefix, lists and tuples become JSON lists,
+/// and structs and enums become JSON objects.
+///
+/// The scenario interpreter concatenates object values in key order,
+/// so object keys are the field names, prefixed by their zero-padded index, e.g. `"01-amount"`.
+/// Enum objects start with the variant name, holding the discriminant.
+pub struct ScenarioValueRenderer<'a> {
+    types: &'a TypeDescriptionContainerImpl,
+}
+
+impl<'a> ScenarioValueRenderer<'a> {
+    pub fn new(types: &'a TypeDescriptionContainerImpl) -> Self {
+        ScenarioValueRenderer { types }
+    }
+
+    /// Renders a top-encoded value.
+    pub fn render_top(&self, abi_type: &AbiTypeName, bytes: &[u8]) -> Result<ValueSubTree, String> {
+        match abi_type.name.as_str() {
+            "u8" | "u16" | "u32" | "usize" | "u64" => {
+                check_top_int_len(abi_type, bytes, fixed_int_len(&abi_type.name))?;
+                Ok(str_value(BigUint::from_bytes_be(bytes).to_string()))
+            },
+            "i8" | "i16" | "i32" | "isize" | "i64" => {
+                check_top_int_len(abi_type, bytes, fixed_int_len(&abi_type.name))?;
+                Ok(str_value(signed_repr(bytes)))
+            },
+            "BigUint" | "NonZeroBigUint" => {
+                Ok(str_value(BigUint::from_bytes_be(bytes).to_string()))
+            },
+            "BigInt" => Ok(str_value(signed_repr(bytes))),
+            "bool" => match bytes {
+                [] => Ok(str_value("false".to_string())),
+                [1] => Ok(str_value("true".to_string())),
+                _ => Err(format!("invalid bool: 0x{}", hex::encode(bytes))),
+            },
+            "bytes" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+                Ok(str_value(buffer_repr(&abi_type.name, bytes)))
+            },
+            "Option" => {
+                if bytes.is_empty() {
+                    Ok(str_value(String::new()))
+                } else {
+                    self.render_top_entirely(abi_type, bytes)
+                }
+            },
+            "List" | "vec" => {
+                let item_type = required_first_param(abi_type)?;
+                let mut input = bytes;
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.render_nested(item_type, &mut input)?);
+                }
+                Ok(ValueSubTree::List(items))
+            },
+            _ => match self.type_contents(abi_type) {
+                Some(TypeContents::Enum(variants)) if bytes.is_empty() => {
+                    let variant = find_variant(abi_type, variants, 0)?;
+                    if !variant.fields.is_empty() {
+                        return Err(format!("missing fields of {abi_type}::{}", variant.name));
+                    }
+                    let mut entries = BTreeMap::new();
+                    entries.insert(field_key(0, 1, &variant.name), str_value(String::new()));
+                    Ok(ValueSubTree::Map(entries))
+                },
+                Some(TypeContents::Struct(fields)) => {
+                    self.render_top_struct_fields(abi_type, fields, bytes)
+                },
+                Some(TypeContents::ExplicitEnum(_)) => {
+                    Ok(str_value(buffer_repr("utf-8 string", bytes)))
+                },
+                _ => self.render_top_entirely(abi_type, bytes),
+            },
+        }
+    }
+
+    /// Types whose top encoding is the same as the nested encoding.
+    fn render_top_entirely(
+        &self,
+        abi_type: &AbiTypeName,
+        bytes: &[u8],
+    ) -> Result<ValueSubTree, String> {
+        let mut input = bytes;
+        let result = self.render_nested(abi_type, &mut input)?;
+        if !input.is_empty() {
+            return Err(format!(
+                "{} leftover bytes after rendering {abi_type}",
+                input.len()
+            ));
+        }
+        Ok(result)
+    }
+
+    /// Renders a nested-encoded value from the beginning of the input and advances it.
+    pub fn render_nested(
+        &self,
+        abi_type: &AbiTypeName,
+        input: &mut &[u8],
+    ) -> Result<ValueSubTree, String> {
+        match abi_type.name.as_str() {
+            "u8" | "u16" | "u32" | "usize" | "u64" => {
+                let len = fixed_int_len(&abi_type.name);
+                let value = BigUint::from_bytes_be(take(input, len)?);
+                Ok(str_value(format!("u{}:{value}", len * 8)))
+            },
+            "i8" | "i16" | "i32" | "isize" | "i64" => {
+                let len = fixed_int_len(&abi_type.name);
+                let value = BigInt::from_signed_bytes_be(take(input, len)?);
+                Ok(str_value(format!("i{}:{value}", len * 8)))
+            },
+            "bool" => match take(input, 1)? {
+                [0] => Ok(str_value("u8:0".to_string())),
+                [1] => Ok(str_value("u8:1".to_string())),
+                other => Err(format!("invalid bool: 0x{}", hex::encode(other))),
+            },
+            "BigUint" | "NonZeroBigUint" => {
+                let bytes = take_length_prefixed(input)?;
+                Ok(str_value(format!(
+                    "biguint:{}",
+                    BigUint::from_bytes_be(bytes)
+                )))
+            },
+            "BigInt" => {
+                let bytes = take_length_prefixed(input)?;
+                Ok(str_value(format!("nested:{}", signed_repr(bytes))))
+            },
+            "bytes" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier" => {
+                let bytes = take_length_prefixed(input)?;
+                Ok(str_value(format!(
+                    "nested:{}",
+                    buffer_repr(&abi_type.name, bytes)
+                )))
+            },
+            "Address" => Ok(str_value(address_repr(take(input, 32)?))),
+            "H256" => Ok(str_value(hex_repr(take(input, 32)?))),
+            "Option" => match take(input, 1)? {
+                [0] => Ok(str_value("u8:0".to_string())),
+                [1] => {
+                    let item = self.render_nested(required_first_param(abi_type)?, input)?;
+                    Ok(ValueSubTree::List(vec![
+                        str_value("u8:1".to_string()),
+                        item,
+                    ]))
+                },
+                other => Err(format!("invalid Option prefix: 0x{}", hex::encode(other))),
+            },
+            "List" | "vec" => {
+                let item_type = required_first_param(abi_type)?;
+                let len = take_len(input)?;
+                let mut items = vec![str_value(format!("u32:{len}"))];
+                for _ in 0..len {
+                    items.push(self.render_nested(item_type, input)?);
+                }
+                Ok(ValueSubTree::List(items))
+            },
+            "tuple" => {
+                let mut items = Vec::new();
+                for item_type in &abi_type.type_params {
+                    items.push(self.render_nested(item_type, input)?);
+                }
+                Ok(ValueSubTree::List(items))
+            },
+            _ => {
+                if let Some(len) = abi_type.array_len() {
+                    let item_type = required_first_param(abi_type)?;
+                    if item_type.name == "u8" {
+                        return Ok(str_value(hex_repr(take(input, len)?)));
+                    }
+                    let mut items = Vec::new();
+                    for _ in 0..len {
+                        items.push(self.render_nested(item_type, input)?);
+                    }
+                    return Ok(ValueSubTree::List(items));
+                }
+
+                self.render_nested_described(abi_type, input)
+            },
+        }
+    }
+
+    fn type_contents(&self, abi_type: &AbiTypeName) -> Option<&'a TypeContents> {
+        self.types
+            .0
+            .get(&abi_type.to_string())
+            .map(|type_description| &type_description.contents)
+    }
+
+    fn render_nested_described(
+        &self,
+        abi_type: &AbiTypeName,
+        input: &mut &[u8],
+    ) -> Result<ValueSubTree, String> {
+        match self.type_contents(abi_type) {
+            Some(TypeContents::Struct(fields)) => {
+                let mut entries = BTreeMap::new();
+                self.render_nested_fields(fields, 0, &mut entries, input)?;
+                Ok(ValueSubTree::Map(entries))
+            },
+            Some(TypeContents::Enum(variants)) => {
+                let discriminant = take(input, 1)?[0];
+                let variant = find_variant(abi_type, variants, discriminant as usize)?;
+                let mut entries = BTreeMap::new();
+                entries.insert(
+                    field_key(0, variant.fields.len() + 1, &variant.name),
+                    str_value(format!("u8:{discriminant}")),
+                );
+                self.render_nested_fields(&variant.fields, 1, &mut entries, input)?;
+                Ok(ValueSubTree::Map(entries))
+            },
+            Some(TypeContents::ExplicitEnum(_)) => {
+                let bytes = take_length_prefixed(input)?;
+                Ok(str_value(format!(
+                    "nested:{}",
+                    buffer_repr("utf-8 string", bytes)
+                )))
+            },
+            _ => Err(format!(
+                "cannot render type {abi_type}, no description found"
+            )),
+        }
+    }
+
+    /// Fields are added to the object after the given number of existing entries.
+    fn render_nested_fields(
+        &self,
+        fields: &[StructFieldDescription],
+        offset: usize,
+        entries: &mut BTreeMap<String, ValueSubTree>,
+        input: &mut &[u8],
+    ) -> Result<(), String> {
+        let count = offset + fields.len();
+        for (index, field) in fields.iter().enumerate() {
+            let field_value = self.render_nested(&AbiTypeName::parse(&field.field_type), input)?;
+            entries.insert(field_key(offset + index, count, &field.name), field_value);
+        }
+        Ok(())
+    }
+
+    /// Values encoded by older versions of a struct can be missing the versioned fields at the end.
+    fn render_top_struct_fields(
+        &self,
+        abi_type: &AbiTypeName,
+        fields: &[StructFieldDescription],
+        bytes: &[u8],
+    ) -> Result<ValueSubTree, String> {
+        let mut input = bytes;
+        let mut entries = BTreeMap::new();
+        for (index, field) in fields.iter().enumerate() {
+            if field.since.is_some() && input.is_empty() {
+                break;
+            }
+            let field_value =
+                self.render_nested(&AbiTypeName::parse(&field.field_type), &mut input)?;
+            entries.insert(field_key(index, fields.len(), &field.name), field_value);
+        }
+        if !input.is_empty() {
+            return Err(format!(
+                "{} leftover bytes after rendering {abi_type}",
+                input.len()
+            ));
+        }
+        Ok(ValueSubTree::Map(entries))
+    }
+}
+
+fn str_value(s: String) -> ValueSubTree {
+    ValueSubTree::Str(s)
+}
+
+/// Zero-padded, so that the keys are sorted in field order.
+fn field_key(index: usize, count: usize, name: &str) -> String {
+    let width = count.saturating_sub(1).to_string().len().max(2);
+    format!("{index:0width$}-{name}")
+}
+
+fn find_variant<'v>(
+    abi_type: &AbiTypeName,
+    variants: &'v [EnumVariantDescription],
+    discriminant: usize,
+) -> Result<&'v EnumVariantDescription, String> {
+    variants
+        .iter()
+        .find(|variant| variant.discriminant == discriminant)
+        .ok_or_else(|| format!("invalid discriminant {discriminant} for enum {abi_type}"))
+}
+
+/// Signed numbers need an explicit sign, otherwise they are interpreted as unsigned.
+fn signed_repr(bytes: &[u8]) -> String {
+    let value = BigInt::from_signed_bytes_be(bytes);
+    if value > BigInt::default() {
+        format!("+{value}")
+    } else {
+        value.to_string()
+    }
+}
+
+/// Text is only shown as such if it is valid UTF-8 and contains no `|`, which would split the expression.
+fn buffer_repr(type_name: &str, bytes: &[u8]) -> String {
+    match buffer_text(type_name, bytes) {
+        Some(s) if !s.contains('|') => format!("str:{s}"),
+        _ => hex_repr(bytes),
+    }
+}
+
+/// Test addresses are shown as `address:`/`sc:` expressions, all others in bech32.
+fn address_repr(bytes: &[u8]) -> String {
+    if let ValueSubTree::Str(expr) = reconstruct(
+        bytes,
+        &ExprReconstructorHint::AddressHint,
+        &ReconstructorContext::new(),
+    ) {
+        if interpret_string(&expr, &InterpreterContext::default()) == bytes {
+            return expr;
+        }
+    }
+    format!("bech32:{}", address_bech32(bytes))
+}
@@ -0,0 +1,43 @@
+use multiversx_sc::{
+    abi::TypeAbi,
+    codec::{TopDecode, TopEncode},
+};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::scenario_format::serde_raw::ValueSubTree;
+
+use super::{from_scenario_value, to_scenario_value};
+
+/// Wrapper that (de)serializes a codec value in the structured scenario value syntax.
+///
+/// Useful as a field in serde-based test fixtures,
+/// to keep encoded values readable in JSON.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct ScenarioValue<T>(pub T);
+
+impl<T> Serialize for ScenarioValue<T>
+where
+    T: TopEncode + TypeAbi,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        to_scenario_value(&self.0).serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for ScenarioValue<T>
+where
+    T: TopDecode,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = ValueSubTree::deserialize(deserializer)?;
+        from_scenario_value(&value)
+            .map(ScenarioValue)
+            .map_err(|err| de::Error::custom(err.message_str()))
+    }
+}
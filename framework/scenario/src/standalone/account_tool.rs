@@ -2,6 +2,10 @@ use super::scenario_cli::AccountArgs;
 use multiversx_chain_scenario_format::serde_raw::{
     AccountRaw, EsdtFullRaw, EsdtInstanceRaw, EsdtRaw, ScenarioRaw, StepRaw, ValueSubTree,
 };
+use multiversx_sc_meta::{
+    abi_json::{load_abi_json_from_file, ContractAbiJson},
+    storage_decoder::{DecodedStorage, StorageDecoder},
+};
 use multiversx_sdk::{
     blockchain::CommunicationProxy,
     data::{address::Address, esdt::EsdtBalance},
@@ -14,6 +18,39 @@ pub async fn print_account_as_scenario_set_state(api: String, args: &AccountArgs
     println!("{}", scenario_raw.to_json_string());
 }
 
+pub async fn print_account_decoded_storage(api: String, addr: &str, abi_path: &str) {
+    let abi = load_abi_json_from_file(abi_path);
+    let address = Address::from_bech32_string(addr).unwrap();
+    let blockchain = CommunicationProxy::new(api);
+    let account_storage = blockchain
+        .get_account_storage_keys(&address)
+        .await
+        .unwrap_or_else(|err| panic!("failed to retrieve storage for address {addr}: {err}"));
+    let decoded_storage = decode_account_storage(&abi, &account_storage);
+    print!("{}", decoded_storage.to_json_string());
+}
+
+/// Decodes storage, as retrieved from the API, i.e. with hex-encoded keys and values.
+pub fn decode_account_storage(
+    abi: &ContractAbiJson,
+    account_storage: &HashMap<String, String>,
+) -> DecodedStorage {
+    let raw_storage: Vec<(Vec<u8>, Vec<u8>)> = account_storage
+        .iter()
+        .map(|(k, v)| {
+            (
+                hex::decode(k).unwrap_or_else(|err| panic!("invalid storage key {k}: {err}")),
+                hex::decode(v).unwrap_or_else(|err| panic!("invalid storage value {v}: {err}")),
+            )
+        })
+        .collect();
+    StorageDecoder::new(abi).decode_storage(
+        raw_storage
+            .iter()
+            .map(|(key, value)| (key.as_slice(), value.as_slice())),
+    )
+}
+
 pub async fn retrieve_account_as_scenario_set_state(
     api: String,
     addr: String,
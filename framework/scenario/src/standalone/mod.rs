@@ -1,5 +1,5 @@
 mod account_tool;
 mod scenario_cli;
 
-pub use account_tool::retrieve_account_as_scenario_set_state;
+pub use account_tool::{decode_account_storage, retrieve_account_as_scenario_set_state};
 pub use scenario_cli::cli_main;
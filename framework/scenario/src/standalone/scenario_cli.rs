@@ -29,6 +29,11 @@ pub struct AccountArgs {
     /// Provide the address you want to retrieve data from
     #[arg(long = "address", verbatim_doc_comment)]
     pub address: String,
+
+    /// Provide the contract ABI (`.abi.json` or `.mxsc.json`)
+    /// to print the decoded contract storage instead of the scenario
+    #[arg(long = "abi", verbatim_doc_comment)]
+    pub abi: Option<String>,
 }
 
 /// Entry point in the program when calling it as a standalone tool.
@@ -37,7 +42,11 @@ pub async fn cli_main() {
     let api = cli_args.api.expect("API needs tp be specified");
     match &cli_args.command {
         Some(ScenarioCliAction::Account(args)) => {
-            account_tool::print_account_as_scenario_set_state(api, args).await;
+            if let Some(abi_path) = &args.abi {
+                account_tool::print_account_decoded_storage(api, &args.address, abi_path).await;
+            } else {
+                account_tool::print_account_as_scenario_set_state(api, args).await;
+            }
         },
         None => {},
     }
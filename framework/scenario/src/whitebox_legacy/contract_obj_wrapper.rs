@@ -10,7 +10,7 @@ use crate::{
     },
     scenario_model::{Account, BytesValue, ScCallStep, SetStateStep},
     testing_framework::raw_converter::bytes_to_hex,
-    ScenarioWorld,
+    ScenarioWorld, SnapshotId,
 };
 use multiversx_chain_scenario_format::interpret_trait::InterpretableFrom;
 use multiversx_chain_vm::{
@@ -60,6 +60,15 @@ pub struct BlockchainStateWrapper {
     address_to_code_path: HashMap<Address, Vec<u8>>,
     current_tx_id: u64,
     workspace_path: PathBuf,
+    snapshots: HashMap<SnapshotId, WrapperSnapshot>,
+}
+
+/// The wrapper's own state, saved alongside the world snapshot.
+#[derive(Clone)]
+struct WrapperSnapshot {
+    address_factory: AddressFactory,
+    address_to_code_path: HashMap<Address, Vec<u8>>,
+    current_tx_id: u64,
 }
 
 impl BlockchainStateWrapper {
@@ -77,6 +86,48 @@ impl BlockchainStateWrapper {
             address_to_code_path: HashMap::new(),
             current_tx_id: 0,
             workspace_path: current_dir,
+            snapshots: HashMap::new(),
+        }
+    }
+
+    /// Saves the current blockchain state, so it can later be restored with `revert_to`.
+    pub fn snapshot(&mut self) -> SnapshotId {
+        let snapshot_id = self.world.snapshot();
+        self.snapshots.insert(
+            snapshot_id,
+            WrapperSnapshot {
+                address_factory: self.address_factory.clone(),
+                address_to_code_path: self.address_to_code_path.clone(),
+                current_tx_id: self.current_tx_id,
+            },
+        );
+        snapshot_id
+    }
+
+    /// Restores the blockchain state saved by `snapshot`.
+    ///
+    /// Accounts created afterwards are forgotten, and new accounts get the same addresses again.
+    pub fn revert_to(&mut self, snapshot_id: SnapshotId) {
+        let snapshot = self
+            .snapshots
+            .get(&snapshot_id)
+            .unwrap_or_else(|| panic!("unknown snapshot: {snapshot_id:?}"))
+            .clone();
+        self.world.revert_to(snapshot_id);
+        self.address_factory = snapshot.address_factory;
+        self.address_to_code_path = snapshot.address_to_code_path;
+        self.current_tx_id = snapshot.current_tx_id;
+    }
+
+    /// Creates an independent copy of the wrapper, including its snapshots.
+    pub fn fork(&self) -> Self {
+        BlockchainStateWrapper {
+            world: self.world.fork(),
+            address_factory: self.address_factory.clone(),
+            address_to_code_path: self.address_to_code_path.clone(),
+            current_tx_id: self.current_tx_id,
+            workspace_path: self.workspace_path.clone(),
+            snapshots: self.snapshots.clone(),
         }
     }
 
@@ -0,0 +1,104 @@
+use multiversx_sc::{
+    api::ManagedTypeApi,
+    codec,
+    codec::{
+        derive::{CodecArbitrary, NestedDecode, NestedEncode, TopDecode, TopEncode},
+        test_util::{check_arbitrary_round_trips, check_dep_round_trip, check_top_round_trip},
+        DecodeErrorHandler, EncodeErrorHandler, NestedDecodeInput, NestedEncodeOutput,
+    },
+    types::{
+        BigInt, BigUint, EgldOrEsdtTokenIdentifier, ManagedAddress, ManagedBuffer, ManagedVec,
+    },
+};
+use multiversx_sc_scenario::api::StaticApi;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, CodecArbitrary, PartialEq, Debug)]
+pub struct Plain {
+    pub int: u32,
+    pub bytes: Vec<u8>,
+    pub opt: Option<(u8, i64)>,
+    #[codec(skip)]
+    pub cache: u64,
+    pub arr: [u16; 3],
+    pub name: String,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, CodecArbitrary, PartialEq, Debug)]
+pub enum Kind {
+    Empty,
+    Single(u8),
+    #[codec(discriminant = 5)]
+    Named {
+        id: usize,
+        flags: Vec<bool>,
+    },
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, CodecArbitrary, PartialEq, Debug)]
+pub struct Payment<M: ManagedTypeApi> {
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub nonce: u64,
+    pub amount: BigUint<M>,
+    pub delta: BigInt<M>,
+    pub to: ManagedAddress<M>,
+    pub tags: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+/// Manual encoding that silently truncates the value.
+#[derive(TopEncode, TopDecode, CodecArbitrary, PartialEq, Debug)]
+pub struct Truncated(u16);
+
+impl codec::NestedEncode for Truncated {
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        (self.0 as u8).dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl codec::NestedDecode for Truncated {
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Truncated(u8::dep_decode_or_handle_err(input, h)? as u16))
+    }
+}
+
+#[test]
+fn codec_round_trip_helpers_test() {
+    let value = Plain {
+        int: 5,
+        bytes: vec![1, 2, 3],
+        opt: Some((4, -5)),
+        cache: 0,
+        arr: [6, 7, 8],
+        name: "name".to_string(),
+    };
+    check_top_round_trip(&value);
+    check_dep_round_trip(&value);
+    check_top_round_trip(&Kind::Named {
+        id: 3,
+        flags: vec![true],
+    });
+}
+
+#[test]
+fn codec_arbitrary_plain_test() {
+    check_arbitrary_round_trips::<Plain>(1, 200);
+    check_arbitrary_round_trips::<Kind>(2, 200);
+}
+
+#[test]
+fn codec_arbitrary_managed_test() {
+    check_arbitrary_round_trips::<Payment<StaticApi>>(3, 200);
+}
+
+#[test]
+#[should_panic]
+fn codec_arbitrary_finds_broken_encoding_test() {
+    check_arbitrary_round_trips::<Truncated>(4, 200);
+}
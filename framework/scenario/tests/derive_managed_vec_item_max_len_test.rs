@@ -0,0 +1,49 @@
+use multiversx_sc::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeDescriptionContainerImpl},
+    api::ManagedTypeApi,
+    codec::NestedEncodeMaxLen,
+    types::{BigUint, ManagedVecItem},
+};
+use multiversx_sc_scenario::api::StaticApi;
+
+multiversx_sc::derive_imports!();
+
+// the payload is used as-is as encoding, so it must hold the largest encoding
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, NestedEncodeMaxLen, TypeAbi)]
+#[codec(max_len)]
+pub struct FixedLayout {
+    pub nonce: u64,
+    pub kind: u8,
+    pub active: bool,
+}
+
+// the payload only holds handles, so unbounded encodings are also allowed
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, NestedEncodeMaxLen, TypeAbi)]
+#[codec(max_len)]
+pub struct WithManagedField<M: ManagedTypeApi> {
+    pub nonce: u64,
+    pub amount: BigUint<M>,
+}
+
+fn max_nested_len_in_abi<T: TypeAbi>() -> Option<usize> {
+    let mut type_descriptions = TypeDescriptionContainerImpl::new();
+    T::provide_type_descriptions(&mut type_descriptions);
+    type_descriptions.0[&T::type_name()].max_nested_len
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn fixed_layout_max_len() {
+    assert!(<FixedLayout as ManagedVecItem>::SKIPS_RESERIALIZATION);
+    assert_eq!(<FixedLayout as ManagedVecItem>::PAYLOAD_SIZE, 10);
+    assert_eq!(FixedLayout::MAX_NESTED_LEN, Some(10));
+    assert_eq!(max_nested_len_in_abi::<FixedLayout>(), Some(10));
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn with_managed_field_max_len() {
+    assert!(!<WithManagedField<StaticApi> as ManagedVecItem>::SKIPS_RESERIALIZATION);
+    assert_eq!(WithManagedField::<StaticApi>::MAX_NESTED_LEN, None);
+    assert_eq!(max_nested_len_in_abi::<WithManagedField<StaticApi>>(), None);
+}
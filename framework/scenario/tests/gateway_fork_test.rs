@@ -0,0 +1,215 @@
+mod local_gateway_stand_in;
+
+use std::path::PathBuf;
+
+use local_gateway_stand_in::{GatewayAccountState, LocalGatewayStandIn};
+use multiversx_sc_scenario::{
+    fork::GatewayFork,
+    scenario::parse_scenario,
+    scenario_model::{
+        Account, AddressKey, CheckAccount, CheckStateStep, SetStateStep, Step, TransferStep,
+    },
+    ScenarioWorld,
+};
+use multiversx_sdk::data::address::Address;
+
+const ALICE: &str = "address:alice";
+const BOB: &str = "address:bob";
+const VAULT: &str = "sc:vault";
+const TOKEN_ID: &str = "FORK-123456";
+
+fn sdk_address(address_expr: &str) -> Address {
+    Address::from_bytes(*AddressKey::from(address_expr).to_address().as_array())
+}
+
+/// Unique per process, so that concurrent test runs do not overwrite each other's files.
+fn temp_scenario_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("{name}_{}.scen.json", std::process::id()))
+}
+
+fn start_gateway() -> LocalGatewayStandIn {
+    let gateway = LocalGatewayStandIn::start();
+    gateway.set_account(
+        GatewayAccountState::new(&sdk_address(ALICE))
+            .nonce(5)
+            .balance("1000")
+            .esdt_balance(TOKEN_ID, "500")
+            .esdt_roles(TOKEN_ID, &["ESDTRoleLocalMint"]),
+    );
+    gateway.set_account(
+        GatewayAccountState::new(&sdk_address(VAULT))
+            .balance("7")
+            .code(b"vault-code")
+            .storage(b"total", &[0x03, 0xe8])
+            .storage(b"unused", &[0x01]),
+    );
+    gateway
+}
+
+fn transfer_from_alice_to_bob(world: &mut ScenarioWorld) {
+    world
+        .set_state_step(SetStateStep::new().put_account(BOB, Account::new()))
+        .transfer_step(TransferStep::new().from(ALICE).to(BOB).egld_value("100"))
+        .transfer_step(TransferStep::new().from(ALICE).to(BOB).esdt_transfer(
+            format!("str:{TOKEN_ID}").as_str(),
+            "0",
+            "200",
+        ))
+        .check_state_step(
+            CheckStateStep::new()
+                .put_account(
+                    ALICE,
+                    CheckAccount::new()
+                        .nonce("7")
+                        .balance("900")
+                        .esdt_balance(format!("str:{TOKEN_ID}").as_str(), "300"),
+                )
+                .put_account(
+                    BOB,
+                    CheckAccount::new()
+                        .balance("100")
+                        .esdt_balance(format!("str:{TOKEN_ID}").as_str(), "200"),
+                ),
+        );
+}
+
+#[test]
+fn gateway_fork_test() {
+    let gateway = start_gateway();
+    let mut world = ScenarioWorld::new();
+    world.fork_from_gateway(GatewayFork::new(&gateway.url()).at_block_nonce(42));
+
+    transfer_from_alice_to_bob(&mut world);
+
+    check_vault(&mut world);
+
+    let requests = gateway.requests();
+    assert!(!requests.is_empty());
+    assert!(requests
+        .iter()
+        .all(|request| request.ends_with("?blockNonce=42")));
+
+    // each account is fetched only once
+    let alice_bech32 = sdk_address(ALICE).to_string();
+    assert_eq!(
+        requests
+            .iter()
+            .filter(|request| **request == format!("/address/{alice_bech32}?blockNonce=42"))
+            .count(),
+        1
+    );
+
+    // storage and balances are fetched one by one, only when needed
+    let vault_bech32 = sdk_address(VAULT).to_string();
+    assert!(requests.contains(&format!(
+        "/address/{vault_bech32}/key/{}?blockNonce=42",
+        hex::encode(b"total")
+    )));
+    assert!(requests.contains(&format!(
+        "/address/{alice_bech32}/esdt/{TOKEN_ID}?blockNonce=42"
+    )));
+    assert!(!requests
+        .iter()
+        .any(|request| request.contains("/keys") || request.contains("/esdt?")));
+
+    // only the fetched values are recorded
+    let recorded_path = temp_scenario_path("gateway_fork_test");
+    world.write_forked_state(&recorded_path);
+    let recorded = parse_scenario(&recorded_path);
+    std::fs::remove_file(&recorded_path).unwrap();
+    let Some(Step::SetState(set_state_step)) = recorded.steps.into_iter().next() else {
+        panic!("expected a setState step");
+    };
+    let vault_address = AddressKey::from(VAULT).to_address();
+    let (_, vault) = set_state_step
+        .accounts
+        .iter()
+        .find(|(address_key, _)| address_key.value == vault_address)
+        .expect("vault not recorded");
+    assert_eq!(vault.storage.len(), 1);
+    assert_eq!(
+        vault.storage.values().next().unwrap().value,
+        vec![0x03, 0xe8]
+    );
+}
+
+/// Never touched by a transaction, only checked.
+fn check_vault(world: &mut ScenarioWorld) {
+    world.check_state_step(
+        CheckStateStep::new().put_account(
+            VAULT,
+            CheckAccount::new()
+                .balance("7")
+                .code("str:vault-code")
+                .check_storage("str:total", "1000"),
+        ),
+    );
+}
+
+#[test]
+fn gateway_fork_replay_test() {
+    let gateway = start_gateway();
+    let mut world = ScenarioWorld::new();
+    world.fork_from_gateway(GatewayFork::new(&gateway.url()));
+    transfer_from_alice_to_bob(&mut world);
+
+    let recorded_path = temp_scenario_path("gateway_fork_replay_test");
+    world.write_forked_state(&recorded_path);
+    drop(gateway);
+
+    // the recording contains the state as fetched, not as modified by the test
+    let recorded = parse_scenario(&recorded_path);
+    std::fs::remove_file(&recorded_path).unwrap();
+    let Some(Step::SetState(set_state_step)) = recorded.steps.into_iter().next() else {
+        panic!("expected a setState step");
+    };
+    assert_eq!(set_state_step.accounts.len(), 1);
+
+    let mut offline_world = ScenarioWorld::new();
+    offline_world.set_state_step(set_state_step);
+    transfer_from_alice_to_bob(&mut offline_world);
+}
+
+#[test]
+fn gateway_fork_trace_test() {
+    let gateway = start_gateway();
+    let mut world = ScenarioWorld::new();
+    world.start_trace();
+    world.fork_from_gateway(GatewayFork::new(&gateway.url()));
+    transfer_from_alice_to_bob(&mut world);
+    check_vault(&mut world);
+
+    let trace_path = temp_scenario_path("gateway_fork_trace_test");
+    world.write_scenario_trace(&trace_path);
+    drop(gateway);
+
+    // the trace starts with the fetched state, so it replays offline
+    ScenarioWorld::new().run(&trace_path);
+    std::fs::remove_file(&trace_path).unwrap();
+}
+
+#[test]
+fn gateway_fork_override_test() {
+    let gateway = start_gateway();
+    let mut world = ScenarioWorld::new();
+    world.fork_from_gateway(GatewayFork::new(&gateway.url()));
+
+    // overridden accounts are fully defined locally
+    world.set_state_step(
+        SetStateStep::new().put_account(VAULT, Account::new().balance("3").code("str:local-code")),
+    );
+    world.check_state_step(
+        CheckStateStep::new().put_account(
+            VAULT,
+            CheckAccount::new()
+                .balance("3")
+                .check_storage("str:total", ""),
+        ),
+    );
+
+    let vault_bech32 = sdk_address(VAULT).to_string();
+    assert!(!gateway
+        .requests()
+        .iter()
+        .any(|request| request.starts_with(&format!("/address/{vault_bech32}"))));
+}
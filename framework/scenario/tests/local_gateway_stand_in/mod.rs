@@ -0,0 +1,294 @@
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use multiversx_sdk::data::{
+    account::{Account, AccountData, AccountResponse},
+    account_storage::{
+        AccountStorage, AccountStorageResponse, AccountStorageValue, AccountStorageValueResponse,
+    },
+    address::Address,
+    esdt::{
+        EsdtBalance, EsdtBalanceData, EsdtBalanceResponse, EsdtRolesData, EsdtRolesResponse,
+        EsdtTokenData, EsdtTokenResponse,
+    },
+};
+
+/// The state of an account, as served by the [`LocalGatewayStandIn`].
+#[derive(Clone, Debug)]
+pub struct GatewayAccountState {
+    pub account: Account,
+    pub esdts: HashMap<String, EsdtBalance>,
+    pub roles: HashMap<String, Vec<String>>,
+    /// Hex-encoded keys and values, as returned by the gateway.
+    pub storage: HashMap<String, String>,
+}
+
+impl GatewayAccountState {
+    pub fn new(address: &Address) -> Self {
+        GatewayAccountState {
+            account: Account {
+                address: address.clone(),
+                nonce: 0,
+                balance: "0".to_string(),
+                username: String::new(),
+                code: String::new(),
+                code_hash: None,
+                root_hash: None,
+                code_metadata: None,
+                developer_reward: None,
+                owner_address: None,
+            },
+            esdts: HashMap::new(),
+            roles: HashMap::new(),
+            storage: HashMap::new(),
+        }
+    }
+
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.account.nonce = nonce;
+        self
+    }
+
+    pub fn balance(mut self, balance: &str) -> Self {
+        self.account.balance = balance.to_string();
+        self
+    }
+
+    pub fn code(mut self, code: &[u8]) -> Self {
+        self.account.code = hex::encode(code);
+        self
+    }
+
+    pub fn storage(mut self, key: &[u8], value: &[u8]) -> Self {
+        self.storage.insert(hex::encode(key), hex::encode(value));
+        self
+    }
+
+    /// Fungible tokens only, for NFTs the identifier needs the nonce suffix, e.g. `NFT-123456-01`.
+    pub fn esdt_balance(mut self, token_identifier: &str, balance: &str) -> Self {
+        self.esdts.insert(
+            token_identifier.to_string(),
+            EsdtBalance {
+                token_identifier: token_identifier.to_string(),
+                balance: balance.to_string(),
+            },
+        );
+        self
+    }
+
+    pub fn esdt_roles(mut self, token_identifier: &str, roles: &[&str]) -> Self {
+        self.roles.insert(
+            token_identifier.to_string(),
+            roles.iter().map(|role| role.to_string()).collect(),
+        );
+        self
+    }
+}
+
+#[derive(Default)]
+struct StandInState {
+    accounts: HashMap<String, GatewayAccountState>,
+    requests: Vec<String>,
+}
+
+/// Minimal local HTTP server that answers the account routes of the gateway API.
+///
+/// Serves as a replacement for a real gateway in tests, to avoid depending on the network.
+/// Accounts that were not configured are reported as empty, just like the real gateway does.
+pub struct LocalGatewayStandIn {
+    socket_address: SocketAddr,
+    state: Arc<Mutex<StandInState>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl LocalGatewayStandIn {
+    /// Starts serving on a random local port, on a background thread.
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind local gateway");
+        let socket_address = listener.local_addr().unwrap();
+        let state = Arc::new(Mutex::new(StandInState::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let thread_state = state.clone();
+        let thread_shutdown = shutdown.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if thread_shutdown.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    handle_connection(stream, &thread_state);
+                }
+            }
+        });
+
+        LocalGatewayStandIn {
+            socket_address,
+            state,
+            shutdown,
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.socket_address)
+    }
+
+    pub fn set_account(&self, account_state: GatewayAccountState) {
+        let address = account_state.account.address.to_string();
+        self.state
+            .lock()
+            .unwrap()
+            .accounts
+            .insert(address, account_state);
+    }
+
+    /// All request paths received so far, including query parameters.
+    pub fn requests(&self) -> Vec<String> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for LocalGatewayStandIn {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // wakes up the listener, so that it sees the shutdown flag
+        let _ = TcpStream::connect(self.socket_address);
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, state: &Mutex<StandInState>) {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+    // skip the headers, requests have no body
+    let mut header_line = String::new();
+    while reader.read_line(&mut header_line).is_ok() && header_line.trim() != "" {
+        header_line.clear();
+    }
+
+    let path = request_line
+        .split_whitespace()
+        .nth(1)
+        .unwrap_or_default()
+        .to_string();
+    let (status, body) = respond(&path, state);
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+fn respond(path: &str, state: &Mutex<StandInState>) -> (&'static str, String) {
+    let mut state = state.lock().unwrap();
+    state.requests.push(path.to_string());
+
+    let route = path.split('?').next().unwrap_or_default();
+    let Some(account_route) = route.strip_prefix("/address/") else {
+        return not_found(route);
+    };
+    let (address, resource) = account_route.split_once('/').unwrap_or((account_route, ""));
+    let Ok(sdk_address) = Address::from_bech32_string(address) else {
+        return not_found(route);
+    };
+
+    let mut account_state = state
+        .accounts
+        .get(address)
+        .cloned()
+        .unwrap_or_else(|| GatewayAccountState::new(&sdk_address));
+    if !account_state.storage.is_empty() || !account_state.esdts.is_empty() {
+        account_state.account.root_hash = Some("data-trie-root".to_string());
+    }
+    let resource_parts = resource
+        .trim_end_matches('/')
+        .split('/')
+        .collect::<Vec<_>>();
+    let body = match resource_parts.as_slice() {
+        [""] => serde_json::to_string(&AccountResponse {
+            data: Some(AccountData {
+                account: account_state.account,
+            }),
+            error: String::new(),
+            code: "successful".to_string(),
+        }),
+        ["esdt"] => serde_json::to_string(&EsdtBalanceResponse {
+            data: Some(EsdtBalanceData {
+                esdts: account_state.esdts,
+            }),
+            error: String::new(),
+            code: "successful".to_string(),
+        }),
+        ["esdts", "roles"] => serde_json::to_string(&EsdtRolesResponse {
+            data: Some(EsdtRolesData {
+                roles: account_state.roles,
+            }),
+            error: String::new(),
+            code: "successful".to_string(),
+        }),
+        ["keys"] => serde_json::to_string(&AccountStorageResponse {
+            data: Some(AccountStorage {
+                pairs: account_state.storage,
+            }),
+            error: String::new(),
+            code: "successful".to_string(),
+        }),
+        ["key", key] => serde_json::to_string(&AccountStorageValueResponse {
+            data: Some(AccountStorageValue {
+                value: account_state.storage.get(*key).cloned().unwrap_or_default(),
+            }),
+            error: String::new(),
+            code: "successful".to_string(),
+        }),
+        ["esdt", token_identifier] => esdt_token_response(&account_state, token_identifier),
+        ["nft", token_identifier, "nonce", nonce] => {
+            let Ok(nonce) = nonce.parse::<u64>() else {
+                return not_found(route);
+            };
+            let mut nonce_hex = format!("{nonce:x}");
+            if nonce_hex.len() % 2 == 1 {
+                nonce_hex.insert(0, '0');
+            }
+            esdt_token_response(&account_state, &format!("{token_identifier}-{nonce_hex}"))
+        },
+        _ => return not_found(route),
+    };
+    ("200 OK", body.unwrap())
+}
+
+/// Missing tokens are reported with a zero balance.
+fn esdt_token_response(
+    account_state: &GatewayAccountState,
+    full_token_identifier: &str,
+) -> serde_json::Result<String> {
+    let token_data = account_state
+        .esdts
+        .get(full_token_identifier)
+        .cloned()
+        .unwrap_or_else(|| EsdtBalance {
+            token_identifier: full_token_identifier.to_string(),
+            balance: "0".to_string(),
+        });
+    serde_json::to_string(&EsdtTokenResponse {
+        data: Some(EsdtTokenData { token_data }),
+        error: String::new(),
+        code: "successful".to_string(),
+    })
+}
+
+fn not_found(route: &str) -> (&'static str, String) {
+    (
+        "404 Not Found",
+        format!(r#"{{"data":null,"error":"unknown route {route}","code":"bad_request"}}"#),
+    )
+}
@@ -0,0 +1,165 @@
+use multiversx_sc::{
+    api::ManagedTypeApi,
+    codec::{test_util::top_encode_to_vec_u8_or_panic, TopEncodeOutput},
+    types::{BigInt, BigUint, ManagedAddress, ManagedBuffer, ManagedVec, TokenIdentifier},
+};
+use multiversx_sc_scenario::{
+    api::StaticApi,
+    scenario_format::serde_raw::ValueSubTree,
+    scenario_model::{AddressValue, CheckValue, ScCallStep, TxExpect},
+    scenario_value::{from_scenario_value, to_scenario_value, ScenarioValue},
+};
+use serde_json::json;
+
+multiversx_sc::derive_imports!();
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Debug)]
+pub enum Status {
+    Inactive,
+    Active,
+    Paused { until: u64 },
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Debug)]
+pub struct Position<M: ManagedTypeApi> {
+    pub owner: ManagedAddress<M>,
+    pub token: TokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub pnl: BigInt<M>,
+    pub leverage: u8,
+    pub offset: i32,
+    pub active: bool,
+    pub label: ManagedBuffer<M>,
+    pub limit: Option<u64>,
+    pub history: ManagedVec<M, u64>,
+    pub status: Status,
+}
+
+/// Encoding that does not match the ABI description.
+#[derive(TopDecode, TypeAbi, PartialEq, Debug)]
+pub struct Custom {
+    pub value: u32,
+}
+
+impl codec::TopEncode for Custom {
+    fn top_encode_or_handle_err<O, H>(&self, output: O, _h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: codec::EncodeErrorHandler,
+    {
+        output.set_slice_u8(&[0xff, 0, 0, 0, self.value as u8]);
+        Ok(())
+    }
+}
+
+fn position() -> Position<StaticApi> {
+    Position {
+        owner: AddressValue::from("address:owner").to_address().into(),
+        token: TokenIdentifier::from("TOKEN-123456"),
+        amount: BigUint::from(1_000u32),
+        pnl: BigInt::from(-25),
+        leverage: 3,
+        offset: -7,
+        active: true,
+        label: ManagedBuffer::from("long"),
+        limit: Some(500),
+        history: ManagedVec::from_iter([1u64, 2]),
+        status: Status::Paused { until: 9 },
+    }
+}
+
+fn to_json(value: &ValueSubTree) -> serde_json::Value {
+    serde_json::from_str(&value.to_string()).unwrap()
+}
+
+#[test]
+fn scenario_value_top_level_test() {
+    assert_eq!(to_json(&to_scenario_value(&5u32)), json!("5"));
+    assert_eq!(to_json(&to_scenario_value(&0u64)), json!("0"));
+    assert_eq!(to_json(&to_scenario_value(&-5i64)), json!("-5"));
+    assert_eq!(to_json(&to_scenario_value(&128i16)), json!("+128"));
+    assert_eq!(to_json(&to_scenario_value(&true)), json!("true"));
+    assert_eq!(
+        to_json(&to_scenario_value(&ManagedBuffer::<StaticApi>::from("a|b"))),
+        json!("0x617c62")
+    );
+    assert_eq!(
+        to_json(&to_scenario_value(&TokenIdentifier::<StaticApi>::from(
+            "TOKEN-123456"
+        ))),
+        json!("str:TOKEN-123456")
+    );
+    assert_eq!(
+        to_json(&to_scenario_value(&Status::Inactive)),
+        json!({ "00-Inactive": "" })
+    );
+    assert_eq!(
+        to_json(&to_scenario_value(&Some(3u16))),
+        json!(["u8:1", "u16:3"])
+    );
+}
+
+#[test]
+fn scenario_value_struct_test() {
+    let value = position();
+    let rendered = to_scenario_value(&value);
+    assert_eq!(
+        to_json(&rendered),
+        json!({
+            "00-owner": "address:owner",
+            "01-token": "nested:str:TOKEN-123456",
+            "02-amount": "biguint:1000",
+            "03-pnl": "nested:-25",
+            "04-leverage": "u8:3",
+            "05-offset": "i32:-7",
+            "06-active": "u8:1",
+            "07-label": "nested:0x6c6f6e67",
+            "08-limit": ["u8:1", "u64:500"],
+            "09-history": ["u32:2", "u64:1", "u64:2"],
+            "10-status": {
+                "00-Paused": "u8:2",
+                "01-until": "u64:9"
+            }
+        })
+    );
+
+    let parsed: Position<StaticApi> = from_scenario_value(&rendered).unwrap();
+    assert_eq!(parsed, value);
+}
+
+#[test]
+fn scenario_value_custom_encoding_test() {
+    let rendered = to_scenario_value(&Custom { value: 5 });
+    assert_eq!(to_json(&rendered), json!("0xff00000005"));
+    assert!(from_scenario_value::<Custom>(&rendered).is_err());
+}
+
+#[test]
+fn scenario_value_serde_test() {
+    let serialized = serde_json::to_string(&ScenarioValue(Status::Active)).unwrap();
+    assert_eq!(serialized, r#"{"00-Active":"u8:1"}"#);
+
+    let deserialized: ScenarioValue<Status> = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized.0, Status::Active);
+    assert!(serde_json::from_str::<ScenarioValue<u8>>(r#""u16:256""#).is_err());
+}
+
+#[test]
+fn scenario_value_tx_expect_test() {
+    let value = position();
+    let expect = TxExpect::ok().result_value(&value);
+    let CheckValue::Equal(out) = &expect.out else {
+        panic!("expected result values");
+    };
+    let CheckValue::Equal(result) = &out[0] else {
+        panic!("expected an exact result");
+    };
+    assert_eq!(result.value, top_encode_to_vec_u8_or_panic(&value));
+    assert!(matches!(result.original, ValueSubTree::Map(_)));
+
+    let step = ScCallStep::new().expect_value(&value);
+    assert_eq!(
+        format!("{:?}", step.expect.unwrap().out),
+        format!("{:?}", expect.out)
+    );
+}
@@ -1,4 +1,7 @@
-use multiversx_sc::types::{LockableStaticBuffer, StaticBufferRef};
+use multiversx_sc::{
+    codec::NestedEncodeMaxLen,
+    types::{CodeMetadata, LockableStaticBuffer, ManagedAddress, StaticBufferRef},
+};
 use multiversx_sc_scenario::api::StaticApi;
 
 #[test]
@@ -53,3 +56,13 @@ fn test_lock_2() {
     new_should_succeed();
     new_should_succeed();
 }
+
+#[test]
+fn test_new_from_nested_encode() {
+    assert_eq!(ManagedAddress::<StaticApi>::MAX_NESTED_LEN, Some(32));
+
+    let value = (5u32, Some(CodeMetadata::UPGRADEABLE));
+    let s = StaticBufferRef::<StaticApi>::try_new_from_nested_encode(&value).unwrap();
+    assert!(s.contents_eq(&[0, 0, 0, 5, 1, 1, 0]));
+    assert!(StaticBufferRef::<StaticApi>::try_new_from_nested_encode(&value).is_none());
+}
@@ -0,0 +1,130 @@
+use multiversx_sc::{
+    storage::{
+        mappers::{MapMapper, SingleValueMapper, StorageMapper, UnorderedSetMapper, VecMapper},
+        StorageKey,
+    },
+    types::{BigUint, ManagedBuffer},
+};
+use multiversx_sc_scenario::{
+    api::SingleTxApi,
+    meta::{abi_json::deserialize_abi_from_json, storage_decoder::StorageDecoder},
+    scenario_model::{Account, SetStateStep},
+    ScenarioWorld,
+};
+use serde_json::{json, Value};
+
+const ABI_JSON: &str = r#"{
+    "name": "storage-decoder-test",
+    "storage": [
+        {
+            "key": "sum",
+            "mapper": "SingleValueMapper",
+            "valueTypes": ["BigUint"]
+        },
+        {
+            "key": "deposit",
+            "mapper": "storage_get",
+            "keyArgs": [{ "name": "id", "type": "u32" }],
+            "valueTypes": ["BigUint"]
+        },
+        {
+            "key": "items",
+            "mapper": "VecMapper",
+            "valueTypes": ["u64"]
+        },
+        {
+            "key": "members",
+            "mapper": "UnorderedSetMapper",
+            "valueTypes": ["u32"]
+        },
+        {
+            "key": "names",
+            "mapper": "MapMapper",
+            "valueTypes": ["utf-8 string", "u8"]
+        }
+    ]
+}"#;
+
+fn populate_storage() {
+    let sum = SingleValueMapper::<SingleTxApi, BigUint<SingleTxApi>>::new(StorageKey::new(b"sum"));
+    sum.set(BigUint::from(1000u32));
+
+    let mut deposit_key = StorageKey::new(b"deposit");
+    deposit_key.append_item(&7u32);
+    let deposit = SingleValueMapper::<SingleTxApi, BigUint<SingleTxApi>>::new(deposit_key);
+    deposit.set(BigUint::from(5u32));
+
+    let mut items = VecMapper::<SingleTxApi, u64>::new(StorageKey::new(b"items"));
+    items.push(&42);
+    items.push(&43);
+
+    let mut members = UnorderedSetMapper::<SingleTxApi, u32>::new(StorageKey::new(b"members"));
+    members.insert(9);
+
+    let mut names =
+        MapMapper::<SingleTxApi, ManagedBuffer<SingleTxApi>, u8>::new(StorageKey::new(b"names"));
+    names.insert(ManagedBuffer::from("alice"), 3);
+}
+
+#[test]
+fn storage_decoder_mappers_test() {
+    SingleTxApi::clear_global();
+    populate_storage();
+    let raw_storage = SingleTxApi::with_global_default_account(|account| account.storage.clone());
+
+    let abi = deserialize_abi_from_json(ABI_JSON).unwrap();
+    let decoded = StorageDecoder::new(&abi).decode_storage(
+        raw_storage
+            .iter()
+            .map(|(key, value)| (key.as_slice(), value.as_slice())),
+    );
+    assert!(decoded.undecoded.is_empty());
+
+    let sum: Vec<_> = decoded.entries_for_key("sum").collect();
+    assert_eq!(sum.len(), 1);
+    assert_eq!(sum[0].value, json!("1000"));
+
+    let deposit: Vec<_> = decoded.entries_for_key("deposit").collect();
+    assert_eq!(deposit[0].key_args, vec![json!(7)]);
+    assert_eq!(deposit[0].value, json!("5"));
+
+    let items: Vec<_> = decoded.entries_for_key("items").collect();
+    assert_eq!(items.len(), 3);
+    assert!(items
+        .iter()
+        .any(|entry| entry.suffix == ".len" && entry.value == json!(2)));
+    assert!(items.iter().any(|entry| entry.suffix == ".item"
+        && entry.item.clone().map(Value::from) == Some(json!(2))
+        && entry.value == json!(43)));
+
+    let members: Vec<_> = decoded.entries_for_key("members").collect();
+    assert!(members.iter().any(|entry| entry.suffix == ".index"
+        && entry.item.clone().map(Value::from) == Some(json!(9))
+        && entry.value == json!(1)));
+
+    let names: Vec<_> = decoded.entries_for_key("names").collect();
+    assert!(names.iter().any(|entry| entry.suffix == ".mapped"
+        && entry.item.clone().map(Value::from) == Some(json!("alice"))
+        && entry.value == json!(3)));
+    assert!(names.iter().any(|entry| entry.suffix == ".info"
+        && entry.value == json!({ "len": 1, "front": 1, "back": 1, "new": 1 })));
+    assert!(names.iter().any(|entry| entry.suffix == ".node_links"
+        && entry.item.clone().map(Value::from) == Some(json!(1))));
+}
+
+#[test]
+fn storage_decoder_world_test() {
+    let mut world = ScenarioWorld::new();
+    let mut account = Account::new();
+    account.storage.insert("str:sum".into(), "1234".into());
+    account.storage.insert("str:unknown".into(), "0x01".into());
+    world.set_state_step(SetStateStep::new().put_account("address:contract", account));
+
+    let abi = deserialize_abi_from_json(ABI_JSON).unwrap();
+    let decoded = world.decode_storage("address:contract", &abi);
+    assert_eq!(decoded.entries.len(), 1);
+    assert_eq!(decoded.entries[0].storage_key, "sum");
+    assert_eq!(decoded.entries[0].value, json!("1234"));
+    assert_eq!(decoded.undecoded.len(), 1);
+    assert_eq!(decoded.undecoded[0].raw_value, "0x01");
+}
@@ -11,6 +11,7 @@ static mut NEXT_HANDLE: i32 = const_handles::NEW_HANDLE_START_FROM;
 static mut NUM_ARGUMENTS: i32 = 0;
 static mut CALL_VALUE_EGLD_HANDLE: i32 = const_handles::UNINITIALIZED_HANDLE;
 static mut CALL_VALUE_MULTI_ESDT_HANDLE: i32 = const_handles::UNINITIALIZED_HANDLE;
+static mut REENTRANCY_LOCK_HELD: bool = false;
 
 // The compiler seems to enjoy inlining this method no matter how many times it shows up.
 // Hence the rather drastic directive.
@@ -78,4 +79,14 @@ impl StaticVarApiImpl for VmApiImpl {
     fn get_call_value_multi_esdt_handle(&self) -> RawHandle {
         unsafe { CALL_VALUE_MULTI_ESDT_HANDLE }
     }
+
+    fn set_reentrancy_lock_held(&self, held: bool) {
+        unsafe {
+            REENTRANCY_LOCK_HELD = held;
+        }
+    }
+
+    fn get_reentrancy_lock_held(&self) -> bool {
+        unsafe { REENTRANCY_LOCK_HELD }
+    }
 }
@@ -1,5 +1,8 @@
 use crate::api::VmApiImpl;
-use multiversx_sc::api::{const_handles, RawHandle, SendApi, SendApiImpl};
+use multiversx_sc::{
+    api::{const_handles, RawHandle, SendApi, SendApiImpl},
+    reentrancy_guard,
+};
 
 extern "C" {
     fn managedMultiTransferESDTNFTExecute(
@@ -178,6 +181,7 @@ impl SendApiImpl for VmApiImpl {
         endpoint_name_handle: RawHandle,
         arg_buffer_handle: RawHandle,
     ) -> ! {
+        reentrancy_guard::exit_before_async_call::<Self>();
         unsafe {
             managedAsyncCall(
                 to_handle,
@@ -2,9 +2,9 @@ use std::collections::HashMap;
 
 use crate::data::{
     account::{Account, AccountResponse},
-    account_storage::AccountStorageResponse,
+    account_storage::{AccountStorageResponse, AccountStorageValueResponse},
     address::Address,
-    esdt::{EsdtBalance, EsdtBalanceResponse, EsdtRolesResponse},
+    esdt::{EsdtBalance, EsdtBalanceResponse, EsdtRolesResponse, EsdtTokenResponse},
     hyperblock::{HyperBlock, HyperBlockResponse},
     network_config::{NetworkConfig, NetworkConfigResponse},
     network_economics::{NetworkEconomics, NetworkEconomicsResponse},
@@ -30,6 +30,7 @@ const NETWORK_CONFIG_ENDPOINT: &str = "network/config";
 const NETWORK_ECONOMICS_ENDPOINT: &str = "network/economics";
 const ACCOUNT_ENDPOINT: &str = "address/";
 const KEYS_ENDPOINT: &str = "/keys/";
+const KEY_ENDPOINT: &str = "/key/";
 const COST_TRANSACTION_ENDPOINT: &str = "transaction/cost";
 const SEND_TRANSACTION_ENDPOINT: &str = "transaction/send";
 const SEND_MULTIPLE_TRANSACTIONS_ENDPOINT: &str = "transaction/send-multiple";
@@ -39,11 +40,13 @@ const GET_HYPER_BLOCK_BY_HASH_ENDPOINT: &str = "hyperblock/by-hash/";
 const GET_NETWORK_STATUS_ENDPOINT: &str = "network/status";
 const WITH_RESULTS_QUERY_PARAM: &str = "?withResults=true";
 const VM_VALUES_ENDPOINT: &str = "vm-values/query";
+const BLOCK_NONCE_QUERY_PARAM: &str = "?blockNonce=";
 
 #[derive(Clone, Debug)]
 pub struct CommunicationProxy {
     proxy_url: String,
     client: Client,
+    block_nonce: Option<u64>,
 }
 
 impl CommunicationProxy {
@@ -51,13 +54,35 @@ impl CommunicationProxy {
         Self {
             proxy_url,
             client: Client::new(),
+            block_nonce: None,
         }
     }
 
+    /// Account state (info, ESDT tokens, roles, storage) will be retrieved as it was at the given block nonce.
+    ///
+    /// Only works with gateways backed by nodes that keep historical state.
+    pub fn with_block_nonce(mut self, block_nonce: u64) -> Self {
+        self.block_nonce = Some(block_nonce);
+        self
+    }
+
+    pub fn block_nonce(&self) -> Option<u64> {
+        self.block_nonce
+    }
+
     fn get_endpoint(&self, endpoint: &str) -> String {
         format!("{}/{}", self.proxy_url, endpoint)
     }
 
+    fn get_account_endpoint(&self, address: &Address, suffix: &str) -> String {
+        let mut endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + suffix;
+        if let Some(block_nonce) = self.block_nonce {
+            endpoint += BLOCK_NONCE_QUERY_PARAM;
+            endpoint += block_nonce.to_string().as_str();
+        }
+        self.get_endpoint(endpoint.as_str())
+    }
+
     // get_network_config retrieves the network configuration from the proxy
     pub async fn get_network_config(&self) -> Result<NetworkConfig> {
         let endpoint = self.get_endpoint(NETWORK_CONFIG_ENDPOINT);
@@ -168,8 +193,7 @@ impl CommunicationProxy {
             return Err(anyhow!("invalid address"));
         }
 
-        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str();
-        let endpoint = self.get_endpoint(endpoint.as_str());
+        let endpoint = self.get_account_endpoint(address, "");
         let resp = self
             .client
             .get(endpoint)
@@ -193,8 +217,7 @@ impl CommunicationProxy {
             return Err(anyhow!("invalid address"));
         }
 
-        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + "/esdts/roles";
-        let endpoint = self.get_endpoint(endpoint.as_str());
+        let endpoint = self.get_account_endpoint(address, "/esdts/roles");
         let resp = self
             .client
             .get(endpoint)
@@ -218,8 +241,7 @@ impl CommunicationProxy {
             return Err(anyhow!("invalid address"));
         }
 
-        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + "/esdt";
-        let endpoint = self.get_endpoint(endpoint.as_str());
+        let endpoint = self.get_account_endpoint(address, "/esdt");
         let resp = self
             .client
             .get(endpoint)
@@ -243,8 +265,7 @@ impl CommunicationProxy {
             return Err(anyhow!("invalid address"));
         }
 
-        let endpoint = ACCOUNT_ENDPOINT.to_string() + address.to_string().as_str() + KEYS_ENDPOINT;
-        let endpoint = self.get_endpoint(endpoint.as_str());
+        let endpoint = self.get_account_endpoint(address, KEYS_ENDPOINT);
         let resp = self
             .client
             .get(endpoint)
@@ -259,6 +280,63 @@ impl CommunicationProxy {
         }
     }
 
+    // get_account_esdt_token retrieves the balance of a single esdt token or nft instance of an account from the network
+    pub async fn get_account_esdt_token(
+        &self,
+        address: &Address,
+        token_identifier: &str,
+        nonce: u64,
+    ) -> Result<EsdtBalance> {
+        if !address.is_valid() {
+            return Err(anyhow!("invalid address"));
+        }
+
+        let suffix = if nonce == 0 {
+            format!("/esdt/{token_identifier}")
+        } else {
+            format!("/nft/{token_identifier}/nonce/{nonce}")
+        };
+        let endpoint = self.get_account_endpoint(address, suffix.as_str());
+        let resp = self
+            .client
+            .get(endpoint)
+            .send()
+            .await?
+            .json::<EsdtTokenResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(anyhow!("{}", resp.error)),
+            Some(b) => Ok(b.token_data),
+        }
+    }
+
+    // get_account_storage_value retrieves a single storage value of an account from the network, hex encoded
+    pub async fn get_account_storage_value(
+        &self,
+        address: &Address,
+        key_hex: &str,
+    ) -> Result<String> {
+        if !address.is_valid() {
+            return Err(anyhow!("invalid address"));
+        }
+
+        let endpoint =
+            self.get_account_endpoint(address, (KEY_ENDPOINT.to_string() + key_hex).as_str());
+        let resp = self
+            .client
+            .get(endpoint)
+            .send()
+            .await?
+            .json::<AccountStorageValueResponse>()
+            .await?;
+
+        match resp.data {
+            None => Err(anyhow!("{}", resp.error)),
+            Some(b) => Ok(b.value),
+        }
+    }
+
     async fn get_transaction_info_internal(
         &self,
         hash: &str,
@@ -23,6 +23,21 @@ pub struct EsdtBalanceResponse {
     pub code: String,
 }
 
+// EsdtTokenData holds the data of a single esdt token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EsdtTokenData {
+    pub token_data: EsdtBalance,
+}
+
+// EsdtTokenResponse holds the single esdt token endpoint response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EsdtTokenResponse {
+    pub data: Option<EsdtTokenData>,
+    pub error: String,
+    pub code: String,
+}
+
 // EsdtRolesData holds the esdt roles data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EsdtRolesData {
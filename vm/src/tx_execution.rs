@@ -6,7 +6,7 @@ mod exec_create;
 mod exec_general_tx;
 mod system_sc;
 
-pub use blockchain_vm::{BlockchainVM, BlockchainVMRef};
+pub use blockchain_vm::{BlockchainVM, BlockchainVMRef, ExecutionObserver};
 pub use builtin_function_mocks::*;
 pub use exec_call::*;
 pub(crate) use exec_general_tx::*;
@@ -1,12 +1,24 @@
-use std::{ops::Deref, sync::Arc};
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
 
 use multiversx_chain_vm_executor::Executor;
 
+use crate::tx_mock::TxInput;
+
 use super::BuiltinFunctionContainer;
 
+/// Gets notified whenever contract code is about to be executed,
+/// be it a direct call, a sync call, an async call, a callback or a promise.
+pub trait ExecutionObserver: Send + Sync {
+    fn on_contract_execution(&self, contract_code: &[u8], tx_input: &TxInput);
+}
+
 pub struct BlockchainVM {
     pub builtin_functions: BuiltinFunctionContainer,
     pub executor: Box<dyn Executor + Send + Sync>,
+    pub execution_observer: Mutex<Option<Arc<dyn ExecutionObserver>>>,
 }
 
 #[derive(Clone)]
@@ -17,6 +29,7 @@ impl BlockchainVM {
         BlockchainVM {
             builtin_functions: BuiltinFunctionContainer,
             executor,
+            execution_observer: Mutex::new(None),
         }
     }
 }
@@ -25,6 +38,18 @@ impl BlockchainVMRef {
     pub fn new(executor: Box<dyn Executor + Send + Sync>) -> Self {
         BlockchainVMRef(Arc::new(BlockchainVM::new(executor)))
     }
+
+    pub fn set_execution_observer(&self, observer: Option<Arc<dyn ExecutionObserver>>) {
+        *self.execution_observer.lock().unwrap() = observer;
+    }
+
+    pub(crate) fn notify_contract_execution(&self, contract_code: &[u8], tx_input: &TxInput) {
+        // not holding the lock while the observer runs
+        let observer = self.execution_observer.lock().unwrap().clone();
+        if let Some(observer) = observer {
+            observer.on_contract_execution(contract_code, tx_input);
+        }
+    }
 }
 
 impl Deref for BlockchainVMRef {
@@ -49,7 +49,7 @@ impl BlockchainVMRef {
         F: FnOnce(),
     {
         state.with_shared(|state_arc| {
-            let tx_cache = TxCache::new(state_arc);
+            let tx_cache = TxCache::from_state(state_arc);
             let mut tx_context_sh =
                 Shareable::new(TxContext::new(self.clone(), tx_input, tx_cache));
             TxContextStack::execute_on_vm_stack(&mut tx_context_sh, f);
@@ -87,7 +87,7 @@ impl BlockchainVMRef {
         state.subtract_tx_gas(&tx_input.from, tx_input.gas_limit, tx_input.gas_price);
 
         let (tx_result, blockchain_updates) = state.with_shared(|state_arc| {
-            let tx_cache = TxCache::new(state_arc);
+            let tx_cache = TxCache::from_state(state_arc);
             self.execute_builtin_function_or_default(tx_input, tx_cache, f)
         });
 
@@ -103,7 +103,7 @@ impl BlockchainVMRef {
         async_data: AsyncCallTxData,
         state: &mut Shareable<BlockchainState>,
     ) -> (TxResult, TxResult) {
-        if state.accounts.contains_key(&async_data.to) {
+        if state.account_exists(&async_data.to) {
             let async_input = async_call_tx_input(&async_data, CallType::AsyncCall);
 
             let async_result = self.sc_call_with_async_and_callback(
@@ -184,7 +184,7 @@ impl BlockchainVMRef {
         promise: &Promise,
         state: &mut Shareable<BlockchainState>,
     ) -> (TxResult, TxResult) {
-        if state.accounts.contains_key(&promise.call.to) {
+        if state.account_exists(&promise.call.to) {
             let async_input = async_call_tx_input(&promise.call, CallType::AsyncCall);
             let async_result = self.sc_call_with_async_and_callback(
                 async_input,
@@ -232,7 +232,7 @@ impl BlockchainVMRef {
         state: &mut Shareable<BlockchainState>,
     ) -> Result<BlockchainUpdate, TxPanic> {
         state.with_shared(|state_arc| {
-            let tx_cache = TxCache::new(state_arc);
+            let tx_cache = TxCache::from_state(state_arc);
             tx_cache.subtract_egld_balance(&async_data.from, &async_data.call_value)?;
             tx_cache.insert_account(AccountData {
                 address: async_data.to.clone(),
@@ -30,6 +30,7 @@ impl BlockchainVMRef {
 
     pub fn get_contract_instance(&self, tx_context: &TxContext) -> Box<dyn Instance> {
         let contract_code = get_contract_identifier(tx_context);
+        self.notify_contract_execution(&contract_code, &tx_context.tx_input_box);
         self.executor
             .new_instance(contract_code.as_slice(), &COMPILATION_OPTIONS)
             .expect("error instantiating executor instance")
@@ -3,8 +3,8 @@ use num_traits::Zero;
 use crate::{
     tx_execution::execute_system_sc,
     tx_mock::{
-        BlockchainUpdate, CallType, TxCache, TxContext, TxContextStack, TxFunctionName, TxInput,
-        TxLog, TxResult,
+        BlockchainUpdate, CallType, ReentrantCall, TxCache, TxContext, TxContextStack,
+        TxFunctionName, TxInput, TxLog, TxResult,
     },
     types::{top_encode_big_uint, VMAddress},
     with_shared::Shareable,
@@ -106,12 +106,18 @@ impl BlockchainVMRef {
         let (mut tx_result, blockchain_updates) = if is_system_sc_address(&tx_input.to) {
             execute_system_sc(tx_input, tx_cache)
         } else if should_execute_sc_call(&tx_input) {
+            let reentrant_call = TxContextStack::static_contains_contract(&tx_input.to)
+                .then(|| ReentrantCall::from_tx_input(&tx_input));
             let tx_context = TxContext::new(self.clone(), tx_input, tx_cache);
             let mut tx_context_sh = Shareable::new(tx_context);
 
             TxContextStack::execute_on_vm_stack(&mut tx_context_sh, f);
 
-            tx_context_sh.into_inner().into_results()
+            let (mut tx_result, blockchain_updates) = tx_context_sh.into_inner().into_results();
+            if let Some(reentrant_call) = reentrant_call {
+                tx_result.reentrant_calls.insert(0, reentrant_call);
+            }
+            (tx_result, blockchain_updates)
         } else {
             // no execution
             (TxResult::empty(), tx_cache.into_blockchain_updates())
@@ -8,6 +8,7 @@ mod tx_back_transfers;
 mod tx_cache;
 mod tx_cache_balance_util;
 mod tx_cache_source;
+mod tx_cache_source_fork;
 mod tx_context;
 mod tx_context_ref;
 mod tx_context_stack;
@@ -17,6 +18,7 @@ mod tx_input_function;
 mod tx_log;
 mod tx_managed_types;
 mod tx_panic;
+mod tx_reentrant_call;
 mod tx_result;
 mod tx_result_calls;
 
@@ -27,6 +29,7 @@ pub use tx_async_promise::*;
 pub use tx_back_transfers::*;
 pub use tx_cache::TxCache;
 pub use tx_cache_source::*;
+pub use tx_cache_source_fork::ForkTxCacheSource;
 pub use tx_context::*;
 pub use tx_context_ref::*;
 pub use tx_context_stack::*;
@@ -36,5 +39,6 @@ pub use tx_input_function::*;
 pub use tx_log::*;
 pub use tx_managed_types::*;
 pub use tx_panic::*;
+pub use tx_reentrant_call::*;
 pub use tx_result::*;
 pub use tx_result_calls::*;
@@ -123,6 +123,7 @@ pub fn merge_results(mut original: TxResult, mut new: TxResult) -> TxResult {
     if original.result_status == 0 {
         original.result_values.append(&mut new.result_values);
         original.result_logs.append(&mut new.result_logs);
+        original.reentrant_calls.append(&mut new.reentrant_calls);
         original.result_message = new.result_message;
         original
     } else {
@@ -7,13 +7,13 @@ use std::{
 use crate::{
     display_util::address_hex,
     types::VMAddress,
-    world_mock::{AccountData, BlockchainState},
+    world_mock::{AccountData, BlockchainState, EsdtInstanceMetadata},
 };
 
-use super::{BlockchainUpdate, TxCacheSource};
+use super::{BlockchainUpdate, ForkTxCacheSource, TxCacheSource};
 
 pub struct TxCache {
-    source_ref: Arc<dyn TxCacheSource>,
+    pub(super) source_ref: Arc<dyn TxCacheSource>,
     pub(super) accounts: Mutex<HashMap<VMAddress, AccountData>>,
     pub(super) new_token_identifiers: Mutex<Option<Vec<String>>>,
 }
@@ -35,6 +35,15 @@ impl TxCache {
         }
     }
 
+    /// Cache on top of the blockchain state, which also fetches missing data from the forked chain, if any.
+    pub fn from_state(state_ref: Arc<BlockchainState>) -> Self {
+        if state_ref.fork.is_some() {
+            TxCache::new(Arc::new(ForkTxCacheSource::new(state_ref)))
+        } else {
+            TxCache::new(state_ref)
+        }
+    }
+
     pub fn blockchain_ref(&self) -> &BlockchainState {
         self.source_ref.blockchain_ref()
     }
@@ -48,6 +57,45 @@ impl TxCache {
         }
     }
 
+    /// Makes sure the storage value is in the cached account, if the source has it.
+    pub fn load_storage_value_if_necessary(&self, address: &VMAddress, key: &[u8]) {
+        self.load_account_if_necessary(address);
+        let mut accounts_mut = self.accounts.lock().unwrap();
+        if let Some(account) = accounts_mut.get_mut(address) {
+            if !account.storage.contains_key(key) {
+                if let Some(value) = self.source_ref.load_missing_storage_value(address, key) {
+                    account.storage.insert(key.to_vec(), value);
+                }
+            }
+        }
+    }
+
+    /// Makes sure the ESDT balance is in the cached account, if the source has it.
+    pub fn load_esdt_balance_if_necessary(
+        &self,
+        address: &VMAddress,
+        token_identifier: &[u8],
+        nonce: u64,
+    ) {
+        self.load_account_if_necessary(address);
+        let mut accounts_mut = self.accounts.lock().unwrap();
+        if let Some(account) = accounts_mut.get_mut(address) {
+            if !account.esdt.has_instance(token_identifier, nonce) {
+                if let Some(balance) =
+                    self.source_ref
+                        .load_missing_esdt_balance(address, token_identifier, nonce)
+                {
+                    account.esdt.set_esdt_balance(
+                        token_identifier.to_vec(),
+                        nonce,
+                        &balance,
+                        EsdtInstanceMetadata::default(),
+                    );
+                }
+            }
+        }
+    }
+
     pub fn with_account<R, F>(&self, address: &VMAddress, f: F) -> R
     where
         F: FnOnce(&AccountData) -> R,
@@ -46,6 +46,7 @@ impl TxCache {
         nonce: u64,
         value: &BigUint,
     ) -> Result<EsdtInstanceMetadata, TxPanic> {
+        self.load_esdt_balance_if_necessary(address, esdt_token_identifier, nonce);
         self.with_account_mut(address, |account| {
             let esdt_data_map = &mut account.esdt;
             let esdt_data = esdt_data_map
@@ -76,6 +77,7 @@ impl TxCache {
         value: &BigUint,
         esdt_metadata: EsdtInstanceMetadata,
     ) {
+        self.load_esdt_balance_if_necessary(address, esdt_token_identifier, nonce);
         self.with_account_mut(address, |account| {
             account.esdt.increase_balance(
                 esdt_token_identifier.to_vec(),
@@ -1,3 +1,5 @@
+use num_bigint::BigUint;
+
 use crate::{
     types::VMAddress,
     world_mock::{AccountData, BlockchainState},
@@ -8,6 +10,21 @@ use super::TxCache;
 pub trait TxCacheSource: Send + Sync {
     fn load_account(&self, address: &VMAddress) -> Option<AccountData>;
 
+    /// Provides a storage value that is missing from a loaded account, if the source knows of one.
+    fn load_missing_storage_value(&self, _address: &VMAddress, _key: &[u8]) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Provides an ESDT balance that is missing from a loaded account, if the source knows of one.
+    fn load_missing_esdt_balance(
+        &self,
+        _address: &VMAddress,
+        _token_identifier: &[u8],
+        _nonce: u64,
+    ) -> Option<BigUint> {
+        None
+    }
+
     fn blockchain_ref(&self) -> &BlockchainState;
 }
 
@@ -16,6 +33,20 @@ impl TxCacheSource for TxCache {
         Some(self.with_account(address, AccountData::clone))
     }
 
+    fn load_missing_storage_value(&self, address: &VMAddress, key: &[u8]) -> Option<Vec<u8>> {
+        self.source_ref.load_missing_storage_value(address, key)
+    }
+
+    fn load_missing_esdt_balance(
+        &self,
+        address: &VMAddress,
+        token_identifier: &[u8],
+        nonce: u64,
+    ) -> Option<BigUint> {
+        self.source_ref
+            .load_missing_esdt_balance(address, token_identifier, nonce)
+    }
+
     fn blockchain_ref(&self) -> &BlockchainState {
         self.blockchain_ref()
     }
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::{
+    types::VMAddress,
+    world_mock::{AccountData, BlockchainState},
+};
+
+use super::TxCacheSource;
+
+/// Transaction cache source for a blockchain state that forks another chain.
+///
+/// Accounts missing from the state, as well as storage values and ESDT balances missing from its accounts,
+/// are fetched from the forked chain, the first time they are accessed.
+pub struct ForkTxCacheSource {
+    state_ref: Arc<BlockchainState>,
+}
+
+impl ForkTxCacheSource {
+    pub fn new(state_ref: Arc<BlockchainState>) -> Self {
+        ForkTxCacheSource { state_ref }
+    }
+}
+
+impl TxCacheSource for ForkTxCacheSource {
+    fn load_account(&self, address: &VMAddress) -> Option<AccountData> {
+        self.state_ref
+            .accounts
+            .get(address)
+            .map(AccountData::clone)
+            .or_else(|| self.state_ref.fetch_forked_account(address))
+    }
+
+    fn load_missing_storage_value(&self, address: &VMAddress, key: &[u8]) -> Option<Vec<u8>> {
+        self.state_ref
+            .fetch_forked_storage_value(address, key)
+            .filter(|value| !value.is_empty())
+    }
+
+    fn load_missing_esdt_balance(
+        &self,
+        address: &VMAddress,
+        token_identifier: &[u8],
+        nonce: u64,
+    ) -> Option<BigUint> {
+        self.state_ref
+            .fetch_forked_esdt_balance(address, token_identifier, nonce)
+            .filter(|balance| !balance.is_zero())
+    }
+
+    fn blockchain_ref(&self) -> &BlockchainState {
+        &self.state_ref
+    }
+}
@@ -1,4 +1,4 @@
-use crate::with_shared::Shareable;
+use crate::{types::VMAddress, with_shared::Shareable};
 
 use super::TxContext;
 
@@ -33,6 +33,17 @@ impl TxContextStack {
         })
     }
 
+    /// Checks whether the contract is already executing somewhere in the current call stack.
+    pub fn static_contains_contract(address: &VMAddress) -> bool {
+        API_STACK.with(|cell| {
+            let stack = cell.lock().unwrap();
+            stack
+                .0
+                .iter()
+                .any(|tx_context| &tx_context.input_ref().to == address)
+        })
+    }
+
     /// Manages the stack.
     ///
     /// Pushes the context to the stack, executes closure, pops after.
@@ -0,0 +1,24 @@
+use crate::types::VMAddress;
+
+use super::{TxFunctionName, TxInput};
+
+/// A call into a contract that was already executing further up the same call stack,
+/// e.g. a contract that makes a sync call and gets called back by the callee.
+///
+/// Only recorded by the debugger, so that tests can spot reentrancy.
+#[derive(Clone, Debug)]
+pub struct ReentrantCall {
+    pub caller: VMAddress,
+    pub contract: VMAddress,
+    pub endpoint: TxFunctionName,
+}
+
+impl ReentrantCall {
+    pub fn from_tx_input(tx_input: &TxInput) -> Self {
+        ReentrantCall {
+            caller: tx_input.from.clone(),
+            contract: tx_input.to.clone(),
+            endpoint: tx_input.func_name.clone(),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use super::{AsyncCallTxData, TxLog, TxPanic, TxResultCalls};
+use super::{AsyncCallTxData, ReentrantCall, TxLog, TxPanic, TxResultCalls};
 
 #[derive(Clone, Debug)]
 #[must_use]
@@ -19,6 +19,10 @@ pub struct TxResult {
     ///
     /// Is never cleared of its contents.
     pub all_calls: Vec<AsyncCallTxData>,
+
+    /// Calls into contracts that were already executing further up the call stack,
+    /// including those made by nested sync calls.
+    pub reentrant_calls: Vec<ReentrantCall>,
 }
 
 impl Default for TxResult {
@@ -30,6 +34,7 @@ impl Default for TxResult {
             result_logs: Vec::new(),
             pending_calls: TxResultCalls::empty(),
             all_calls: Vec::new(),
+            reentrant_calls: Vec::new(),
         }
     }
 }
@@ -83,6 +88,8 @@ impl TxResult {
             .extend_from_slice(sync_call_result.result_values.as_slice());
         self.result_logs
             .extend_from_slice(sync_call_result.result_logs.as_slice());
+        self.reentrant_calls
+            .extend_from_slice(sync_call_result.reentrant_calls.as_slice());
         if let Some(sync_result_async) = &sync_call_result.pending_calls.async_call {
             assert!(
                 self.pending_calls.async_call.is_none(),
@@ -123,6 +130,14 @@ impl TxResult {
     pub fn assert_user_error(&self, expected_message: &str) {
         self.assert_error(4, expected_message);
     }
+
+    pub fn assert_no_reentrant_calls(&self) {
+        assert!(
+            self.reentrant_calls.is_empty(),
+            "No reentrant calls expected, but found: {:?}",
+            self.reentrant_calls
+        );
+    }
 }
 
 impl fmt::Display for TxResult {
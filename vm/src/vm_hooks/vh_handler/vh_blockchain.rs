@@ -131,6 +131,7 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
             "get_esdt_balance not yet implemented for accounts other than the contract itself"
         );
 
+        self.load_esdt_balance(self.current_address(), token_id_bytes, nonce);
         let esdt_balance = self
             .current_account_data()
             .esdt
@@ -156,6 +157,7 @@ pub trait VMHooksBlockchain: VMHooksHandlerSource {
         let address = VMAddress::from_slice(self.m_types_lock().mb_get(address_handle));
         let token_id_bytes = self.m_types_lock().mb_get(token_id_handle).to_vec();
 
+        self.load_esdt_balance(&address, &token_id_bytes, nonce);
         if let Some(account) = self.account_data(&address) {
             if let Some(esdt_data) = account.esdt.get_by_identifier(token_id_bytes.as_slice()) {
                 if let Some(instance) = esdt_data.instances.get_by_nonce(nonce) {
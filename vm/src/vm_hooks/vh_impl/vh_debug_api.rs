@@ -58,6 +58,9 @@ impl VMHooksHandlerSource for DebugApiVMHooksHandler {
     }
 
     fn storage_read_any_address(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
+        self.0
+            .blockchain_cache()
+            .load_storage_value_if_necessary(address, key);
         self.0.with_account_mut(address, |account| {
             account.storage.get(key).cloned().unwrap_or_default()
         })
@@ -239,7 +242,8 @@ impl DebugApiVMHooksHandler {
 
         let contract_address = &self.0.input_ref().to;
         let builtin_functions = &self.0.vm_ref.builtin_functions;
-        self.back_transfers_lock().new_from_result(contract_address, &tx_result, builtin_functions);
+        self.back_transfers_lock()
+            .new_from_result(contract_address, &tx_result, builtin_functions);
 
         tx_result.result_values
     }
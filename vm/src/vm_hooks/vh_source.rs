@@ -62,6 +62,9 @@ pub trait VMHooksHandlerSource: Debug {
             .expect("missing current account")
     }
 
+    /// Makes sure the ESDT balance is included in the account data, for sources that retrieve it lazily.
+    fn load_esdt_balance(&self, _address: &VMAddress, _token_identifier: &[u8], _nonce: u64) {}
+
     fn account_code(&self, address: &VMAddress) -> Vec<u8>;
 
     fn perform_async_call(
@@ -1,5 +1,8 @@
 mod account_data;
 mod block_info;
+mod block_seed_policy;
+mod blockchain_fork;
+mod blockchain_fork_source;
 mod blockchain_mock;
 mod blockchain_mock_account_util;
 mod blockchain_state;
@@ -13,6 +16,9 @@ pub mod reserved;
 
 pub use account_data::*;
 pub use block_info::*;
+pub use block_seed_policy::*;
+pub use blockchain_fork::{BlockchainFork, ForkFetchedState};
+pub use blockchain_fork_source::BlockchainForkSource;
 pub use blockchain_mock::*;
 pub use blockchain_state::BlockchainState;
 pub use esdt_data::*;
@@ -0,0 +1,141 @@
+use sha2::{Digest, Sha512};
+
+pub const BLOCK_RANDOM_SEED_LEN: usize = 48;
+
+pub type BlockRandomSeed = [u8; BLOCK_RANDOM_SEED_LEN];
+
+/// Decides the random seed of each new block produced by the test environment.
+///
+/// All variants are deterministic, so the same test always sees the same seeds.
+#[derive(Clone, Debug, Default)]
+pub enum BlockSeedPolicy {
+    /// Each seed is derived from the seed of the parent block and the block nonce.
+    #[default]
+    ParentDerived,
+
+    /// All blocks get the same seed.
+    Fixed(Box<BlockRandomSeed>),
+
+    /// Each seed is derived from a master seed and the block nonce,
+    /// regardless of the previous blocks.
+    MasterSeed(u64),
+
+    /// Seeds are taken in order from a list, starting over when it is exhausted.
+    List(BlockSeedList),
+}
+
+/// Non-empty list of seeds, together with the position of the next one.
+#[derive(Clone, Debug)]
+pub struct BlockSeedList {
+    seeds: Vec<BlockRandomSeed>,
+    next_index: usize,
+}
+
+impl BlockSeedList {
+    fn next_seed(&mut self) -> BlockRandomSeed {
+        let seed = self.seeds[self.next_index % self.seeds.len()];
+        self.next_index += 1;
+        seed
+    }
+}
+
+impl BlockSeedPolicy {
+    pub fn fixed(seed: BlockRandomSeed) -> Self {
+        BlockSeedPolicy::Fixed(Box::new(seed))
+    }
+
+    pub fn master_seed(master_seed: u64) -> Self {
+        BlockSeedPolicy::MasterSeed(master_seed)
+    }
+
+    pub fn list(seeds: Vec<BlockRandomSeed>) -> Self {
+        assert!(!seeds.is_empty(), "block seed list cannot be empty");
+        BlockSeedPolicy::List(BlockSeedList {
+            seeds,
+            next_index: 0,
+        })
+    }
+
+    /// Produces the seed of a new block. Advances the position in the list, for list policies.
+    pub fn next_seed(
+        &mut self,
+        parent_seed: &BlockRandomSeed,
+        block_nonce: u64,
+    ) -> BlockRandomSeed {
+        match self {
+            BlockSeedPolicy::ParentDerived => hash_seed(parent_seed, block_nonce),
+            BlockSeedPolicy::Fixed(seed) => **seed,
+            BlockSeedPolicy::MasterSeed(master_seed) => {
+                hash_seed(&master_seed.to_be_bytes(), block_nonce)
+            },
+            BlockSeedPolicy::List(list) => list.next_seed(),
+        }
+    }
+
+    /// Produces a new seed for the current block, used by its next transaction.
+    ///
+    /// Master seed policies chain it from the current one, the others work the same as for new blocks.
+    pub fn next_tx_seed(
+        &mut self,
+        current_seed: &BlockRandomSeed,
+        block_nonce: u64,
+    ) -> BlockRandomSeed {
+        match self {
+            BlockSeedPolicy::MasterSeed(_) => hash_seed(current_seed, block_nonce),
+            _ => self.next_seed(current_seed, block_nonce),
+        }
+    }
+}
+
+impl core::fmt::Display for BlockSeedPolicy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BlockSeedPolicy::ParentDerived => write!(f, "derived from parent block"),
+            BlockSeedPolicy::Fixed(_) => write!(f, "fixed"),
+            BlockSeedPolicy::MasterSeed(master_seed) => write!(f, "master seed {master_seed}"),
+            BlockSeedPolicy::List(list) => write!(f, "list of {} seeds", list.seeds.len()),
+        }
+    }
+}
+
+fn hash_seed(input: &[u8], block_nonce: u64) -> BlockRandomSeed {
+    let mut hasher = Sha512::new();
+    hasher.update(input);
+    hasher.update(block_nonce.to_be_bytes());
+    let mut seed = [0u8; BLOCK_RANDOM_SEED_LEN];
+    seed.copy_from_slice(&hasher.finalize()[..BLOCK_RANDOM_SEED_LEN]);
+    seed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_master_seed_ignores_parent() {
+        let mut policy = BlockSeedPolicy::master_seed(42);
+        let seed_1 = policy.next_seed(&[1u8; BLOCK_RANDOM_SEED_LEN], 5);
+        let seed_2 = policy.next_seed(&[2u8; BLOCK_RANDOM_SEED_LEN], 5);
+        assert_eq!(seed_1, seed_2);
+        assert_ne!(seed_1, policy.next_seed(&[1u8; BLOCK_RANDOM_SEED_LEN], 6));
+    }
+
+    #[test]
+    fn test_list_cycles() {
+        let mut policy = BlockSeedPolicy::list(vec![[1u8; 48], [2u8; 48]]);
+        let parent = [0u8; BLOCK_RANDOM_SEED_LEN];
+        assert_eq!(policy.next_seed(&parent, 1), [1u8; 48]);
+        assert_eq!(policy.next_seed(&parent, 2), [2u8; 48]);
+        assert_eq!(policy.next_seed(&parent, 3), [1u8; 48]);
+    }
+
+    #[test]
+    fn test_master_seed_tx_seeds_differ() {
+        let mut policy = BlockSeedPolicy::master_seed(42);
+        let block_seed = policy.next_seed(&[0u8; BLOCK_RANDOM_SEED_LEN], 5);
+        let tx_seed_1 = policy.next_tx_seed(&block_seed, 5);
+        let tx_seed_2 = policy.next_tx_seed(&tx_seed_1, 5);
+        assert_ne!(block_seed, tx_seed_1);
+        assert_ne!(tx_seed_1, tx_seed_2);
+    }
+}
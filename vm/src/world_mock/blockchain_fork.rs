@@ -0,0 +1,121 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use num_bigint::BigUint;
+
+use crate::types::VMAddress;
+
+use super::{AccountData, BlockchainForkSource};
+
+/// A forked chain, together with everything fetched from it so far.
+///
+/// It is part of the blockchain state, so the fetched values are copied along with it, e.g. in snapshots.
+/// They are recorded even when fetched by transactions that fail, so they are never requested twice.
+pub struct BlockchainFork {
+    source: Arc<dyn BlockchainForkSource>,
+    fetched: Mutex<ForkFetchedState>,
+    local_accounts: HashSet<VMAddress>,
+}
+
+/// The values fetched from a forked chain, as they were on that chain.
+#[derive(Clone, Default, Debug)]
+pub struct ForkFetchedState {
+    /// `None` for the accounts that do not exist on the forked chain.
+    pub accounts: HashMap<VMAddress, Option<AccountData>>,
+    pub storage: HashMap<(VMAddress, Vec<u8>), Vec<u8>>,
+    pub esdt_balances: HashMap<(VMAddress, Vec<u8>, u64), BigUint>,
+}
+
+impl BlockchainFork {
+    pub fn new(source: Arc<dyn BlockchainForkSource>) -> Self {
+        BlockchainFork {
+            source,
+            fetched: Mutex::new(ForkFetchedState::default()),
+            local_accounts: HashSet::new(),
+        }
+    }
+
+    /// Marks an account as fully defined locally, so nothing more gets fetched for it.
+    pub fn set_local_account(&mut self, address: &VMAddress) {
+        self.local_accounts.insert(address.clone());
+    }
+
+    pub fn is_local_account(&self, address: &VMAddress) -> bool {
+        self.local_accounts.contains(address)
+    }
+
+    /// The account info, without storage and ESDT balances.
+    pub fn account(&self, address: &VMAddress) -> Option<AccountData> {
+        if let Some(account) = self.fetched.lock().unwrap().accounts.get(address) {
+            return account.clone();
+        }
+        let account = self.source.fetch_account(address);
+        self.fetched
+            .lock()
+            .unwrap()
+            .accounts
+            .insert(address.clone(), account.clone());
+        account
+    }
+
+    pub fn storage_value(&self, address: &VMAddress, key: &[u8]) -> Vec<u8> {
+        let fetched_key = (address.clone(), key.to_vec());
+        if let Some(value) = self.fetched.lock().unwrap().storage.get(&fetched_key) {
+            return value.clone();
+        }
+        let value = self.source.fetch_storage_value(address, key);
+        self.fetched
+            .lock()
+            .unwrap()
+            .storage
+            .insert(fetched_key, value.clone());
+        value
+    }
+
+    pub fn esdt_balance(
+        &self,
+        address: &VMAddress,
+        token_identifier: &[u8],
+        nonce: u64,
+    ) -> BigUint {
+        let fetched_key = (address.clone(), token_identifier.to_vec(), nonce);
+        if let Some(balance) = self.fetched.lock().unwrap().esdt_balances.get(&fetched_key) {
+            return balance.clone();
+        }
+        let balance = self
+            .source
+            .fetch_esdt_balance(address, token_identifier, nonce);
+        self.fetched
+            .lock()
+            .unwrap()
+            .esdt_balances
+            .insert(fetched_key, balance.clone());
+        balance
+    }
+
+    /// A copy of everything fetched so far.
+    pub fn fetched_state(&self) -> ForkFetchedState {
+        self.fetched.lock().unwrap().clone()
+    }
+}
+
+impl Clone for BlockchainFork {
+    fn clone(&self) -> Self {
+        BlockchainFork {
+            source: self.source.clone(),
+            fetched: Mutex::new(self.fetched_state()),
+            local_accounts: self.local_accounts.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for BlockchainFork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockchainFork")
+            .field("source", &self.source)
+            .finish()
+    }
+}
@@ -0,0 +1,30 @@
+use std::fmt::Debug;
+
+use num_bigint::BigUint;
+
+use crate::types::VMAddress;
+
+use super::AccountData;
+
+/// Provides state that is missing locally, for instance by fetching it from a real network.
+///
+/// The VM consults it lazily: accounts the first time they are accessed,
+/// storage values and ESDT balances individually, the first time each of them is accessed.
+/// The results are kept in the blockchain state, so each value is only requested once.
+pub trait BlockchainForkSource: Debug + Send + Sync {
+    /// Retrieves the account info and its ESDT roles, but not its storage and ESDT balances.
+    ///
+    /// Returns `None` if the account does not exist on the forked chain either.
+    fn fetch_account(&self, address: &VMAddress) -> Option<AccountData>;
+
+    /// Retrieves a single storage value. Missing keys have an empty value.
+    fn fetch_storage_value(&self, address: &VMAddress, key: &[u8]) -> Vec<u8>;
+
+    /// Retrieves the balance of a single ESDT token, or NFT/SFT instance for nonces other than 0.
+    fn fetch_esdt_balance(
+        &self,
+        address: &VMAddress,
+        token_identifier: &[u8],
+        nonce: u64,
+    ) -> BigUint;
+}
@@ -5,8 +5,12 @@ use crate::{display_util::address_hex, types::VMAddress};
 use super::{AccountData, BlockchainState};
 
 impl BlockchainState {
+    /// Accounts added explicitly replace the forked ones, if any.
     pub fn add_account(&mut self, acct: AccountData) {
         let address = acct.address.clone();
+        if let Some(fork) = &mut self.fork {
+            fork.set_local_account(&address);
+        }
         self.accounts.insert(address, acct);
     }
 
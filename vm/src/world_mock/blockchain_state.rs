@@ -1,11 +1,14 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
 
 use num_bigint::BigUint;
 use num_traits::Zero;
 
 use crate::{tx_mock::BlockchainUpdate, types::VMAddress};
 
-use super::{reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo};
+use super::{
+    reserved::STORAGE_REWARD_KEY, AccountData, BlockInfo, BlockRandomSeed, BlockSeedPolicy,
+    BlockchainFork, BlockchainForkSource, EsdtInstanceMetadata,
+};
 
 #[derive(Default, Clone)]
 pub struct BlockchainState {
@@ -14,6 +17,20 @@ pub struct BlockchainState {
     pub previous_block_info: BlockInfo,
     pub current_block_info: BlockInfo,
     pub new_token_identifiers: Vec<String>,
+
+    /// Random seed of the blocks produced by the test environment.
+    pub block_seed_policy: BlockSeedPolicy,
+
+    /// Set when a seed policy is chosen explicitly.
+    /// Consecutive transactions in the same block then also get new seeds from the policy.
+    pub block_seed_per_tx: bool,
+
+    /// The block seed seen by the last transaction, used to detect transactions in the same block.
+    pub last_tx_block_random_seed: Option<Box<BlockRandomSeed>>,
+
+    /// If set, accounts missing from `accounts` are fetched from here, the first time they are needed.
+    /// So are the storage values and ESDT balances missing from the accounts that come from the forked chain.
+    pub fork: Option<BlockchainFork>,
 }
 
 impl BlockchainState {
@@ -22,10 +39,109 @@ impl BlockchainState {
     }
 
     pub fn account_exists(&self, address: &VMAddress) -> bool {
-        self.accounts.contains_key(address)
+        self.accounts.contains_key(address) || self.fetch_forked_account(address).is_some()
+    }
+
+    /// Forks another chain. Accounts already in the state are kept as they are, nothing gets fetched for them.
+    pub fn set_fork_source(&mut self, source: Arc<dyn BlockchainForkSource>) {
+        let mut fork = BlockchainFork::new(source);
+        for address in self.accounts.keys() {
+            fork.set_local_account(address);
+        }
+        self.fork = Some(fork);
+    }
+
+    /// Retrieves an account from the forked chain, if configured. Does not look at the local accounts.
+    ///
+    /// Storage values and ESDT balances are not included, they are fetched separately.
+    pub fn fetch_forked_account(&self, address: &VMAddress) -> Option<AccountData> {
+        self.fork.as_ref().and_then(|fork| fork.account(address))
+    }
+
+    /// Retrieves a storage value from the forked chain,
+    /// if configured and if the account comes from there. Does not look at the local accounts.
+    pub fn fetch_forked_storage_value(&self, address: &VMAddress, key: &[u8]) -> Option<Vec<u8>> {
+        let fork = self.fork_for_account(address)?;
+        Some(fork.storage_value(address, key))
+    }
+
+    /// Retrieves an ESDT balance from the forked chain,
+    /// if configured and if the account comes from there. Does not look at the local accounts.
+    pub fn fetch_forked_esdt_balance(
+        &self,
+        address: &VMAddress,
+        token_identifier: &[u8],
+        nonce: u64,
+    ) -> Option<BigUint> {
+        let fork = self.fork_for_account(address)?;
+        Some(fork.esdt_balance(address, token_identifier, nonce))
+    }
+
+    fn fork_for_account(&self, address: &VMAddress) -> Option<&BlockchainFork> {
+        let fork = self.fork.as_ref()?;
+        if fork.is_local_account(address) {
+            return None;
+        }
+        fork.account(address)?;
+        Some(fork)
+    }
+
+    /// Copies the account from the fork source into the local state, if it is not already there.
+    pub fn load_forked_account_if_missing(&mut self, address: &VMAddress) {
+        if self.accounts.contains_key(address) {
+            return;
+        }
+        if let Some(account) = self.fetch_forked_account(address) {
+            self.accounts.insert(address.clone(), account);
+        }
+    }
+
+    /// Copies the storage value from the forked chain into the local account, if it is not already there.
+    pub fn load_forked_storage_value_if_missing(&mut self, address: &VMAddress, key: &[u8]) {
+        self.load_forked_account_if_missing(address);
+        if self
+            .accounts
+            .get(address)
+            .map_or(true, |account| account.storage.contains_key(key))
+        {
+            return;
+        }
+        if let Some(value) = self.fetch_forked_storage_value(address, key) {
+            if !value.is_empty() {
+                let account = self.accounts.get_mut(address).unwrap();
+                account.storage.insert(key.to_vec(), value);
+            }
+        }
+    }
+
+    /// Copies the ESDT balance from the forked chain into the local account, if it is not already there.
+    pub fn load_forked_esdt_balance_if_missing(
+        &mut self,
+        address: &VMAddress,
+        token_identifier: &[u8],
+        nonce: u64,
+    ) {
+        self.load_forked_account_if_missing(address);
+        if self.accounts.get(address).map_or(true, |account| {
+            account.esdt.has_instance(token_identifier, nonce)
+        }) {
+            return;
+        }
+        if let Some(balance) = self.fetch_forked_esdt_balance(address, token_identifier, nonce) {
+            if !balance.is_zero() {
+                let account = self.accounts.get_mut(address).unwrap();
+                account.esdt.set_esdt_balance(
+                    token_identifier.to_vec(),
+                    nonce,
+                    &balance,
+                    EsdtInstanceMetadata::default(),
+                );
+            }
+        }
     }
 
     pub fn increase_account_nonce(&mut self, address: &VMAddress) {
+        self.load_forked_account_if_missing(address);
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
@@ -36,6 +152,7 @@ impl BlockchainState {
     }
 
     pub fn subtract_tx_gas(&mut self, address: &VMAddress, gas_limit: u64, gas_price: u64) {
+        self.load_forked_account_if_missing(address);
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
@@ -51,6 +168,7 @@ impl BlockchainState {
     }
 
     pub fn increase_validator_reward(&mut self, address: &VMAddress, amount: &BigUint) {
+        self.load_forked_storage_value_if_missing(address, STORAGE_REWARD_KEY);
         let account = self.accounts.get_mut(address).unwrap_or_else(|| {
             panic!(
                 "Account not found: {}",
@@ -89,6 +207,7 @@ impl Debug for BlockchainState {
             .field("accounts", &self.accounts)
             .field("new_addresses", &self.new_addresses)
             .field("current_block_info", &self.current_block_info)
+            .field("fork", &self.fork)
             .finish()
     }
 }
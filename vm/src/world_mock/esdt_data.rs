@@ -57,6 +57,14 @@ impl AccountEsdt {
         esdt_data.roles = EsdtRoles::new(roles);
     }
 
+    /// Whether the instance is present, even with a zero balance.
+    pub fn has_instance(&self, identifier: &[u8], nonce: u64) -> bool {
+        self.get_by_identifier(identifier)
+            .map_or(false, |esdt_data| {
+                esdt_data.instances.get_by_nonce(nonce).is_some()
+            })
+    }
+
     /// Will provide a clone.
     pub fn get_by_identifier_or_default(&self, identifier: &[u8]) -> EsdtData {
         if let Some(value) = self.0.get(identifier) {